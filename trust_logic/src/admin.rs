@@ -0,0 +1,134 @@
+// 🏛️ PROJECT BAZAAR | ADMIN & PROTOCOL CONFIG
+// One-time initialization, the admin key, and protocol-wide settings. All of it
+// lives in instance storage so it is loaded alongside the contract itself. The deployer
+// is fixed when the contract is created, so nobody can front-run `initialize`.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+/// Semver of the deployed code; bump with every `upgrade`-bound release.
+const CONTRACT_VERSION: &str = "3.1.0";
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ProtocolConfig {
+    pub bond_amount: i128,
+    pub slash_bps: u32,
+    pub juror_stake: i128,
+    pub juror_reward_bps: u32,
+    pub listing_value_cap: i128,
+}
+
+pub(crate) fn require_admin(env: &Env) -> Result<Address, Error> {
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).ok_or(Error::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+pub(crate) fn protocol_config(env: &Env) -> Result<ProtocolConfig, Error> {
+    env.storage().instance().get(&DataKey::Config).ok_or(Error::NotInitialized)
+}
+
+pub(crate) fn bzr_token(env: &Env) -> Result<Address, Error> {
+    env.storage().instance().get(&DataKey::BzrToken).ok_or(Error::NotInitialized)
+}
+
+/// Circuit breaker guard for every state-mutating user entry point.
+pub(crate) fn require_not_paused(env: &Env) -> Result<(), Error> {
+    if env.storage().instance().get(&DataKey::Paused).unwrap_or(false) { return Err(Error::Paused); }
+    Ok(())
+}
+
+pub(crate) fn validate_config(config: &ProtocolConfig) -> Result<(), Error> {
+    if config.bond_amount < 0 { return Err(Error::InvalidConfig); }
+    if config.slash_bps > FULL_SHARE_BPS { return Err(Error::InvalidConfig); }
+    if config.juror_stake < 0 { return Err(Error::InvalidConfig); }
+    if config.juror_reward_bps > FULL_SHARE_BPS { return Err(Error::InvalidConfig); }
+    if config.listing_value_cap < 0 { return Err(Error::InvalidConfig); }
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 0: ADMIN ---
+
+    /// Runs in the deploy transaction itself; only `deployer` may then call `initialize`.
+    pub fn __constructor(env: Env, deployer: Address) {
+        env.storage().instance().set(&DataKey::Deployer, &deployer);
+    }
+
+    /// The deployer names the admin, who must sign as well.
+    pub fn initialize(env: Env, admin: Address, bzr_token: Address, config: ProtocolConfig) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) { return Err(Error::AlreadyInitialized); }
+        let deployer: Address = env.storage().instance().get(&DataKey::Deployer).ok_or(Error::NotInitialized)?;
+        deployer.require_auth();
+        admin.require_auth();
+        validate_config(&config)?;
+
+        env.storage().instance().remove(&DataKey::Deployer);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::BzrToken, &bzr_token);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.events().publish((events::INITIALIZED, admin), bzr_token);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, config: ProtocolConfig) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.events().publish((events::CONFIG_SET, admin), config.bond_amount);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        new_admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.events().publish((events::ADMIN_CHANGED, admin), new_admin);
+        Ok(())
+    }
+
+    pub fn pause(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events().publish((events::PAUSED, admin), true);
+        Ok(())
+    }
+
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        env.storage().instance().set(&DataKey::Paused, &false);
+        env.events().publish((events::PAUSED, admin), false);
+        Ok(())
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+    }
+
+    /// Swaps the contract code in place. Storage is untouched, so vaults and merchants carry over.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = require_admin(&env)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        env.events().publish((events::UPGRADED, admin), new_wasm_hash);
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> String {
+        String::from_str(&env, CONTRACT_VERSION)
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage().instance().get(&DataKey::Admin).ok_or(Error::NotInitialized)
+    }
+
+    pub fn get_config(env: Env) -> Result<ProtocolConfig, Error> {
+        protocol_config(&env)
+    }
+
+    pub fn get_bzr_token(env: Env) -> Result<Address, Error> {
+        bzr_token(&env)
+    }
+}
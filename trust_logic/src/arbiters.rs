@@ -0,0 +1,212 @@
+// 🏛️ PROJECT BAZAAR | ARBITER REGISTRY
+// Arbiters register themselves by staking at least one merchant bond in BZR. Either
+// party can have an open dispute assigned to a registered arbiter drawn at random,
+// weighted by stake; only that arbiter may then rule on it. Leaving starts a 14-day
+// cooldown before the stake comes back, and an arbiter removed for misbehaving
+// forfeits it to the treasury. Admin-appointed arbiters from `add_arbiter` keep working
+// alongside the registry.
+
+use super::*;
+
+const ARBITER_COOLDOWN_SECONDS: u64 = 1_209_600; // 14 Days
+const MAX_ARBITERS: u32 = 50;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ArbiterRecord {
+    pub stake: i128,
+    pub registered_at: u64,
+    pub active_cases: u32,
+    pub cases: u32,
+    /// Rulings the admin later marked as overturned; `overturned / cases` is the overturn rate.
+    pub overturned: u32,
+    pub leaving_at: Option<u64>,
+}
+
+fn load_arbiter(env: &Env, arbiter: &Address) -> Result<ArbiterRecord, Error> {
+    storage::read(env, &DataKey::ArbiterRecord(arbiter.clone())).ok_or(Error::NotArbiter)
+}
+
+/// Arbiters currently eligible for new assignments.
+fn registry(env: &Env) -> Vec<Address> {
+    storage::read(env, &DataKey::ArbiterRegistry).unwrap_or(Vec::new(env))
+}
+
+fn leave_registry(env: &Env, arbiter: &Address) {
+    let mut arbiters = registry(env);
+    if let Some(index) = arbiters.first_index_of(arbiter) {
+        arbiters.remove(index);
+        storage::write(env, &DataKey::ArbiterRegistry, &arbiters);
+    }
+}
+
+pub(crate) fn is_registered(env: &Env, arbiter: &Address) -> bool {
+    storage::read::<ArbiterRecord>(env, &DataKey::ArbiterRecord(arbiter.clone())).is_some_and(|record| record.leaving_at.is_none())
+}
+
+/// Only the assigned arbiter may rule on an assigned dispute, even during their cooldown.
+/// Unassigned disputes, or ones whose arbiter was expelled, are open to any arbiter.
+pub(crate) fn require_may_rule(env: &Env, dispute_id: u64, arbiter: &Address) -> Result<(), Error> {
+    match storage::read::<Address>(env, &DataKey::AssignedArbiter(dispute_id)) {
+        Some(assigned) if storage::has(env, &DataKey::ArbiterRecord(assigned.clone())) => {
+            if assigned != *arbiter { return Err(Error::NotAssignedArbiter); }
+            Ok(())
+        }
+        _ => {
+            if !disputes::is_arbiter(env, arbiter) && !is_registered(env, arbiter) { return Err(Error::NotArbiter); }
+            Ok(())
+        }
+    }
+}
+
+/// Books a ruling against a registered arbiter's statistics.
+pub(crate) fn record_ruling(env: &Env, dispute_id: u64, arbiter: &Address) {
+    let Ok(mut record) = load_arbiter(env, arbiter) else { return; };
+    if storage::has(env, &DataKey::AssignedArbiter(dispute_id)) {
+        storage::remove(env, &DataKey::AssignedArbiter(dispute_id));
+        record.active_cases = record.active_cases.saturating_sub(1);
+    }
+    record.cases += 1;
+    storage::write(env, &DataKey::ArbiterRecord(arbiter.clone()), &record);
+}
+
+/// Drops a misbehaving arbiter and books their stake into the treasury. Returns the
+/// amount forfeited; 0 for arbiters that were only admin-appointed.
+pub(crate) fn expel(env: &Env, arbiter: &Address) -> Result<i128, Error> {
+    let Ok(record) = load_arbiter(env, arbiter) else { return Ok(0); };
+    leave_registry(env, arbiter);
+    storage::remove(env, &DataKey::ArbiterRecord(arbiter.clone()));
+    treasury::credit(env, &admin::bzr_token(env)?, arbiter, record.stake);
+    env.events().publish((events::ARBITER_EXPELLED, arbiter.clone()), record.stake);
+    Ok(record.stake)
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 41: ARBITER REGISTRY ---
+
+    /// `stake` must cover at least one merchant bond; a larger stake is drawn more often.
+    pub fn register_arbiter(env: Env, arbiter: Address, stake: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        arbiter.require_auth();
+        touch_heartbeat(&env, &arbiter);
+        if storage::has(&env, &DataKey::ArbiterRecord(arbiter.clone())) { return Err(Error::ArbiterAlreadyRegistered); }
+        if stake <= 0 || stake < admin::protocol_config(&env)?.bond_amount { return Err(Error::ArbiterStakeTooLow); }
+        let mut arbiters = registry(&env);
+        if arbiters.len() >= MAX_ARBITERS { return Err(Error::TooManyArbiters); }
+
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&arbiter, &env.current_contract_address(), &stake);
+        let record = ArbiterRecord {
+            stake,
+            registered_at: env.ledger().timestamp(),
+            active_cases: 0,
+            cases: 0,
+            overturned: 0,
+            leaving_at: None,
+        };
+        storage::write(&env, &DataKey::ArbiterRecord(arbiter.clone()), &record);
+        arbiters.push_back(arbiter.clone());
+        storage::write(&env, &DataKey::ArbiterRegistry, &arbiters);
+        env.events().publish((events::ARBITER_REGISTERED, arbiter), stake);
+        Ok(())
+    }
+
+    /// Stops new assignments straight away and starts the cooldown on the stake.
+    pub fn deregister_arbiter(env: Env, arbiter: Address) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        arbiter.require_auth();
+        touch_heartbeat(&env, &arbiter);
+        let mut record = load_arbiter(&env, &arbiter)?;
+        if record.leaving_at.is_some() { return Err(Error::ArbiterCooldown); }
+
+        let leaving_at = env.ledger().timestamp() + ARBITER_COOLDOWN_SECONDS;
+        record.leaving_at = Some(leaving_at);
+        storage::write(&env, &DataKey::ArbiterRecord(arbiter.clone()), &record);
+        leave_registry(&env, &arbiter);
+        env.events().publish((events::ARBITER_LEAVING, arbiter), leaving_at);
+        Ok(leaving_at)
+    }
+
+    /// Returns the stake once the cooldown is over and every assigned case is ruled on.
+    pub fn withdraw_arbiter_stake(env: Env, arbiter: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        arbiter.require_auth();
+        touch_heartbeat(&env, &arbiter);
+        let record = load_arbiter(&env, &arbiter)?;
+        let leaving_at = record.leaving_at.ok_or(Error::ArbiterCooldown)?;
+        if env.ledger().timestamp() < leaving_at { return Err(Error::ArbiterCooldown); }
+        if record.active_cases > 0 { return Err(Error::ArbiterBusy); }
+
+        storage::remove(&env, &DataKey::ArbiterRecord(arbiter.clone()));
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &arbiter, &record.stake);
+        env.events().publish((events::ARBITER_LEFT, arbiter), record.stake);
+        Ok(record.stake)
+    }
+
+    /// Either party draws a registered arbiter for an open dispute, weighted by stake.
+    /// Uses the ledger PRNG, like panel selection.
+    pub fn assign_arbiter(env: Env, caller: Address, dispute_id: u64) -> Result<Address, Error> {
+        require_not_paused(&env)?;
+        caller.require_auth();
+        touch_heartbeat(&env, &caller);
+        let dispute = disputes::load_dispute(&env, dispute_id)?;
+        if caller != dispute.buyer && caller != dispute.merchant { return Err(Error::NotOrderParty); }
+        if dispute.status != DisputeStatus::Open { return Err(Error::DisputeClosed); }
+        if storage::has(&env, &DataKey::Panel(dispute_id)) { return Err(Error::DisputeEscalated); }
+        if storage::has(&env, &DataKey::AssignedArbiter(dispute_id)) { return Err(Error::ArbiterAlreadyAssigned); }
+
+        let mut candidates: Vec<(Address, ArbiterRecord)> = Vec::new(&env);
+        let mut total: u64 = 0;
+        for arbiter in registry(&env).iter() {
+            if arbiter == dispute.buyer || arbiter == dispute.merchant { continue; }
+            let Ok(record) = load_arbiter(&env, &arbiter) else { continue; };
+            total = total.saturating_add(record.stake.clamp(0, u64::MAX as i128) as u64);
+            candidates.push_back((arbiter, record));
+        }
+        if candidates.is_empty() || total == 0 { return Err(Error::NoArbiters); }
+
+        let mut ticket = env.prng().gen_range::<u64>(0..total);
+        let mut chosen = candidates.get_unchecked(candidates.len() - 1);
+        for candidate in candidates.iter() {
+            let weight = candidate.1.stake.clamp(0, u64::MAX as i128) as u64;
+            if ticket < weight { chosen = candidate; break; }
+            ticket -= weight;
+        }
+
+        let (arbiter, mut record) = chosen;
+        record.active_cases += 1;
+        storage::write(&env, &DataKey::ArbiterRecord(arbiter.clone()), &record);
+        storage::write(&env, &DataKey::AssignedArbiter(dispute_id), &arbiter);
+        env.events().publish((events::ARBITER_ASSIGNED, dispute.merchant), (dispute_id, arbiter.clone()));
+        Ok(arbiter)
+    }
+
+    /// Records that a registered arbiter's ruling was later found wrong.
+    pub fn mark_overturned(env: Env, dispute_id: u64) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        let dispute = disputes::load_dispute(&env, dispute_id)?;
+        if dispute.status == DisputeStatus::Open { return Err(Error::DisputeNotResolved); }
+        if storage::has(&env, &DataKey::Overturned(dispute_id)) { return Err(Error::AlreadyOverturned); }
+        let arbiter = dispute.resolved_by.ok_or(Error::DisputeNotResolved)?;
+        let mut record = load_arbiter(&env, &arbiter)?;
+
+        record.overturned += 1;
+        storage::write(&env, &DataKey::ArbiterRecord(arbiter.clone()), &record);
+        storage::write(&env, &DataKey::Overturned(dispute_id), &true);
+        env.events().publish((events::RULING_OVERTURNED, arbiter), dispute_id);
+        Ok(())
+    }
+
+    pub fn get_arbiter(env: Env, arbiter: Address) -> Result<ArbiterRecord, Error> {
+        load_arbiter(&env, &arbiter)
+    }
+
+    pub fn get_assigned_arbiter(env: Env, dispute_id: u64) -> Option<Address> {
+        storage::read(&env, &DataKey::AssignedArbiter(dispute_id))
+    }
+
+    pub fn get_arbiter_registry(env: Env) -> Vec<Address> {
+        registry(&env)
+    }
+}
@@ -0,0 +1,220 @@
+// 🏛️ PROJECT BAZAAR | JUROR ARBITRATION
+// Gold-tier merchants stake BZR to sit on dispute panels. An escalated dispute draws
+// three jurors from the pool; the majority ruling settles the escrow and the jurors
+// who voted with it share a cut of the disputed amount. Jurors vote only after
+// acknowledging the evidence on file.
+
+use super::*;
+
+const PANEL_SIZE: u32 = 3;
+const PANEL_MAJORITY: u32 = 2;
+const MAX_JURORS: u32 = 100;
+const JUROR_MIN_TIER: TrustTier = TrustTier::Gold;
+const JUROR_VOTE_SECONDS: u64 = 604_800; // 7 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Juror {
+    pub stake: i128,
+    pub registered_at: u64,
+    pub active_cases: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Panel {
+    pub dispute_id: u64,
+    pub jurors: Vec<Address>,
+    pub votes: Map<Address, bool>,
+    pub deadline: u64,
+}
+
+fn load_juror(env: &Env, juror: &Address) -> Result<Juror, Error> {
+    storage::read(env, &DataKey::Juror(juror.clone())).ok_or(Error::NotJuror)
+}
+
+pub(crate) fn load_panel(env: &Env, dispute_id: u64) -> Result<Panel, Error> {
+    storage::read(env, &DataKey::Panel(dispute_id)).ok_or(Error::PanelNotFound)
+}
+
+fn juror_pool(env: &Env) -> Vec<Address> {
+    storage::read(env, &DataKey::JurorPool).unwrap_or(Vec::new(env))
+}
+
+/// Frees every juror on the panel for new cases and drops the panel itself.
+fn close_panel(env: &Env, panel: &Panel) {
+    for juror in panel.jurors.iter() {
+        if let Ok(mut record) = load_juror(env, &juror) {
+            record.active_cases = record.active_cases.saturating_sub(1);
+            storage::write(env, &DataKey::Juror(juror.clone()), &record);
+        }
+    }
+    storage::remove(env, &DataKey::Panel(panel.dispute_id));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 9: ARBITRATION ---
+
+    pub fn register_juror(env: Env, juror: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        juror.require_auth();
+        touch_heartbeat(&env, &juror);
+        if storage::has(&env, &DataKey::Juror(juror.clone())) { return Err(Error::AlreadyJuror); }
+        if tier_of(current_trust(&env, &juror)) < JUROR_MIN_TIER { return Err(Error::JurorTrustTooLow); }
+
+        let mut pool = juror_pool(&env);
+        if pool.len() >= MAX_JURORS { return Err(Error::TooManyJurors); }
+
+        let stake = admin::protocol_config(&env)?.juror_stake;
+        if stake > 0 {
+            token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&juror, &env.current_contract_address(), &stake);
+        }
+
+        let record = Juror { stake, registered_at: env.ledger().timestamp(), active_cases: 0 };
+        storage::write(&env, &DataKey::Juror(juror.clone()), &record);
+        pool.push_back(juror.clone());
+        storage::write(&env, &DataKey::JurorPool, &pool);
+        env.events().publish((events::JUROR_JOINED, juror), stake);
+        Ok(())
+    }
+
+    /// Returns the juror stake. Not possible while sitting on an undecided panel.
+    pub fn unregister_juror(env: Env, juror: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        juror.require_auth();
+        touch_heartbeat(&env, &juror);
+        require_not_frozen(&env, &juror)?;
+        let record = load_juror(&env, &juror)?;
+        if record.active_cases > 0 { return Err(Error::JurorBusy); }
+
+        let mut pool = juror_pool(&env);
+        if let Some(index) = pool.first_index_of(&juror) { pool.remove(index); }
+        storage::write(&env, &DataKey::JurorPool, &pool);
+        storage::remove(&env, &DataKey::Juror(juror.clone()));
+
+        if record.stake > 0 {
+            token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &juror, &record.stake);
+        }
+        env.events().publish((events::JUROR_LEFT, juror), record.stake);
+        Ok(record.stake)
+    }
+
+    /// Either party moves an open dispute from the arbiters to a randomly drawn panel.
+    /// Selection uses the ledger PRNG, which is fine for assignment but not for secrets.
+    pub fn escalate_dispute(env: Env, caller: Address, dispute_id: u64) -> Result<Vec<Address>, Error> {
+        require_not_paused(&env)?;
+        caller.require_auth();
+        touch_heartbeat(&env, &caller);
+        let dispute = disputes::load_dispute(&env, dispute_id)?;
+        if caller != dispute.buyer && caller != dispute.merchant { return Err(Error::NotOrderParty); }
+        if dispute.status != DisputeStatus::Open { return Err(Error::DisputeClosed); }
+        if storage::has(&env, &DataKey::Panel(dispute_id)) { return Err(Error::DisputeEscalated); }
+        if storage::has(&env, &DataKey::AssignedArbiter(dispute_id)) { return Err(Error::ArbiterAlreadyAssigned); }
+
+        let mut candidates = Vec::new(&env);
+        for juror in juror_pool(&env).iter() {
+            if juror == dispute.buyer || juror == dispute.merchant { continue; }
+            if tier_of(current_trust(&env, &juror)) < JUROR_MIN_TIER { continue; }
+            candidates.push_back(juror);
+        }
+        if candidates.len() < PANEL_SIZE { return Err(Error::NotEnoughJurors); }
+
+        env.prng().shuffle(&mut candidates);
+        let jurors = candidates.slice(0..PANEL_SIZE);
+        for juror in jurors.iter() {
+            let mut record = load_juror(&env, &juror)?;
+            record.active_cases += 1;
+            storage::write(&env, &DataKey::Juror(juror.clone()), &record);
+        }
+
+        let panel = Panel {
+            dispute_id,
+            jurors: jurors.clone(),
+            votes: Map::new(&env),
+            deadline: env.ledger().timestamp() + JUROR_VOTE_SECONDS,
+        };
+        storage::write(&env, &DataKey::Panel(dispute_id), &panel);
+        env.events().publish((events::PANEL_DRAWN, dispute.merchant), (dispute_id, jurors.clone()));
+        Ok(jurors)
+    }
+
+    pub fn juror_vote(env: Env, juror: Address, dispute_id: u64, buyer_wins: bool) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        juror.require_auth();
+        touch_heartbeat(&env, &juror);
+        let mut panel = load_panel(&env, dispute_id)?;
+        if !panel.jurors.contains(&juror) { return Err(Error::NotJuror); }
+        if env.ledger().timestamp() > panel.deadline { return Err(Error::VotingClosed); }
+        if panel.votes.contains_key(juror.clone()) { return Err(Error::AlreadyVoted); }
+        evidence::require_acknowledged(&env, dispute_id, &juror)?;
+
+        panel.votes.set(juror.clone(), buyer_wins);
+        storage::write(&env, &DataKey::Panel(dispute_id), &panel);
+        env.events().publish((events::JUROR_VOTE, juror), (dispute_id, buyer_wins));
+        Ok(())
+    }
+
+    /// Anyone may settle a panel once a majority agrees or the deadline passes. Without
+    /// a majority by the deadline the panel dissolves and the arbiters take over again.
+    /// Returns the jurors' total reward, split between those who voted with the majority.
+    pub fn finalize_panel(env: Env, dispute_id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        let panel = load_panel(&env, dispute_id)?;
+        let mut dispute = disputes::load_dispute(&env, dispute_id)?;
+
+        let (mut for_buyer, mut for_merchant) = (0u32, 0u32);
+        for (_, vote) in panel.votes.iter() {
+            if vote { for_buyer += 1; } else { for_merchant += 1; }
+        }
+        let majority = if for_buyer >= PANEL_MAJORITY { Some(true) } else if for_merchant >= PANEL_MAJORITY { Some(false) } else { None };
+        let buyer_wins = match majority {
+            Some(buyer_wins) => buyer_wins,
+            None if env.ledger().timestamp() > panel.deadline => {
+                close_panel(&env, &panel);
+                env.events().publish((events::PANEL_DISSOLVED, dispute.merchant), dispute_id);
+                return Ok(0);
+            }
+            None => return Err(Error::VotingOpen),
+        };
+
+        let mut winners = Vec::new(&env);
+        for (juror, vote) in panel.votes.iter() {
+            if vote == buyer_wins { winners.push_back(juror); }
+        }
+
+        let order = escrow::load_order(&env, dispute.order_id)?;
+        let reward_bps = admin::protocol_config(&env)?.juror_reward_bps;
+        let reward = order.amount * reward_bps as i128 / FULL_SHARE_BPS as i128;
+
+        close_panel(&env, &panel);
+        disputes::settle_dispute(&env, &mut dispute, buyer_wins, env.current_contract_address(), reward)?;
+
+        // Same dust rule as inheritance: the last winner takes the rounding remainder
+        if reward > 0 {
+            let client = token::Client::new(&env, &order.token);
+            let share = reward / winners.len() as i128;
+            let mut paid: i128 = 0;
+            for (i, juror) in winners.iter().enumerate() {
+                let amount = if i as u32 == winners.len() - 1 { reward - paid } else { share };
+                paid += amount;
+                client.transfer(&env.current_contract_address(), &juror, &amount);
+                env.events().publish((events::JUROR_REWARD, juror), (dispute_id, amount));
+            }
+        }
+        Ok(reward)
+    }
+
+    pub fn get_panel(env: Env, dispute_id: u64) -> Result<Panel, Error> {
+        load_panel(&env, dispute_id)
+    }
+
+    pub fn get_juror(env: Env, juror: Address) -> Result<Juror, Error> {
+        load_juror(&env, &juror)
+    }
+
+    pub fn get_juror_pool(env: Env) -> Vec<Address> {
+        juror_pool(&env)
+    }
+}
@@ -0,0 +1,98 @@
+// 🏛️ PROJECT BAZAAR | AWAY MODE
+// Sellers travelling home for a month shouldn't lose trust or receive orders they can't
+// fulfil. While away, a merchant's listings take no new orders and trust decay is
+// frozen; both resume on their own once the away period runs out. Under the hood the
+// away window is cut out of the decay timeline. One window lasts at most 60 days and a
+// new one can start 30 days after the last ended.
+
+use super::*;
+
+const MAX_AWAY_SECONDS: u64 = 5_184_000;   // 60 Days
+const AWAY_COOLDOWN_SECONDS: u64 = 2_592_000; // 30 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AwayWindow {
+    pub since: u64,
+    pub until: u64,
+}
+
+impl AwayWindow {
+    /// Maps real time onto the decay timeline, where the window takes no time at all.
+    fn squeeze(&self, t: u64) -> u64 {
+        if t <= self.since { t } else if t <= self.until { self.since } else { t - (self.until - self.since) }
+    }
+
+    /// Inverse of `squeeze`, landing after the window for points past its start.
+    fn unsqueeze(&self, t: u64) -> u64 {
+        if t <= self.since { t } else { t + (self.until - self.since) }
+    }
+}
+
+/// The latest away window, running or over.
+fn window(env: &Env, user: &Address) -> Option<AwayWindow> {
+    storage::read(env, &DataKey::Away(user.clone()))
+}
+
+pub(crate) fn is_away(env: &Env, user: &Address) -> bool {
+    window(env, user).is_some_and(|window| env.ledger().timestamp() < window.until)
+}
+
+/// `decayed` with the latest away window taken out of the elapsed time.
+pub(crate) fn decayed_for(env: &Env, user: &Address, score: u32, last_activity: u64, now: u64) -> (u32, u64) {
+    let Some(window) = window(env, user) else { return decayed(score, last_activity, now); };
+    let start = window.squeeze(last_activity);
+    let (score, checkpoint) = decayed(score, start, window.squeeze(now));
+    if checkpoint == start { return (score, last_activity); }
+    (score, window.unsqueeze(checkpoint))
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 45: AWAY MODE ---
+
+    /// Starts or extends an away period ending at `until`. An `until` in the past ends a
+    /// running one now.
+    pub fn set_away(env: Env, merchant: Address, until: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        let now = env.ledger().timestamp();
+        let current = window(&env, &merchant);
+
+        let next = match current.clone() {
+            Some(window) if now < window.until => {
+                let until = until.max(now);
+                if until - window.since > MAX_AWAY_SECONDS { return Err(Error::InvalidAwayPeriod); }
+                AwayWindow { since: window.since, until }
+            }
+            _ if until <= now => return Err(Error::NotAway),
+            Some(window) if now < window.until + AWAY_COOLDOWN_SECONDS => return Err(Error::AwayCooldown),
+            _ => {
+                if until - now > MAX_AWAY_SECONDS { return Err(Error::InvalidAwayPeriod); }
+                // The old window is about to be replaced, so bake its effect into the
+                // checkpoint: keep just the decay time that has really counted since.
+                if let (Some(old), true) = (current, storage::has(&env, &DataKey::Merchant(merchant.clone()))) {
+                    let mut profile = load_merchant_for_update(&env, &merchant);
+                    if profile.last_activity != 0 {
+                        profile.last_activity = now - (old.squeeze(now) - old.squeeze(profile.last_activity));
+                    }
+                    write_merchant(&env, &merchant, &profile);
+                }
+                AwayWindow { since: now, until }
+            }
+        };
+        storage::write(&env, &DataKey::Away(merchant.clone()), &next);
+        env.events().publish((events::AWAY_SET, merchant), (next.since, next.until));
+        Ok(())
+    }
+
+    pub fn is_away(env: Env, merchant: Address) -> bool {
+        is_away(&env, &merchant)
+    }
+
+    pub fn get_away(env: Env, merchant: Address) -> Option<AwayWindow> {
+        window(&env, &merchant)
+    }
+}
@@ -0,0 +1,156 @@
+// 🏛️ PROJECT BAZAAR | MERCHANT BADGES
+// Milestone badges are awarded automatically whenever a merchant's profile changes;
+// the admin can grant special badges or revoke any badge by hand. Each badge records when
+// it was issued, and if the admin names a badge minter, every issue and revocation is
+// mirrored as a soulbound token so other dApps can gate features on Bazaar achievements.
+
+use super::*;
+use soroban_sdk::{contractclient, symbol_short};
+
+pub const BADGE_BONDED: Symbol = symbol_short!("bonded");
+pub const BADGE_TRUSTED: Symbol = symbol_short!("trusted");
+pub const BADGE_SELLER_100: Symbol = symbol_short!("seller100");
+pub const BADGE_ONE_YEAR: Symbol = symbol_short!("one_year");
+/// Only ever set through `verify_merchant`.
+pub const BADGE_VERIFIED: Symbol = symbol_short!("verified");
+/// Earned the first time a savings goal reaches its target.
+pub const BADGE_GOAL_MET: Symbol = symbol_short!("saver");
+/// Earned by witnesses who answer most of the emergencies and panics they are called to.
+pub const BADGE_GUARDIAN: Symbol = symbol_short!("guardian");
+
+const TRUSTED_BADGE_SCORE: u32 = 50;
+const SELLER_BADGE_ORDERS: u32 = 100;
+const TENURE_BADGE_SECONDS: u64 = 31_536_000; // 365 Days
+
+/// What this contract expects from a badge minter. The minter must refuse transfers of
+/// the tokens it mints; only this contract is meant to call it.
+#[contractclient(name = "BadgeMinterClient")]
+pub trait BadgeMinter {
+    fn mint_badge(env: Env, to: Address, badge: Symbol);
+    fn burn_badge(env: Env, from: Address, badge: Symbol);
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BadgeInfo {
+    pub badge: Symbol,
+    /// `None` for badges issued before issuance times were recorded.
+    pub issued_at: Option<u64>,
+}
+
+fn badge_minter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::BadgeMinter)
+}
+
+/// Adds the badge to the in-memory profile and mirrors it to the minter. A failing minter
+/// never blocks the order, review or vote that earned the badge.
+pub(crate) fn issue(env: &Env, user: &Address, merchant: &mut Merchant, badge: Symbol) {
+    merchant.badges.push_back(badge.clone());
+    storage::write(env, &DataKey::BadgeIssued(user.clone(), badge.clone()), &env.ledger().timestamp());
+    if let Some(minter) = badge_minter(env) {
+        let _ = BadgeMinterClient::new(env, &minter).try_mint_badge(user, &badge);
+    }
+    env.events().publish((events::BADGE_AWARDED, user.clone()), badge);
+}
+
+/// Removes the badge at `index` from the in-memory profile and burns its token. The caller
+/// publishes the event, since a revocation and a verification downgrade read differently.
+pub(crate) fn retract(env: &Env, user: &Address, merchant: &mut Merchant, index: u32) {
+    let Some(badge) = merchant.badges.get(index) else { return; };
+    merchant.badges.remove(index);
+    storage::remove(env, &DataKey::BadgeIssued(user.clone(), badge.clone()));
+    if let Some(minter) = badge_minter(env) {
+        let _ = BadgeMinterClient::new(env, &minter).try_burn_badge(user, &badge);
+    }
+}
+
+/// Carries issuance times and soulbound tokens over to a recovered account. Runs before
+/// the merchant record itself is renamed.
+pub(crate) fn move_badges(env: &Env, old: &Address, new: &Address) {
+    let minter = badge_minter(env);
+    for badge in load_merchant(env, old).badges.iter() {
+        storage::rename(env, &DataKey::BadgeIssued(old.clone(), badge.clone()), &DataKey::BadgeIssued(new.clone(), badge.clone()));
+        if let Some(minter) = &minter {
+            let client = BadgeMinterClient::new(env, minter);
+            let _ = client.try_burn_badge(old, &badge);
+            let _ = client.try_mint_badge(new, &badge);
+        }
+    }
+}
+
+pub(crate) fn award(env: &Env, user: &Address, merchant: &mut Merchant, badge: Symbol) {
+    if merchant.badges.contains(&badge) { return; }
+    // An admin revocation sticks until the badge is granted again by hand
+    if storage::has(env, &DataKey::BadgeRevoked(user.clone(), badge.clone())) { return; }
+    issue(env, user, merchant, badge);
+}
+
+/// Evaluates every milestone against the in-memory profile. The caller persists it.
+pub(crate) fn check_and_award_badges(env: &Env, user: &Address, merchant: &mut Merchant) {
+    if merchant.bond_staked { award(env, user, merchant, BADGE_BONDED); }
+    if merchant.trust_score >= TRUSTED_BADGE_SCORE { award(env, user, merchant, BADGE_TRUSTED); }
+    if merchant.completed_orders >= SELLER_BADGE_ORDERS { award(env, user, merchant, BADGE_SELLER_100); }
+    if env.ledger().timestamp() >= merchant.joined_at + TENURE_BADGE_SECONDS { award(env, user, merchant, BADGE_ONE_YEAR); }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 5: BADGES ---
+
+    pub fn grant_badge(env: Env, user: Address, badge: Symbol) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        let mut merchant = load_merchant_for_update(&env, &user);
+        if merchant.badges.contains(&badge) { return Err(Error::BadgeAlreadyHeld); }
+
+        storage::remove(&env, &DataKey::BadgeRevoked(user.clone(), badge.clone()));
+        issue(&env, &user, &mut merchant, badge);
+        write_merchant(&env, &user, &merchant);
+        Ok(())
+    }
+
+    pub fn revoke_badge(env: Env, user: Address, badge: Symbol) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        let mut merchant = load_merchant_for_update(&env, &user);
+        let index = merchant.badges.first_index_of(&badge).ok_or(Error::BadgeNotHeld)?;
+
+        retract(&env, &user, &mut merchant, index);
+        write_merchant(&env, &user, &merchant);
+        storage::write(&env, &DataKey::BadgeRevoked(user.clone(), badge.clone()), &true);
+        env.events().publish((events::BADGE_REVOKED, user), badge);
+        Ok(())
+    }
+
+    pub fn get_badges(env: Env, user: Address) -> Vec<Symbol> {
+        load_merchant(&env, &user).badges
+    }
+
+    pub fn has_badge(env: Env, user: Address, badge: Symbol) -> bool {
+        load_merchant(&env, &user).badges.contains(&badge)
+    }
+
+    /// Held badges with their issuance times, in the order they were earned.
+    pub fn get_badge_info(env: Env, user: Address) -> Vec<BadgeInfo> {
+        let mut info = Vec::new(&env);
+        for badge in load_merchant(&env, &user).badges.iter() {
+            let issued_at = storage::read(&env, &DataKey::BadgeIssued(user.clone(), badge.clone()));
+            info.push_back(BadgeInfo { badge, issued_at });
+        }
+        info
+    }
+
+    /// `None` stops mirroring. Badges held before a minter is named are not backfilled.
+    pub fn set_badge_minter(env: Env, minter: Option<Address>) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        match &minter {
+            Some(minter) => env.storage().instance().set(&DataKey::BadgeMinter, minter),
+            None => env.storage().instance().remove(&DataKey::BadgeMinter),
+        }
+        env.events().publish((events::BADGE_MINTER_SET, admin), minter);
+        Ok(())
+    }
+
+    pub fn get_badge_minter(env: Env) -> Option<Address> {
+        badge_minter(&env)
+    }
+}
@@ -0,0 +1,58 @@
+// 🏛️ PROJECT BAZAAR | DENY LIST
+// Compliance deny list ahead of fiat on/off-ramps. A listed address cannot open orders
+// or trade as the merchant on one, cannot vouch, cannot claim a vault, and can't have its
+// account moved by social recovery, in either direction. The admin manages the list
+// directly and governance can through proposals. Reason codes are defined off-chain.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BlacklistEntry {
+    pub reason_code: u32,
+    pub listed_at: u64,
+}
+
+pub(crate) fn is_blacklisted(env: &Env, address: &Address) -> bool {
+    storage::has(env, &DataKey::Blacklist(address.clone()))
+}
+
+pub(crate) fn require_not_blacklisted(env: &Env, address: &Address) -> Result<(), Error> {
+    if is_blacklisted(env, address) { return Err(Error::Blacklisted); }
+    Ok(())
+}
+
+pub(crate) fn add(env: &Env, address: &Address, reason_code: u32) {
+    let entry = BlacklistEntry { reason_code, listed_at: env.ledger().timestamp() };
+    storage::write(env, &DataKey::Blacklist(address.clone()), &entry);
+    env.events().publish((events::BLACKLISTED, address.clone()), reason_code);
+}
+
+pub(crate) fn remove(env: &Env, address: &Address) {
+    storage::remove(env, &DataKey::Blacklist(address.clone()));
+    env.events().publish((events::UNBLACKLISTED, address.clone()), ());
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 46: DENY LIST ---
+
+    /// Listing an address again replaces its reason code.
+    pub fn blacklist(env: Env, address: Address, reason_code: u32) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        add(&env, &address, reason_code);
+        Ok(())
+    }
+
+    pub fn unblacklist(env: Env, address: Address) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        if !is_blacklisted(&env, &address) { return Err(Error::NotBlacklisted); }
+        remove(&env, &address);
+        Ok(())
+    }
+
+    pub fn get_blacklist_status(env: Env, address: Address) -> Option<BlacklistEntry> {
+        storage::read(&env, &DataKey::Blacklist(address))
+    }
+}
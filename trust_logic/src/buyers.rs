@@ -0,0 +1,97 @@
+// 🏛️ PROJECT BAZAAR | BUYER TRUST
+// Merchants screen buyers too, especially for high-value custom orders. Buyers earn
+// their own score from completed orders and prompt escrow funding, and lose it when an
+// arbiter or panel rules against them or an order they opened lapses unfunded. Kept
+// apart from the merchant score, since one address can be both.
+
+use super::*;
+
+const BUYER_ORDER_POINTS: u32 = 2;
+const ON_TIME_FUNDING_POINTS: u32 = 1;
+const DISPUTE_LOST_PENALTY: u32 = 10;
+const LAPSE_PENALTY: u32 = 3;
+const ON_TIME_FUNDING_SECONDS: u64 = 86_400; // 1 Day
+
+#[contracttype]
+#[derive(Clone)]
+pub struct BuyerProfile {
+    pub trust_score: u32,
+    pub completed_orders: u32,
+    pub on_time_fundings: u32,
+    pub disputes_won: u32,
+    pub disputes_lost: u32,
+    pub lapsed_orders: u32,
+    pub last_activity: u64,
+}
+
+fn load_buyer(env: &Env, buyer: &Address) -> BuyerProfile {
+    storage::read(env, &DataKey::BuyerProfile(buyer.clone())).unwrap_or(BuyerProfile {
+        trust_score: 0,
+        completed_orders: 0,
+        on_time_fundings: 0,
+        disputes_won: 0,
+        disputes_lost: 0,
+        lapsed_orders: 0,
+        last_activity: 0,
+    })
+}
+
+fn write_buyer(env: &Env, buyer: &Address, profile: &mut BuyerProfile, before: u32) {
+    profile.last_activity = env.ledger().timestamp();
+    storage::write(env, &DataKey::BuyerProfile(buyer.clone()), profile);
+    if profile.trust_score != before {
+        env.events().publish((events::BUYER_TRUST_CHANGED, buyer.clone()), profile.trust_score);
+    }
+}
+
+/// Funding within a day of opening the order earns a point.
+pub(crate) fn record_funding(env: &Env, buyer: &Address, created_at: u64) {
+    if env.ledger().timestamp() > created_at.saturating_add(ON_TIME_FUNDING_SECONDS) { return; }
+    let mut profile = load_buyer(env, buyer);
+    let before = profile.trust_score;
+    profile.on_time_fundings += 1;
+    profile.trust_score = (profile.trust_score + ON_TIME_FUNDING_POINTS).min(MAX_TRUST_SCORE);
+    write_buyer(env, buyer, &mut profile, before);
+}
+
+pub(crate) fn record_completion(env: &Env, buyer: &Address) {
+    let mut profile = load_buyer(env, buyer);
+    let before = profile.trust_score;
+    profile.completed_orders += 1;
+    profile.trust_score = (profile.trust_score + BUYER_ORDER_POINTS).min(MAX_TRUST_SCORE);
+    write_buyer(env, buyer, &mut profile, before);
+}
+
+pub(crate) fn record_dispute(env: &Env, buyer: &Address, buyer_won: bool) {
+    let mut profile = load_buyer(env, buyer);
+    let before = profile.trust_score;
+    if buyer_won {
+        profile.disputes_won += 1;
+    } else {
+        profile.disputes_lost += 1;
+        profile.trust_score = profile.trust_score.saturating_sub(DISPUTE_LOST_PENALTY);
+    }
+    write_buyer(env, buyer, &mut profile, before);
+}
+
+pub(crate) fn record_lapse(env: &Env, buyer: &Address) {
+    let mut profile = load_buyer(env, buyer);
+    let before = profile.trust_score;
+    profile.lapsed_orders += 1;
+    profile.trust_score = profile.trust_score.saturating_sub(LAPSE_PENALTY);
+    write_buyer(env, buyer, &mut profile, before);
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 37: BUYER TRUST ---
+
+    pub fn get_buyer_trust(env: Env, buyer: Address) -> u32 {
+        load_buyer(&env, &buyer).trust_score
+    }
+
+    pub fn get_buyer_profile(env: Env, buyer: Address) -> BuyerProfile {
+        load_buyer(&env, &buyer)
+    }
+}
@@ -0,0 +1,83 @@
+// 🏛️ PROJECT BAZAAR | BZR WALLET
+// An in-app BZR balance per user, backed one-to-one by BZR the contract holds: it is only
+// credited by a deposit and only shrinks by a withdrawal or an internal transfer, so the
+// books never exceed the tokens behind them. Internal transfers move no tokens and pay
+// no fee. Deny-listed addresses can't send or receive internal transfers.
+
+use super::*;
+
+pub(crate) fn bzr_balance(env: &Env, user: &Address) -> i128 {
+    storage::read(env, &DataKey::BzrBalance(user.clone())).unwrap_or(0)
+}
+
+/// Books BZR the contract already holds to `user`.
+pub(crate) fn credit(env: &Env, user: &Address, amount: i128) {
+    if amount <= 0 { return; }
+    storage::write(env, &DataKey::BzrBalance(user.clone()), &(bzr_balance(env, user) + amount));
+}
+
+/// Takes `amount` off the user's books; the tokens stay in the contract for the caller to move.
+pub(crate) fn debit(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+    let balance = bzr_balance(env, user);
+    if amount > balance { return Err(Error::InsufficientBalance); }
+    let key = DataKey::BzrBalance(user.clone());
+    if balance == amount { storage::remove(env, &key); } else { storage::write(env, &key, &(balance - amount)); }
+    Ok(())
+}
+
+/// Adds `old`'s balance to `new`'s, which may already hold BZR of its own.
+pub(crate) fn merge_balance(env: &Env, old: &Address, new: &Address) {
+    let amount = bzr_balance(env, old);
+    if amount <= 0 { return; }
+    storage::remove(env, &DataKey::BzrBalance(old.clone()));
+    credit(env, new, amount);
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 49: BZR WALLET ---
+
+    pub fn deposit_bzr(env: Env, user: Address, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&user, &env.current_contract_address(), &amount);
+        credit(&env, &user, amount);
+        env.events().publish((events::BZR_DEPOSITED, user.clone()), amount);
+        Ok(bzr_balance(&env, &user))
+    }
+
+    pub fn transfer_bzr_internal(env: Env, from: Address, to: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        from.require_auth();
+        touch_heartbeat(&env, &from);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if from == to { return Err(Error::BzrTransferToSelf); }
+        blacklist::require_not_blacklisted(&env, &from)?;
+        blacklist::require_not_blacklisted(&env, &to)?;
+
+        debit(&env, &from, amount)?;
+        credit(&env, &to, amount);
+        env.events().publish((events::BZR_TRANSFERRED, from, to), amount);
+        Ok(())
+    }
+
+    pub fn withdraw_bzr(env: Env, user: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        debit(&env, &user, amount)?;
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &user, &amount);
+        env.events().publish((events::BZR_WITHDRAWN, user), amount);
+        Ok(())
+    }
+
+    pub fn get_bzr_balance(env: Env, user: Address) -> i128 {
+        bzr_balance(&env, &user)
+    }
+}
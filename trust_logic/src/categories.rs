@@ -0,0 +1,42 @@
+// 🏛️ PROJECT BAZAAR | CATEGORY TRUST
+// A merchant great at electronics may be new to food. Orders opened against a listing
+// with a category also earn a sub-score in that category. The global score still
+// counts every completed order, so it stays the order-weighted aggregate of the
+// sub-scores plus the bond, vouch and remittance points that belong to no category.
+
+use super::*;
+
+const CATEGORY_TRUST_POINTS: u32 = 1;
+const MAX_CATEGORIES: u32 = 16;
+
+fn category_scores(env: &Env, merchant: &Address) -> Map<Symbol, u32> {
+    storage::read(env, &DataKey::CategoryTrust(merchant.clone())).unwrap_or(Map::new(env))
+}
+
+/// Credits a completed order to its category. Orders in a category beyond the first
+/// sixteen a merchant sold in only count towards the global score.
+pub(crate) fn record_completion(env: &Env, merchant: &Address, category: &Symbol) {
+    let mut scores = category_scores(env, merchant);
+    let current = scores.get(category.clone());
+    if current.is_none() && scores.len() >= MAX_CATEGORIES { return; }
+
+    let score = (current.unwrap_or(0) + CATEGORY_TRUST_POINTS).min(MAX_TRUST_SCORE);
+    scores.set(category.clone(), score);
+    storage::write(env, &DataKey::CategoryTrust(merchant.clone()), &scores);
+    env.events().publish((events::CATEGORY_TRUST_CHANGED, merchant.clone()), (category.clone(), score));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 38: CATEGORY TRUST ---
+
+    /// 0 for a category the merchant never completed an order in.
+    pub fn get_trust_by_category(env: Env, merchant: Address, category: Symbol) -> u32 {
+        category_scores(&env, &merchant).get(category).unwrap_or(0)
+    }
+
+    pub fn get_category_scores(env: Env, merchant: Address) -> Map<Symbol, u32> {
+        category_scores(&env, &merchant)
+    }
+}
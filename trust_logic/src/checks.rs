@@ -0,0 +1,122 @@
+// 🏛️ PROJECT BAZAAR | DRY RUNS
+// Read-only previews of the main state-changing calls. They never fail: each returns the
+// first reason the real call would be refused, or Ready, so wallets can explain a
+// disabled button instead of simulating a transaction and parsing the error.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClaimCheck {
+    Ready,
+    Paused,
+    NoVault,
+    AlreadyClaimed,
+    NoHeir,
+    HeirNotAccepted,
+    /// Seconds until the claimant's deadline passes.
+    TimerRemaining(u64),
+    /// Someone else already opened a claim.
+    ClaimPending,
+    /// The claimant's own claim is waiting out the challenge window.
+    ChallengeRemaining(u64),
+    /// Witness approvals still missing before the claim can finalize.
+    ApprovalsNeeded(u32),
+    /// The claimant is on the deny list.
+    Blacklisted,
+    /// Seconds until a claimant whose claim the circle vetoed may open a new one.
+    VetoRemaining(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmergencyCheck {
+    /// Share of each asset unlocked, in basis points.
+    Ready(u32),
+    Paused,
+    NoEmergency,
+    Expired,
+    Consumed,
+    /// Witness weight still missing from the medical quorum.
+    VotesNeeded(u32),
+    NoRecipient,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WithdrawCheck {
+    Ready,
+    Paused,
+    NoVault,
+    Frozen,
+    InsufficientBalance,
+    NeedsApproval,
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 36: DRY RUNS ---
+
+    /// Mirrors `initiate_claim`, then `finalize_claim` once the claimant's claim is open.
+    pub fn can_claim(env: Env, claimant: Address, target_user: Address, vault_id: u32) -> ClaimCheck {
+        if require_not_paused(&env).is_err() { return ClaimCheck::Paused; }
+        let Ok(vault) = load_vault(&env, &target_user, vault_id) else { return ClaimCheck::NoVault; };
+        if blacklist::is_blacklisted(&env, &claimant) { return ClaimCheck::Blacklisted; }
+        if vault.is_claimed { return ClaimCheck::AlreadyClaimed; }
+
+        let now = env.ledger().timestamp();
+        let opens_at = if vault.heirs.iter().any(|share| share.address == claimant) {
+            if !vault.accepted_heirs.contains(&claimant) { return ClaimCheck::HeirNotAccepted; }
+            claimable_at(&vault)
+        } else {
+            match vault.backup_heirs.first_index_of(&claimant) {
+                Some(index) => backup_claimable_at(&vault, index),
+                None => return ClaimCheck::NoHeir,
+            }
+        };
+        if now < opens_at { return ClaimCheck::TimerRemaining(opens_at - now); }
+
+        let cosigns = user_config::claim_cosigns(&env, &target_user);
+        match storage::read::<PendingClaim>(&env, &DataKey::PendingClaim(target_user.clone(), vault_id)) {
+            Some(claim) if claim.claimant != claimant => ClaimCheck::ClaimPending,
+            Some(claim) if now < claim.challenge_ends_at => ClaimCheck::ChallengeRemaining(claim.challenge_ends_at - now),
+            Some(claim) => match cosigns.saturating_sub(claims::live_approvals(&env, &target_user, &claim)) {
+                0 => ClaimCheck::Ready,
+                missing => ClaimCheck::ApprovalsNeeded(missing),
+            },
+            None => match claims::veto_lifts_at(&env, &target_user, vault_id, &claimant) {
+                Some(lifts_at) => ClaimCheck::VetoRemaining(lifts_at - now),
+                None => ClaimCheck::Ready,
+            },
+        }
+    }
+
+    /// Whether medical funds can flow from one of `target_user`'s vaults right now.
+    pub fn can_unlock_medical(env: Env, target_user: Address, vault_id: u32) -> EmergencyCheck {
+        if require_not_paused(&env).is_err() { return EmergencyCheck::Paused; }
+        let emergency = match load_emergency(&env, &target_user) {
+            Ok(emergency) => emergency,
+            Err(Error::EmergencyExpired) => return EmergencyCheck::Expired,
+            Err(_) => return EmergencyCheck::NoEmergency,
+        };
+        if emergency.is_consumed { return EmergencyCheck::Consumed; }
+        if !emergency.is_unlocked {
+            let quorum = circle_config(&env, &target_user).medical_quorum;
+            return EmergencyCheck::VotesNeeded(quorum.saturating_sub(emergency.votes_collected));
+        }
+        let Ok(vault) = load_vault(&env, &target_user, vault_id) else { return EmergencyCheck::NoEmergency; };
+        if medical_recipient(&env, &vault, &emergency).is_none() { return EmergencyCheck::NoRecipient; }
+        EmergencyCheck::Ready(emergency.unlocked_bps)
+    }
+
+    /// Mirrors `withdraw`; NeedsApproval means the amount must go through `request_withdrawal`.
+    pub fn can_withdraw(env: Env, user: Address, vault_id: u32, token: Address, amount: i128) -> WithdrawCheck {
+        if require_not_paused(&env).is_err() { return WithdrawCheck::Paused; }
+        let Ok(vault) = load_vault(&env, &user, vault_id) else { return WithdrawCheck::NoVault; };
+        if vault.is_frozen { return WithdrawCheck::Frozen; }
+        if amount <= 0 || amount > vault.balances.get(token.clone()).unwrap_or(0) { return WithdrawCheck::InsufficientBalance; }
+        if spending::require_within_limit(&env, &user, &token, amount).is_err() { return WithdrawCheck::NeedsApproval; }
+        WithdrawCheck::Ready
+    }
+}
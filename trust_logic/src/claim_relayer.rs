@@ -0,0 +1,60 @@
+// 🏛️ PROJECT BAZAAR | CLAIM RELAYER
+// Heirs often hold empty Stellar accounts and can't pay for the claim transaction. An
+// owner can name a relayer service for each vault: the heir only signs the auth entry,
+// the relayer submits and pays for the transaction, and a small share of every claimed
+// asset goes to the relayer in return. The share is capped at 2%.
+
+use super::*;
+
+const MAX_RELAYER_FEE_BPS: u32 = 200; // 2%
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimRelayer {
+    pub relayer: Address,
+    pub fee_bps: u32,
+}
+
+/// Pays the relayer's share of one claimed asset out of `amount` and returns the rest.
+pub(crate) fn take_fee(env: &Env, owner: &Address, vault_id: u32, token_id: &Address, amount: i128) -> i128 {
+    let Some(terms) = storage::read::<ClaimRelayer>(env, &DataKey::ClaimRelayer(owner.clone(), vault_id)) else { return amount; };
+    let fee = treasury::fee_on(amount, terms.fee_bps);
+    if fee <= 0 { return amount; }
+    token::Client::new(env, token_id).transfer(&env.current_contract_address(), &terms.relayer, &fee);
+    env.events().publish((events::RELAYER_PAID, owner.clone(), vault_id), (terms.relayer, token_id.clone(), fee));
+    amount - fee
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 48: CLAIM RELAYER ---
+
+    /// `None` removes the relayer. Terms can't change while a claim is open, so a heir
+    /// always finalizes under the terms they saw when claiming.
+    pub fn set_claim_relayer(env: Env, user: Address, vault_id: u32, relayer: Option<Address>, fee_bps: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        load_vault(&env, &user, vault_id)?;
+        if storage::has(&env, &DataKey::PendingClaim(user.clone(), vault_id)) { return Err(Error::ClaimPending); }
+        let key = DataKey::ClaimRelayer(user.clone(), vault_id);
+        match relayer {
+            Some(relayer) => {
+                if relayer == user || fee_bps > MAX_RELAYER_FEE_BPS { return Err(Error::InvalidRelayer); }
+                storage::write(&env, &key, &ClaimRelayer { relayer: relayer.clone(), fee_bps });
+                env.events().publish((events::RELAYER_SET, user, vault_id), (Some(relayer), fee_bps));
+            }
+            None => {
+                storage::remove(&env, &key);
+                env.events().publish((events::RELAYER_SET, user, vault_id), (None::<Address>, 0u32));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_claim_relayer(env: Env, user: Address, vault_id: u32) -> Option<ClaimRelayer> {
+        storage::read(&env, &DataKey::ClaimRelayer(user, vault_id))
+    }
+}
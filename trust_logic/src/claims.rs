@@ -0,0 +1,227 @@
+// 🏛️ PROJECT BAZAAR | LEGACY CLAIMS
+// Claims run in two steps: an heir opens a claim, and it only executes once a 14-day
+// challenge window passes without the owner or three witnesses vetoing it. A stolen
+// phone alone is no longer enough to drain a vault. Owners can also require witnesses
+// to approve the claim, so a stranger holding an heir's key can't collect either.
+
+use super::*;
+
+const CLAIM_VETO_QUORUM: u32 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingClaim {
+    pub claimant: Address,
+    pub is_backup: bool,
+    pub initiated_at: u64,
+    pub challenge_ends_at: u64,
+    pub vetoes: Vec<Address>,
+    pub approvals: Vec<Address>,
+}
+
+/// Checks the claimant may claim right now; returns whether they claim as a backup.
+/// A panic freeze doesn't block claims: it rewinds the timer so the heirs can claim early.
+fn check_eligibility(env: &Env, vault: &LegacyVault, claimant: &Address) -> Result<bool, Error> {
+    blacklist::require_not_blacklisted(env, claimant)?;
+    if vault.is_claimed { return Err(Error::VaultAlreadyClaimed); }
+    let now = env.ledger().timestamp();
+
+    if vault.heirs.iter().any(|share| share.address == *claimant) {
+        if !vault.accepted_heirs.contains(claimant) { return Err(Error::HeirNotAccepted); }
+        if now < claimable_at(vault) { return Err(Error::TimerNotExpired); }
+        return Ok(false);
+    }
+
+    // Fallback path: the primary heirs let the grace period lapse, so the whole vault goes to the backup
+    let index = vault.backup_heirs.first_index_of(claimant).ok_or(Error::NotHeir)?;
+    if now < backup_claimable_at(vault, index) { return Err(Error::TimerNotExpired); }
+    Ok(true)
+}
+
+/// Approvals that still count toward the owner's claim co-signs: a witness removed from
+/// the circle since approving no longer vouches for the claimant.
+pub(crate) fn live_approvals(env: &Env, owner: &Address, claim: &PendingClaim) -> u32 {
+    let active = active_witnesses(env, owner);
+    claim.approvals.iter().filter(|approver| active.contains(approver)).count() as u32
+}
+
+/// When `claimant` may claim again after the circle vetoed their last claim on this vault;
+/// `None` once a challenge window has passed since the veto, or if they were never vetoed.
+pub(crate) fn veto_lifts_at(env: &Env, owner: &Address, vault_id: u32, claimant: &Address) -> Option<u64> {
+    let (vetoed, vetoed_at) = storage::read::<(Address, u64)>(env, &DataKey::ClaimVetoed(owner.clone(), vault_id))?;
+    let lifts_at = vetoed_at + CLAIM_CHALLENGE_SECONDS;
+    (vetoed == *claimant && env.ledger().timestamp() < lifts_at).then_some(lifts_at)
+}
+
+/// Drops any open claim on `owner`'s vault, e.g. because the owner just proved they are alive.
+pub(crate) fn cancel_pending_claim(env: &Env, owner: &Address, vault_id: u32) {
+    let key = DataKey::PendingClaim(owner.clone(), vault_id);
+    let Some(claim) = storage::read::<PendingClaim>(env, &key) else { return; };
+    storage::remove(env, &key);
+    env.events().publish((events::CLAIM_BLOCKED, owner.clone(), vault_id), claim.claimant);
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 10: LEGACY CLAIMS ---
+
+    /// Opens the challenge window. Returns the timestamp from which `finalize_claim` works.
+    pub fn initiate_claim(env: Env, claimant: Address, target_user: Address, vault_id: u32) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        claimant.require_auth();
+        touch_heartbeat(&env, &claimant);
+        let vault = load_vault(&env, &target_user, vault_id)?;
+        let key = DataKey::PendingClaim(target_user.clone(), vault_id);
+        if storage::has(&env, &key) { return Err(Error::ClaimPending); }
+        let is_backup = check_eligibility(&env, &vault, &claimant)?;
+        if veto_lifts_at(&env, &target_user, vault_id, &claimant).is_some() { return Err(Error::ClaimVetoed); }
+
+        let now = env.ledger().timestamp();
+        let claim = PendingClaim {
+            claimant: claimant.clone(),
+            is_backup,
+            initiated_at: now,
+            challenge_ends_at: now + CLAIM_CHALLENGE_SECONDS,
+            vetoes: Vec::new(&env),
+            approvals: Vec::new(&env),
+        };
+        storage::write(&env, &key, &claim);
+        env.events().publish((events::CLAIM_OPENED, target_user, vault_id), (claimant, claim.challenge_ends_at));
+        Ok(claim.challenge_ends_at)
+    }
+
+    /// The owner cancels a claim outright; active witnesses need three vetoes between them,
+    /// after which the same claimant can't reopen it for one challenge window.
+    pub fn veto_claim(env: Env, vetoer: Address, target_user: Address, vault_id: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        vetoer.require_auth();
+        touch_heartbeat(&env, &vetoer);
+        let key = DataKey::PendingClaim(target_user.clone(), vault_id);
+        let mut claim: PendingClaim = storage::read(&env, &key).ok_or(Error::NoPendingClaim)?;
+
+        if vetoer == target_user {
+            cancel_pending_claim(&env, &target_user, vault_id);
+            return Ok(());
+        }
+
+        require_witness(&env, &vetoer, &target_user)?;
+        if claim.vetoes.contains(&vetoer) { return Err(Error::AlreadyVoted); }
+        claim.vetoes.push_back(vetoer.clone());
+        env.events().publish((events::CLAIM_VETOED, target_user.clone(), vault_id), vetoer);
+
+        if claim.vetoes.len() >= CLAIM_VETO_QUORUM {
+            cancel_pending_claim(&env, &target_user, vault_id);
+            storage::write(&env, &DataKey::ClaimVetoed(target_user.clone(), vault_id), &(claim.claimant, env.ledger().timestamp()));
+        } else {
+            storage::write(&env, &key, &claim);
+        }
+        Ok(())
+    }
+
+    /// An active witness vouches that the claimant really is the heir. Only counted when
+    /// the owner asked for claim co-signs in their user config, and only while the witness
+    /// stays in the circle. Returns the approvals that currently count.
+    pub fn approve_claim(env: Env, witness: Address, target_user: Address, vault_id: u32) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        require_witness(&env, &witness, &target_user)?;
+        let key = DataKey::PendingClaim(target_user.clone(), vault_id);
+        let mut claim: PendingClaim = storage::read(&env, &key).ok_or(Error::NoPendingClaim)?;
+        if claim.approvals.contains(&witness) { return Err(Error::AlreadyVoted); }
+
+        claim.approvals.push_back(witness.clone());
+        storage::write(&env, &key, &claim);
+        let approvals = live_approvals(&env, &target_user, &claim);
+        env.events().publish((events::CLAIM_APPROVED, target_user, vault_id), (witness, approvals));
+        Ok(approvals)
+    }
+
+    /// Executes an unchallenged claim. Eligibility is checked again, so a panic freeze raised
+    /// during the window holds it until the rewound deadline. A claim ends the freeze.
+    pub fn finalize_claim(env: Env, claimant: Address, target_user: Address, vault_id: u32) -> Result<Map<Address, i128>, Error> {
+        require_not_paused(&env)?;
+        claimant.require_auth();
+        touch_heartbeat(&env, &claimant);
+        let key = DataKey::PendingClaim(target_user.clone(), vault_id);
+        let claim: PendingClaim = storage::read(&env, &key).ok_or(Error::NoPendingClaim)?;
+        if claim.claimant != claimant { return Err(Error::NotClaimant); }
+        if env.ledger().timestamp() < claim.challenge_ends_at { return Err(Error::ChallengeActive); }
+        if live_approvals(&env, &target_user, &claim) < user_config::claim_cosigns(&env, &target_user) { return Err(Error::ClaimCosignRequired); }
+
+        let mut vault = load_vault(&env, &target_user, vault_id)?;
+        let is_backup = check_eligibility(&env, &vault, &claimant)?;
+
+        // Checks-effects-interactions: the claim is marked done before any other contract is
+        // called, so a re-entrant call finds the vault already claimed.
+        storage::remove(&env, &key);
+        vault.is_claimed = true;
+        vault.is_locked = false;
+        vault.is_frozen = false;
+        vault.pre_panic_heartbeat = None;
+        vault.frozen_at = None;
+        write_vault(&env, &target_user, vault_id, &vault);
+
+        // Bring parked funds home, then empty the vault before paying out.
+        // Excluded assets are not inherited and stay behind.
+        strategies::unwind_all(&env, &target_user, vault_id, &mut vault);
+        let routing = routing::token_routing(&env, &target_user, vault_id);
+        let mut claimed = Map::new(&env);
+        let mut kept = Map::new(&env);
+        for (token_id, amount) in vault.balances.iter() {
+            if routing.get(token_id.clone()) == Some(TokenRoute::Exclude) { kept.set(token_id, amount); } else { claimed.set(token_id, amount); }
+        }
+        vault.balances = kept;
+        write_vault(&env, &target_user, vault_id, &vault);
+
+        let claim_bps = treasury::fee_config(&env).claim_bps;
+        for (token_id, gross) in claimed.iter() {
+            let fee = treasury::fee_on(gross, claim_bps);
+            treasury::credit(&env, &token_id, &target_user, fee);
+            let amount = claim_relayer::take_fee(&env, &target_user, vault_id, &token_id, gross - fee);
+            let routed_heir = match routing.get(token_id.clone()) {
+                Some(TokenRoute::Heir(heir)) if vault.heirs.iter().any(|share| share.address == heir) => Some(heir),
+                _ => None,
+            };
+            if is_backup {
+                vesting::pay_or_vest(&env, &target_user, vault_id, &claimant, &token_id, amount);
+            } else if let Some(heir) = routed_heir {
+                vesting::pay_or_vest(&env, &target_user, vault_id, &heir, &token_id, amount);
+            } else {
+                for (heir, portion) in split_among_heirs(&env, &vault.heirs, amount).iter() {
+                    vesting::pay_or_vest(&env, &target_user, vault_id, &heir, &token_id, portion);
+                }
+            }
+            env.events().publish((events::LEGACY_CLAIMED, target_user.clone(), vault_id), (claimant.clone(), token_id, amount));
+        }
+        legacy_note::release_note(&env, &target_user, &claimant);
+        Ok(claimed)
+    }
+
+    /// The owner "coming back from the dead" after a finalized claim: running vesting grants
+    /// stop, the unvested remainder returns to the vault, and the vault is armed again.
+    /// Lump-sum payouts already made are not clawed back.
+    pub fn reclaim_vault(env: Env, user: Address, vault_id: u32) -> Result<Map<Address, i128>, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        if !vault.is_claimed { return Err(Error::VaultNotClaimed); }
+
+        let returned = vesting::revoke_unvested(&env, &user, vault_id);
+        for (token_id, amount) in returned.iter() {
+            if amount > 0 { vault.balances.set(token_id.clone(), vault.balances.get(token_id).unwrap_or(0) + amount); }
+        }
+        vault.is_claimed = false;
+        vault.is_locked = true;
+        vault.last_heartbeat = env.ledger().timestamp();
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::VAULT_RECLAIMED, user, vault_id), returned.clone());
+        Ok(returned)
+    }
+
+    pub fn get_pending_claim(env: Env, target_user: Address, vault_id: u32) -> Option<PendingClaim> {
+        storage::read(&env, &DataKey::PendingClaim(target_user, vault_id))
+    }
+}
@@ -0,0 +1,44 @@
+// 🏛️ PROJECT BAZAAR | WITNESS CONTACTS
+// Witnesses can leave a pointer to an encrypted contact record (an IPFS digest), so an
+// off-chain notifier can reach the circle fast. When an emergency is declared or a panic
+// attempt opens, one alert event lists the pointers of every witness in the circle who
+// left one. The records themselves must be encrypted off-chain; only the hash is public.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+/// Publishes the circle's contact pointers; `kind` is the event topic that caused it.
+pub(crate) fn alert_circle(env: &Env, user: &Address, kind: Symbol) {
+    let mut contacts: Map<Address, BytesN<32>> = Map::new(env);
+    for member in load_circle(env, user).unwrap_or(Vec::new(env)).iter() {
+        if let Some(hash) = storage::read::<BytesN<32>>(env, &DataKey::WitnessContact(member.address.clone())) {
+            contacts.set(member.address, hash);
+        }
+    }
+    if contacts.is_empty() { return; }
+    env.events().publish((events::CIRCLE_ALERT, user.clone()), (kind, contacts));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 47: WITNESS CONTACTS ---
+
+    /// `None` clears it. The same pointer is used for every circle the witness sits in.
+    pub fn set_witness_contact(env: Env, witness: Address, contact_hash: Option<BytesN<32>>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        let key = DataKey::WitnessContact(witness.clone());
+        match contact_hash {
+            Some(hash) => storage::write(&env, &key, &hash),
+            None => storage::remove(&env, &key),
+        }
+        env.events().publish((events::CONTACT_SET, witness), storage::has(&env, &key));
+        Ok(())
+    }
+
+    pub fn get_witness_contact(env: Env, witness: Address) -> Option<BytesN<32>> {
+        storage::read(&env, &DataKey::WitnessContact(witness))
+    }
+}
@@ -0,0 +1,175 @@
+// 🏛️ PROJECT BAZAAR | DISPUTES & BOND SLASHING
+// Buyers open disputes against a merchant's escrowed order, an admin-appointed arbiter
+// rules, and a losing merchant is refunded against and forfeits part of their bond.
+// Recent vouch stakes behind a losing merchant are burned with it. Arbiters are either
+// appointed by the admin or registered with a stake (see `arbiters`).
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    BuyerWon,
+    MerchantWon,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Dispute {
+    pub id: u64,
+    pub buyer: Address,
+    pub merchant: Address,
+    pub order_id: u64,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    pub resolved_by: Option<Address>,
+    pub slashed: i128,
+}
+
+pub(crate) fn load_dispute(env: &Env, dispute_id: u64) -> Result<Dispute, Error> {
+    storage::read(env, &DataKey::Dispute(dispute_id)).ok_or(Error::DisputeNotFound)
+}
+
+pub(crate) fn is_arbiter(env: &Env, arbiter: &Address) -> bool {
+    storage::read(env, &DataKey::Arbiter(arbiter.clone())).unwrap_or(false)
+}
+
+fn set_open_disputes(env: &Env, merchant: &Address, count: u32) {
+    let mut profile = load_merchant_for_update(env, merchant);
+    profile.is_disputed = count > 0;
+    write_merchant(env, merchant, &profile);
+    storage::write(env, &DataKey::OpenDisputes(merchant.clone()), &count);
+}
+
+/// Applies a ruling from either an arbiter or a juror panel. `withheld` is kept back
+/// from the escrow payout so the caller can distribute it (juror rewards).
+pub(crate) fn settle_dispute(env: &Env, dispute: &mut Dispute, buyer_wins: bool, resolver: Address, withheld: i128) -> Result<i128, Error> {
+    let mut slashed: i128 = 0;
+    if buyer_wins {
+        let slash_bps = admin::protocol_config(env)?.slash_bps;
+        let mut profile = load_merchant_for_update(env, &dispute.merchant);
+        slashed = profile.staked_amount * slash_bps as i128 / FULL_SHARE_BPS as i128;
+        profile.staked_amount -= slashed;
+        write_merchant(env, &dispute.merchant, &profile);
+        discount_if_collapsed(env, &dispute.merchant, &profile);
+
+        let pool_key = DataKey::Compensation(dispute.buyer.clone());
+        let pool: i128 = storage::read(env, &pool_key).unwrap_or(0);
+        storage::write(env, &pool_key, &(pool + slashed));
+        env.events().publish((events::BOND_SLASHED, dispute.merchant.clone()), (dispute.id, slashed));
+        vouch_stakes::burn_recent(env, &dispute.merchant)?;
+    }
+
+    let mut order = escrow::load_order(env, dispute.order_id)?;
+    if buyer_wins { escrow::refund(env, &mut order, withheld); } else { escrow::release(env, &mut order, withheld); }
+
+    buyers::record_dispute(env, &dispute.buyer, buyer_wins);
+    dispute.status = if buyer_wins { DisputeStatus::BuyerWon } else { DisputeStatus::MerchantWon };
+    dispute.resolved_by = Some(resolver);
+    dispute.slashed = slashed;
+    storage::write(env, &DataKey::Dispute(dispute.id), dispute);
+
+    let open: u32 = storage::read(env, &DataKey::OpenDisputes(dispute.merchant.clone())).unwrap_or(0);
+    set_open_disputes(env, &dispute.merchant, open.saturating_sub(1));
+    env.events().publish((events::DISPUTE_RESOLVED, dispute.merchant.clone()), (dispute.id, buyer_wins));
+    Ok(slashed)
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 4: DISPUTES ---
+
+    pub fn add_arbiter(env: Env, arbiter: Address) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        storage::write(&env, &DataKey::Arbiter(arbiter.clone()), &true);
+        env.events().publish((events::ARBITER_SET, arbiter), true);
+        Ok(())
+    }
+
+    /// Also expels a registered arbiter, forfeiting their stake.
+    pub fn remove_arbiter(env: Env, arbiter: Address) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        arbiters::expel(&env, &arbiter)?;
+        storage::remove(&env, &DataKey::Arbiter(arbiter.clone()));
+        env.events().publish((events::ARBITER_SET, arbiter), false);
+        Ok(())
+    }
+
+    pub fn open_dispute(env: Env, buyer: Address, merchant: Address, order_id: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        if buyer == merchant { return Err(Error::SelfDispute); }
+
+        let mut order = escrow::load_order(&env, order_id)?;
+        if order.buyer != buyer || order.merchant != merchant { return Err(Error::NotOrderParty); }
+        if !matches!(order.state, OrderState::Funded | OrderState::Shipped | OrderState::Delivered) { return Err(Error::InvalidOrderState); }
+        order.state = OrderState::Disputed;
+        order.updated_at = env.ledger().timestamp();
+        storage::write(&env, &DataKey::Order(order_id), &order);
+
+        let id: u64 = env.storage().instance().get(&DataKey::DisputeCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::DisputeCount, &id);
+
+        let dispute = Dispute {
+            id,
+            buyer: buyer.clone(),
+            merchant: merchant.clone(),
+            order_id,
+            status: DisputeStatus::Open,
+            opened_at: env.ledger().timestamp(),
+            resolved_by: None,
+            slashed: 0,
+        };
+        storage::write(&env, &DataKey::Dispute(id), &dispute);
+
+        let open: u32 = storage::read(&env, &DataKey::OpenDisputes(merchant.clone())).unwrap_or(0);
+        set_open_disputes(&env, &merchant, open + 1);
+        env.events().publish((events::DISPUTE_OPENED, merchant), (id, buyer, order_id));
+        Ok(id)
+    }
+
+    /// Arbiter ruling. A buyer win refunds the escrow and slashes `slash_bps` of the
+    /// merchant's bond into the buyer's compensation pool; a merchant win releases it.
+    pub fn resolve_dispute(env: Env, arbiter: Address, dispute_id: u64, buyer_wins: bool) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        arbiter.require_auth();
+        touch_heartbeat(&env, &arbiter);
+        arbiters::require_may_rule(&env, dispute_id, &arbiter)?;
+
+        let mut dispute = load_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Open { return Err(Error::DisputeClosed); }
+        if storage::has(&env, &DataKey::Panel(dispute_id)) { return Err(Error::DisputeEscalated); }
+        let slashed = settle_dispute(&env, &mut dispute, buyer_wins, arbiter.clone(), 0)?;
+        arbiters::record_ruling(&env, dispute_id, &arbiter);
+        Ok(slashed)
+    }
+
+    pub fn claim_compensation(env: Env, buyer: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let pool_key = DataKey::Compensation(buyer.clone());
+        let amount: i128 = storage::read(&env, &pool_key).unwrap_or(0);
+        if amount == 0 { return Err(Error::InsufficientBalance); }
+
+        storage::remove(&env, &pool_key);
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &buyer, &amount);
+        env.events().publish((events::COMPENSATION_PAID, buyer), amount);
+        Ok(amount)
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Result<Dispute, Error> {
+        load_dispute(&env, dispute_id)
+    }
+
+    pub fn get_compensation(env: Env, buyer: Address) -> i128 {
+        storage::read(&env, &DataKey::Compensation(buyer)).unwrap_or(0)
+    }
+
+    pub fn is_disputed(env: Env, merchant: Address) -> bool {
+        load_merchant(&env, &merchant).is_disputed
+    }
+}
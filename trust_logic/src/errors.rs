@@ -0,0 +1,300 @@
+// 🏛️ PROJECT BAZAAR | CONTRACT ERRORS
+// Stable error codes returned by every entry point. Never renumber a variant:
+// SDKs and the dApp frontend match on these values. The list is past the 50 cases a
+// contract spec entry can hold, so it isn't exported; clients match on the code.
+
+use soroban_sdk::contracterror;
+
+#[contracterror(export = false)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    // --- LEGACY VAULT ---
+    VaultNotFound = 1,
+    InvalidAmount = 2,
+    TooManyAssets = 3,
+    InvalidTimeout = 4,
+    NoHeir = 5,
+    TimerNotExpired = 6,
+    NotHeir = 7,
+    InvalidHeirShares = 8,
+    VaultFrozen = 9,
+    InsufficientBalance = 10,
+    NotFrozen = 11,
+    NotDelegate = 12,
+    HeirNotAccepted = 13,
+    HeirAlreadyAccepted = 14,
+    ClaimPending = 15,
+    NoPendingClaim = 16,
+    ChallengeActive = 17,
+    NotClaimant = 18,
+    InvalidVesting = 19,
+
+    // --- SECURITY CIRCLE ---
+    NoCircle = 20,
+    NotWitness = 21,
+    TooManyWitnesses = 22,
+    EmergencyActive = 23,
+    EmergencyNotFound = 24,
+    AlreadyVoted = 25,
+    EmergencyLocked = 26,
+    EmergencyConsumed = 27,
+    WitnessPending = 28,
+    AlreadyWitness = 29,
+    InvalidQuorum = 30,
+    EmergencyExpired = 31,
+    MedicalTierExceeded = 32,
+    MedicalYearCapReached = 33,
+    NoMedicalPayee = 34,
+    WitnessNotAccepted = 35,
+    WitnessAlreadyAccepted = 36,
+
+    // --- MERCHANT TRUST ---
+    AlreadyBonded = 40,
+    NotBonded = 41,
+    BondLocked = 42,
+    SelfVouch = 43,
+    VouchCooldown = 44,
+    BadgeAlreadyHeld = 45,
+    BadgeNotHeld = 46,
+    InvalidMessage = 47,
+    AlreadyVouched = 48,
+    TooManyVouches = 49,
+    NotVouched = 50,
+    TierTooLow = 51,
+
+    // --- ADMIN ---
+    NotInitialized = 60,
+    AlreadyInitialized = 61,
+    InvalidConfig = 62,
+    Paused = 63,
+
+    // --- DISPUTES ---
+    DisputeNotFound = 80,
+    NotArbiter = 81,
+    DisputeClosed = 82,
+    SelfDispute = 83,
+    DisputeEscalated = 84,
+    DisputeOutstanding = 85,
+
+    // --- ESCROW ---
+    OrderNotFound = 100,
+    InvalidOrderState = 101,
+    NotOrderParty = 102,
+    SelfTrade = 103,
+    NotShippingOracle = 104,
+    AutoReleaseNotDue = 105,
+    InvalidRefund = 106,
+    NoRefundProposal = 107,
+
+    // --- LISTINGS ---
+    ListingNotFound = 120,
+    ListingInactive = 121,
+    NotListingOwner = 122,
+    InvalidListing = 123,
+    TooManyListings = 124,
+
+    // --- ARBITRATION ---
+    NotJuror = 140,
+    AlreadyJuror = 141,
+    JurorTrustTooLow = 142,
+    TooManyJurors = 143,
+    NotEnoughJurors = 144,
+    JurorBusy = 145,
+    PanelNotFound = 146,
+    VotingClosed = 147,
+    VotingOpen = 148,
+
+    // --- GUARDIANSHIP ---
+    InvalidGuardianship = 160,
+    NotGuardian = 161,
+    NoGuardedFunds = 162,
+    AllowanceExceeded = 163,
+    AllowanceCooldown = 164,
+    FundsLocked = 165,
+
+    // --- SOCIAL RECOVERY ---
+    RecoveryNotFound = 180,
+    RecoveryMismatch = 181,
+    RecoveryNotApproved = 182,
+    RecoveryDelayActive = 183,
+    RecoveryTargetInUse = 184,
+    RecoveryAlreadyApproved = 185,
+    NoRecoveryVote = 186,
+
+    // --- HEARTBEAT ---
+    ReminderNotDue = 200,
+    ReminderCooldown = 201,
+
+    // --- REMITTANCES ---
+    SelfRemit = 220,
+    InvalidMemo = 221,
+
+    // --- PALUWAGAN ---
+    PaluwaganNotFound = 240,
+    InvalidPaluwagan = 241,
+    NotMember = 242,
+    AlreadyJoined = 243,
+    PaluwaganNotActive = 244,
+    AlreadyContributed = 245,
+    RoundClosed = 246,
+    RoundOpen = 247,
+    PaluwaganOutstanding = 248,
+    PaluwaganStarted = 249,
+
+    // --- PROFILES ---
+    NicknameTaken = 260,
+    InvalidNickname = 261,
+    InvalidProfile = 262,
+
+    // --- REVIEWS ---
+    InvalidRating = 280,
+    AlreadyRated = 281,
+
+    // --- BATCH ---
+    BatchTooLarge = 300,
+
+    // --- RATE LIMITS ---
+    RateLimited = 320,
+
+    // --- MIGRATION ---
+    AlreadyMigrated = 340,
+    NotLegacyContract = 341,
+    MigrationTargetInUse = 342,
+
+    // --- TRUST ORACLE ---
+    ConsumerNotAllowed = 360,
+
+    // --- REWARDS ---
+    NothingToClaim = 380,
+    RewardPoolEmpty = 381,
+
+    // --- HEIR CHANGES ---
+    NoHeirChange = 400,
+    HeirChangeLocked = 401,
+
+    // --- PROOF OF LIFE ---
+    AttestationCooldown = 420,
+    AttestationLimit = 421,
+
+    // --- USER CONFIG ---
+    CosignRequired = 440,
+
+    // --- RECLAIM ---
+    VaultNotClaimed = 460,
+    VaultAlreadyClaimed = 461,
+
+    // --- CLAIM CO-SIGN ---
+    ClaimCosignRequired = 600,
+
+    // --- ORDER EXPIRY ---
+    OrderNotExpired = 620,
+    OrderExpired = 621,
+
+    // --- VOUCH STAKES ---
+    NoVouchStake = 640,
+    VouchStakeLocked = 641,
+
+    // --- DISPUTE EVIDENCE ---
+    EvidenceWindowClosed = 660,
+    TooMuchEvidence = 661,
+    EvidenceNotAcknowledged = 662,
+
+    // --- ARBITER REGISTRY ---
+    ArbiterAlreadyRegistered = 680,
+    ArbiterStakeTooLow = 681,
+    TooManyArbiters = 682,
+    ArbiterCooldown = 683,
+    ArbiterBusy = 684,
+    NoArbiters = 685,
+    ArbiterAlreadyAssigned = 686,
+    NotAssignedArbiter = 687,
+    DisputeNotResolved = 688,
+    AlreadyOverturned = 689,
+
+    // --- GOVERNANCE ---
+    ProposalNotFound = 700,
+    NoVotingPower = 701,
+    ProposalRejected = 702,
+    ProposalExecuted = 703,
+    ProposalExpired = 704,
+
+    // --- GRANTS ---
+    GrantNotFound = 720,
+    InvalidGrant = 721,
+    GrantNotApproved = 722,
+    MilestoneLocked = 723,
+    GrantComplete = 724,
+
+    // --- REFERRALS ---
+    InvalidReferral = 740,
+    AlreadyRegistered = 741,
+    ReferralLimitReached = 742,
+
+    // --- AWAY MODE ---
+    MerchantAway = 760,
+    InvalidAwayPeriod = 761,
+    AwayCooldown = 762,
+    NotAway = 763,
+
+    // --- DENY LIST ---
+    Blacklisted = 780,
+    NotBlacklisted = 781,
+
+    // --- CLAIM RELAYER ---
+    InvalidRelayer = 800,
+
+    // --- MULTI-VAULT ---
+    TooManyVaults = 820,
+
+    // --- BZR WALLET ---
+    BzrTransferToSelf = 840,
+
+    // --- WEIGHTED WITNESSES ---
+    InvalidWitnessWeight = 860,
+
+    // --- CLAIM VETOES ---
+    ClaimVetoed = 880,
+
+    // --- SUBSCRIPTIONS ---
+    SubscriptionNotFound = 480,
+    InvalidSubscription = 481,
+    NotSubscriptionPayer = 482,
+    SubscriptionInactive = 483,
+    PaymentNotDue = 484,
+
+    // --- SAVINGS GOALS ---
+    GoalNotFound = 500,
+    InvalidGoal = 501,
+    NotGoalOwner = 502,
+    GoalClosed = 503,
+    GoalLocked = 504,
+
+    // --- GIFT POOLS ---
+    PoolNotFound = 520,
+    InvalidPool = 521,
+    PoolClosed = 522,
+    PoolOpen = 523,
+    PoolNotFailed = 524,
+    NothingToRefund = 525,
+
+    // --- LOANS ---
+    LoanNotFound = 540,
+    InvalidLoan = 541,
+    InvalidLoanState = 542,
+    LoanOutstanding = 543,
+    LoanLimitExceeded = 544,
+    NotBorrower = 545,
+    LoanNotDue = 546,
+
+    // --- YIELD STRATEGIES ---
+    StrategyNotApproved = 560,
+    StrategyMismatch = 561,
+    NoStrategyPosition = 562,
+
+    // --- SPENDING POLICY ---
+    ApprovalRequired = 580,
+    WithdrawalNotFound = 581,
+    WithdrawalExpired = 582,
+    WithdrawalExecuted = 583,
+}
@@ -0,0 +1,362 @@
+// 🏛️ PROJECT BAZAAR | ESCROW ORDERS
+// Buyer funds sit in the contract until delivery is confirmed. A clean release
+// pays the merchant and is the only way trade history feeds the trust score.
+// Shipments carry a tracking hash; once an admin-registered shipping oracle confirms
+// delivery, a silent buyer no longer holds the merchant's money hostage. Either side
+// can also offer a partial refund, which settles the order once the other accepts.
+// Buyers build their own trust record from prompt funding and finished orders.
+// Orders that stall expire: unfunded after a day, unshipped a week after funding. Anyone
+// can then cancel them, and whoever let the order lapse loses trust.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+const ORDER_TRUST_POINTS: u32 = 1;
+const AUTO_RELEASE_SECONDS: u64 = 604_800; // 7 Days
+const FUND_WINDOW_SECONDS: u64 = 86_400;    // 1 Day
+const SHIP_WINDOW_SECONDS: u64 = 604_800;   // 7 Days
+const LAPSE_TRUST_PENALTY: u32 = 5;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrderState {
+    Created,
+    Funded,
+    Shipped,
+    Released,
+    Refunded,
+    Disputed,
+    Delivered,
+    Settled,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RefundProposal {
+    pub proposer: Address,
+    /// Part of the escrow that goes back to the buyer; the merchant keeps the rest.
+    pub refund_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Order {
+    pub id: u64,
+    pub listing_id: u64,
+    pub buyer: Address,
+    pub merchant: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub state: OrderState,
+    pub created_at: u64,
+    pub updated_at: u64,
+    /// Digest of the carrier tracking number, set when the merchant ships.
+    pub tracking_hash: Option<BytesN<32>>,
+    pub delivered_at: Option<u64>,
+    /// Copied from the listing at creation; a released order credits this category.
+    pub category: Option<Symbol>,
+}
+
+pub(crate) fn load_order(env: &Env, order_id: u64) -> Result<Order, Error> {
+    storage::read(env, &DataKey::Order(order_id)).ok_or(Error::OrderNotFound)
+}
+
+/// When the order lapses if nobody moves it on, or `None` once it is past funding and shipping.
+/// A funded order's `updated_at` is its funding time until it ships.
+fn expires_at(order: &Order) -> Option<u64> {
+    match order.state {
+        OrderState::Created => Some(order.created_at.saturating_add(FUND_WINDOW_SECONDS)),
+        OrderState::Funded => Some(order.updated_at.saturating_add(SHIP_WINDOW_SECONDS)),
+        _ => None,
+    }
+}
+
+fn save_order(env: &Env, order: &mut Order, state: OrderState) {
+    order.state = state;
+    order.updated_at = env.ledger().timestamp();
+    storage::write(env, &DataKey::Order(order.id), order);
+}
+
+/// Pays the escrow, less `withheld` and the escrow fee, out to the merchant and credits
+/// the completed trade.
+pub(crate) fn release(env: &Env, order: &mut Order, withheld: i128) {
+    // A buyer who lost the dispute on this order is penalised there, not credited here
+    if order.state != OrderState::Disputed { buyers::record_completion(env, &order.buyer); }
+    save_order(env, order, OrderState::Released);
+
+    let mut profile = load_merchant_for_update(env, &order.merchant);
+    profile.completed_orders += 1;
+    profile.last_activity = env.ledger().timestamp();
+    let before = profile.trust_score;
+    profile.trust_score = (profile.trust_score + ORDER_TRUST_POINTS).min(MAX_TRUST_SCORE);
+    rewards::accrue(env, &order.merchant, &mut profile, RewardKind::Order);
+    badges::check_and_award_badges(env, &order.merchant, &mut profile);
+    write_merchant(env, &order.merchant, &profile);
+    history::record(env, &order.merchant, before, profile.trust_score, TrustReason::OrderReleased, &order.buyer);
+    if let Some(category) = &order.category { categories::record_completion(env, &order.merchant, category); }
+    referrals::reach(env, &order.merchant, ReferralMilestone::FirstOrder);
+    referrals::reach(env, &order.buyer, ReferralMilestone::FirstOrder);
+
+    let fee = treasury::fee_on(order.amount - withheld, treasury::fee_config(env).escrow_bps);
+    let payout = order.amount - withheld - fee;
+    treasury::credit(env, &order.token, &order.merchant, fee);
+    token::Client::new(env, &order.token).transfer(&env.current_contract_address(), &order.merchant, &payout);
+    env.events().publish((events::ORDER_RELEASED, order.merchant.clone()), (order.id, payout));
+    env.events().publish((events::TRUST_CHANGED, order.merchant.clone()), profile.trust_score);
+}
+
+/// Returns the escrow, less `withheld`, to the buyer. The merchant's trust score is left untouched.
+pub(crate) fn refund(env: &Env, order: &mut Order, withheld: i128) {
+    save_order(env, order, OrderState::Refunded);
+    let payout = order.amount - withheld;
+    token::Client::new(env, &order.token).transfer(&env.current_contract_address(), &order.buyer, &payout);
+    env.events().publish((events::ORDER_REFUNDED, order.buyer.clone()), (order.id, payout));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 7: ESCROW ---
+
+    /// Opens an order at the listing's current price and token.
+    pub fn create_order(env: Env, buyer: Address, listing_id: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let listing = listings::load_listing(&env, listing_id)?;
+        if !listing.is_active { return Err(Error::ListingInactive); }
+        if buyer == listing.merchant { return Err(Error::SelfTrade); }
+        if away::is_away(&env, &listing.merchant) { return Err(Error::MerchantAway); }
+        blacklist::require_not_blacklisted(&env, &buyer)?;
+        blacklist::require_not_blacklisted(&env, &listing.merchant)?;
+        let (merchant, amount) = (listing.merchant, listing.price);
+
+        let id: u64 = env.storage().instance().get(&DataKey::OrderCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::OrderCount, &id);
+
+        let now = env.ledger().timestamp();
+        let order = Order {
+            id,
+            listing_id,
+            buyer: buyer.clone(),
+            merchant: merchant.clone(),
+            token: listing.token,
+            amount,
+            state: OrderState::Created,
+            created_at: now,
+            updated_at: now,
+            tracking_hash: None,
+            delivered_at: None,
+            category: listing.category,
+        };
+        storage::write(&env, &DataKey::Order(id), &order);
+        env.events().publish((events::ORDER_CREATED, merchant), (id, listing_id, buyer));
+        Ok(id)
+    }
+
+    pub fn fund_order(env: Env, buyer: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let mut order = load_order(&env, order_id)?;
+        if order.buyer != buyer { return Err(Error::NotOrderParty); }
+        if order.state != OrderState::Created { return Err(Error::InvalidOrderState); }
+        if expires_at(&order).is_some_and(|at| env.ledger().timestamp() >= at) { return Err(Error::OrderExpired); }
+
+        save_order(&env, &mut order, OrderState::Funded);
+        buyers::record_funding(&env, &buyer, order.created_at);
+        token::Client::new(&env, &order.token).transfer(&buyer, &env.current_contract_address(), &order.amount);
+        env.events().publish((events::ORDER_FUNDED, order.merchant), (order_id, order.amount));
+        Ok(())
+    }
+
+    /// Buyer walks away from an order that was never funded.
+    pub fn cancel_order(env: Env, buyer: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let mut order = load_order(&env, order_id)?;
+        if order.buyer != buyer { return Err(Error::NotOrderParty); }
+        if order.state != OrderState::Created { return Err(Error::InvalidOrderState); }
+
+        save_order(&env, &mut order, OrderState::Refunded);
+        env.events().publish((events::ORDER_REFUNDED, buyer), (order_id, 0i128));
+        Ok(())
+    }
+
+    /// Permissionless cleanup of a lapsed order. An unfunded order costs the buyer trust; an
+    /// unshipped one is refunded and costs the merchant trust.
+    pub fn cancel_expired_order(env: Env, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut order = load_order(&env, order_id)?;
+        let expires_at = expires_at(&order).ok_or(Error::InvalidOrderState)?;
+        if env.ledger().timestamp() < expires_at { return Err(Error::OrderNotExpired); }
+
+        storage::remove(&env, &DataKey::RefundProposal(order_id));
+        if order.state == OrderState::Created {
+            save_order(&env, &mut order, OrderState::Refunded);
+            buyers::record_lapse(&env, &order.buyer);
+            env.events().publish((events::ORDER_EXPIRED, order.buyer.clone()), (order_id, order.merchant));
+            return Ok(());
+        }
+
+        refund(&env, &mut order, 0);
+        let mut profile = load_merchant_for_update(&env, &order.merchant);
+        let before = profile.trust_score;
+        profile.trust_score = profile.trust_score.saturating_sub(LAPSE_TRUST_PENALTY);
+        write_merchant(&env, &order.merchant, &profile);
+        history::record(&env, &order.merchant, before, profile.trust_score, TrustReason::OrderLapsed, &env.current_contract_address());
+        discount_if_collapsed(&env, &order.merchant, &profile);
+        env.events().publish((events::ORDER_EXPIRED, order.merchant.clone()), (order_id, order.buyer));
+        env.events().publish((events::TRUST_CHANGED, order.merchant), profile.trust_score);
+        Ok(())
+    }
+
+    pub fn mark_shipped(env: Env, merchant: Address, order_id: u64, tracking_hash: BytesN<32>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        let mut order = load_order(&env, order_id)?;
+        if order.merchant != merchant { return Err(Error::NotOrderParty); }
+        if order.state != OrderState::Funded { return Err(Error::InvalidOrderState); }
+        if expires_at(&order).is_some_and(|at| env.ledger().timestamp() >= at) { return Err(Error::OrderExpired); }
+
+        order.tracking_hash = Some(tracking_hash.clone());
+        save_order(&env, &mut order, OrderState::Shipped);
+        env.events().publish((events::ORDER_SHIPPED, order.buyer), (order_id, tracking_hash));
+        Ok(())
+    }
+
+    /// Buyer confirms a shipped order arrived, which releases the escrow.
+    pub fn confirm_delivery(env: Env, buyer: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let mut order = load_order(&env, order_id)?;
+        if order.buyer != buyer { return Err(Error::NotOrderParty); }
+        if !matches!(order.state, OrderState::Shipped | OrderState::Delivered) { return Err(Error::InvalidOrderState); }
+
+        release(&env, &mut order, 0);
+        Ok(())
+    }
+
+    pub fn set_shipping_oracle(env: Env, oracle: Address, allowed: bool) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        let key = DataKey::ShippingOracle(oracle.clone());
+        if allowed { storage::write(&env, &key, &true); } else { storage::remove(&env, &key); }
+        env.events().publish((events::SHIPPING_ORACLE_SET, oracle), allowed);
+        Ok(())
+    }
+
+    pub fn is_shipping_oracle(env: Env, oracle: Address) -> bool {
+        storage::has(&env, &DataKey::ShippingOracle(oracle))
+    }
+
+    /// Carrier-side confirmation. Starts the 7-day window after which anyone may release.
+    pub fn confirm_delivery_oracle(env: Env, oracle: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        oracle.require_auth();
+        touch_heartbeat(&env, &oracle);
+        if !storage::has(&env, &DataKey::ShippingOracle(oracle.clone())) { return Err(Error::NotShippingOracle); }
+        let mut order = load_order(&env, order_id)?;
+        if order.state != OrderState::Shipped { return Err(Error::InvalidOrderState); }
+
+        order.delivered_at = Some(env.ledger().timestamp());
+        save_order(&env, &mut order, OrderState::Delivered);
+        env.events().publish((events::ORDER_DELIVERED, order.buyer), (order_id, oracle));
+        Ok(())
+    }
+
+    /// Permissionless release of an oracle-confirmed order the buyer never acted on.
+    pub fn auto_release(env: Env, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut order = load_order(&env, order_id)?;
+        if order.state != OrderState::Delivered { return Err(Error::InvalidOrderState); }
+        let delivered_at = order.delivered_at.unwrap_or(order.updated_at);
+        if env.ledger().timestamp() < delivered_at + AUTO_RELEASE_SECONDS { return Err(Error::AutoReleaseNotDue); }
+
+        release(&env, &mut order, 0);
+        Ok(())
+    }
+
+    /// Buyer releases the escrow early, e.g. for an in-person handover that never ships.
+    pub fn release_escrow(env: Env, buyer: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        let mut order = load_order(&env, order_id)?;
+        if order.buyer != buyer { return Err(Error::NotOrderParty); }
+        if !matches!(order.state, OrderState::Funded | OrderState::Shipped | OrderState::Delivered) { return Err(Error::InvalidOrderState); }
+
+        release(&env, &mut order, 0);
+        Ok(())
+    }
+
+    /// Merchant backs out of a funded order before shipping and sends the buyer's money back.
+    pub fn refund_order(env: Env, merchant: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        let mut order = load_order(&env, order_id)?;
+        if order.merchant != merchant { return Err(Error::NotOrderParty); }
+        if order.state != OrderState::Funded { return Err(Error::InvalidOrderState); }
+
+        storage::remove(&env, &DataKey::RefundProposal(order_id));
+        refund(&env, &mut order, 0);
+        Ok(())
+    }
+
+    /// Either party offers to settle with `refund_amount` going back to the buyer. A new
+    /// offer replaces the previous one.
+    pub fn propose_partial_refund(env: Env, party: Address, order_id: u64, refund_amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        party.require_auth();
+        touch_heartbeat(&env, &party);
+        let order = load_order(&env, order_id)?;
+        if party != order.buyer && party != order.merchant { return Err(Error::NotOrderParty); }
+        if !matches!(order.state, OrderState::Funded | OrderState::Shipped | OrderState::Delivered) { return Err(Error::InvalidOrderState); }
+        if refund_amount <= 0 || refund_amount >= order.amount { return Err(Error::InvalidRefund); }
+
+        let proposal = RefundProposal { proposer: party.clone(), refund_amount };
+        storage::write(&env, &DataKey::RefundProposal(order_id), &proposal);
+        env.events().publish((events::REFUND_PROPOSED, party), (order_id, refund_amount));
+        Ok(())
+    }
+
+    /// The counterparty accepts the standing offer: the buyer gets the refund, the merchant
+    /// the rest less the escrow fee. Not a clean trade, so no trust points, but both
+    /// histories record the settlement.
+    pub fn accept_partial_refund(env: Env, party: Address, order_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        party.require_auth();
+        touch_heartbeat(&env, &party);
+        let mut order = load_order(&env, order_id)?;
+        if party != order.buyer && party != order.merchant { return Err(Error::NotOrderParty); }
+        if !matches!(order.state, OrderState::Funded | OrderState::Shipped | OrderState::Delivered) { return Err(Error::InvalidOrderState); }
+        let key = DataKey::RefundProposal(order_id);
+        let proposal: RefundProposal = storage::read(&env, &key).ok_or(Error::NoRefundProposal)?;
+        if proposal.proposer == party { return Err(Error::NotOrderParty); }
+
+        storage::remove(&env, &key);
+        save_order(&env, &mut order, OrderState::Settled);
+        let kept = order.amount - proposal.refund_amount;
+        let fee = treasury::fee_on(kept, treasury::fee_config(&env).escrow_bps);
+        treasury::credit(&env, &order.token, &order.merchant, fee);
+        let client = token::Client::new(&env, &order.token);
+        client.transfer(&env.current_contract_address(), &order.buyer, &proposal.refund_amount);
+        client.transfer(&env.current_contract_address(), &order.merchant, &(kept - fee));
+
+        history::note(&env, &order.buyer, TrustReason::PartialRefund, &party);
+        history::note(&env, &order.merchant, TrustReason::PartialRefund, &party);
+        env.events().publish((events::ORDER_SETTLED, order.merchant.clone()), (order_id, proposal.refund_amount, kept - fee));
+        Ok(())
+    }
+
+    pub fn get_refund_proposal(env: Env, order_id: u64) -> Option<RefundProposal> {
+        storage::read(&env, &DataKey::RefundProposal(order_id))
+    }
+
+    pub fn get_order(env: Env, order_id: u64) -> Result<Order, Error> {
+        load_order(&env, order_id)
+    }
+}
@@ -0,0 +1,260 @@
+// 🏛️ PROJECT BAZAAR | EVENT TOPICS
+// First topic of every event the contract publishes. The second topic is always
+// the address whose state changed, so indexers can filter per user.
+
+use soroban_sdk::{symbol_short, Symbol};
+
+// --- ADMIN ---
+pub const INITIALIZED: Symbol = symbol_short!("init");
+pub const CONFIG_SET: Symbol = symbol_short!("config");
+pub const ADMIN_CHANGED: Symbol = symbol_short!("admin");
+pub const PAUSED: Symbol = symbol_short!("paused");
+pub const UPGRADED: Symbol = symbol_short!("upgraded");
+
+// --- LEGACY VAULT ---
+pub const VAULT_CREATED: Symbol = symbol_short!("vault_new");
+pub const HEIRS_SET: Symbol = symbol_short!("heirs");
+pub const HEIRS_QUEUED: Symbol = symbol_short!("heirs_q");
+pub const HEIR_CHANGE_CANCELLED: Symbol = symbol_short!("heirs_x");
+pub const BACKUPS_SET: Symbol = symbol_short!("backups");
+pub const HEIR_ACCEPTED: Symbol = symbol_short!("heir_ok");
+pub const HEIR_DECLINED: Symbol = symbol_short!("heir_no");
+pub const VAULT_DEPOSIT: Symbol = symbol_short!("deposit");
+pub const VAULT_WITHDRAW: Symbol = symbol_short!("withdraw");
+pub const TIMEOUT_SET: Symbol = symbol_short!("timeout");
+pub const HEARTBEAT: Symbol = symbol_short!("heartbeat");
+pub const DELEGATE_SET: Symbol = symbol_short!("delegate");
+pub const HEARTBEAT_DUE: Symbol = symbol_short!("hb_due");
+pub const CLAIM_OPENED: Symbol = symbol_short!("clm_open");
+pub const CLAIM_VETOED: Symbol = symbol_short!("clm_veto");
+pub const CLAIM_APPROVED: Symbol = symbol_short!("clm_ok");
+pub const CLAIM_BLOCKED: Symbol = symbol_short!("clm_stop");
+pub const LEGACY_CLAIMED: Symbol = symbol_short!("claimed");
+pub const LEGACY_NOTE_SET: Symbol = symbol_short!("note_set");
+pub const LEGACY_NOTE_RELEASED: Symbol = symbol_short!("last_will");
+pub const TOKEN_ROUTED: Symbol = symbol_short!("routed");
+pub const VESTING_SET: Symbol = symbol_short!("vesting");
+pub const VESTED_PAID: Symbol = symbol_short!("vest_paid");
+pub const VAULT_RECLAIMED: Symbol = symbol_short!("reclaimed");
+pub const VAULT_STATE: Symbol = symbol_short!("vault_st");
+
+// --- SECURITY CIRCLE ---
+pub const CIRCLE_SET: Symbol = symbol_short!("circle");
+pub const CIRCLE_CONFIG_SET: Symbol = symbol_short!("circ_cfg");
+pub const WITNESS_ADDED: Symbol = symbol_short!("wit_add");
+pub const WITNESS_REMOVED: Symbol = symbol_short!("wit_rm");
+pub const WITNESS_WEIGHT_SET: Symbol = symbol_short!("wit_wt");
+pub const WITNESS_ACCEPTED: Symbol = symbol_short!("wit_ok");
+pub const EMERGENCY_DECLARED: Symbol = symbol_short!("emergency");
+pub const EMERGENCY_CANCELLED: Symbol = symbol_short!("emerg_end");
+pub const MEDICAL_VOTE: Symbol = symbol_short!("med_vote");
+pub const MEDICAL_UNLOCKED: Symbol = symbol_short!("med_open");
+pub const MEDICAL_RELEASED: Symbol = symbol_short!("med_paid");
+pub const MEDICAL_PAYEE_SET: Symbol = symbol_short!("med_payee");
+pub const MEDICAL_RECIPIENT_VOTE: Symbol = symbol_short!("med_recip");
+pub const PANIC_VOTE: Symbol = symbol_short!("pnc_vote");
+pub const PANIC_FREEZE: Symbol = symbol_short!("frozen");
+pub const PANIC_CANCELLED: Symbol = symbol_short!("unfrozen");
+pub const PANIC_WINDOW_SET: Symbol = symbol_short!("pnc_wind");
+pub const PANIC_CHECKPOINT: Symbol = symbol_short!("pnc_tick");
+pub const ALIVE_VOTE: Symbol = symbol_short!("alive_vot");
+pub const ALIVE_ATTESTED: Symbol = symbol_short!("alive");
+
+// --- MERCHANT TRUST ---
+pub const TRUST_CHANGED: Symbol = symbol_short!("trust");
+pub const TRUST_DECAYED: Symbol = symbol_short!("decayed");
+pub const TRUST_THRESHOLD_LOST: Symbol = symbol_short!("tier_lost");
+pub const TIER_CHANGED: Symbol = symbol_short!("tier");
+pub const STAKED: Symbol = symbol_short!("staked");
+pub const UNSTAKED: Symbol = symbol_short!("unstaked");
+pub const VOUCH_REVOKED: Symbol = symbol_short!("unvouch");
+pub const VOUCH_DISCOUNTED: Symbol = symbol_short!("vch_cut");
+pub const BADGE_AWARDED: Symbol = symbol_short!("badge_add");
+pub const BADGE_REVOKED: Symbol = symbol_short!("badge_rm");
+pub const BADGE_MINTER_SET: Symbol = symbol_short!("badge_nft");
+pub const MERCHANT_VERIFIED: Symbol = symbol_short!("kyc");
+pub const MESSAGE_SENT: Symbol = symbol_short!("message");
+
+// --- DISPUTES ---
+pub const ARBITER_SET: Symbol = symbol_short!("arbiter");
+pub const DISPUTE_OPENED: Symbol = symbol_short!("disp_open");
+pub const DISPUTE_RESOLVED: Symbol = symbol_short!("disp_done");
+pub const BOND_SLASHED: Symbol = symbol_short!("slashed");
+pub const COMPENSATION_PAID: Symbol = symbol_short!("comp_paid");
+
+// --- ESCROW ---
+pub const ORDER_CREATED: Symbol = symbol_short!("order_new");
+pub const ORDER_FUNDED: Symbol = symbol_short!("funded");
+pub const ORDER_SHIPPED: Symbol = symbol_short!("shipped");
+pub const ORDER_DELIVERED: Symbol = symbol_short!("delivered");
+pub const SHIPPING_ORACLE_SET: Symbol = symbol_short!("ship_orcl");
+pub const ORDER_RELEASED: Symbol = symbol_short!("released");
+pub const ORDER_REFUNDED: Symbol = symbol_short!("refunded");
+pub const REFUND_PROPOSED: Symbol = symbol_short!("ref_offer");
+pub const ORDER_SETTLED: Symbol = symbol_short!("settled");
+pub const ORDER_EXPIRED: Symbol = symbol_short!("expired");
+
+// --- LISTINGS ---
+pub const LISTING_SET: Symbol = symbol_short!("listing");
+pub const LISTING_CLOSED: Symbol = symbol_short!("list_off");
+
+// --- ARBITRATION ---
+pub const JUROR_JOINED: Symbol = symbol_short!("jur_join");
+pub const JUROR_LEFT: Symbol = symbol_short!("jur_left");
+pub const PANEL_DRAWN: Symbol = symbol_short!("panel");
+pub const JUROR_VOTE: Symbol = symbol_short!("jur_vote");
+pub const JUROR_REWARD: Symbol = symbol_short!("jur_paid");
+pub const PANEL_DISSOLVED: Symbol = symbol_short!("panel_end");
+
+// --- GUARDIANSHIP ---
+pub const MINOR_HEIR_SET: Symbol = symbol_short!("minor");
+pub const MINOR_HEIR_REMOVED: Symbol = symbol_short!("minor_rm");
+pub const FUNDS_GUARDED: Symbol = symbol_short!("guarded");
+pub const ALLOWANCE_PAID: Symbol = symbol_short!("allowance");
+pub const GUARDED_RELEASED: Symbol = symbol_short!("grd_free");
+
+// --- SOCIAL RECOVERY ---
+pub const RECOVERY_VOTE: Symbol = symbol_short!("rec_vote");
+pub const RECOVERY_VOTE_WITHDRAWN: Symbol = symbol_short!("rec_undo");
+pub const RECOVERY_APPROVED: Symbol = symbol_short!("rec_ok");
+pub const RECOVERY_CANCELLED: Symbol = symbol_short!("rec_stop");
+pub const ACCOUNT_RECOVERED: Symbol = symbol_short!("recovered");
+
+// --- REMITTANCES ---
+pub const REMITTED: Symbol = symbol_short!("remit");
+
+// --- PALUWAGAN ---
+pub const PALUWAGAN_CREATED: Symbol = symbol_short!("pal_new");
+pub const PALUWAGAN_JOINED: Symbol = symbol_short!("pal_join");
+pub const PALUWAGAN_LEFT: Symbol = symbol_short!("pal_left");
+pub const PALUWAGAN_CONTRIBUTED: Symbol = symbol_short!("pal_paid");
+pub const PALUWAGAN_ROUND_PAID: Symbol = symbol_short!("pal_round");
+pub const PALUWAGAN_MISSED: Symbol = symbol_short!("pal_miss");
+
+// --- TREASURY ---
+pub const FEE_COLLECTED: Symbol = symbol_short!("fee");
+pub const FEES_SET: Symbol = symbol_short!("fees");
+pub const TREASURY_WITHDRAWN: Symbol = symbol_short!("trs_out");
+
+// --- PROFILES ---
+pub const NICKNAME_SET: Symbol = symbol_short!("nickname");
+pub const PROFILE_SET: Symbol = symbol_short!("profile");
+
+// --- REVIEWS ---
+pub const MERCHANT_RATED: Symbol = symbol_short!("rated");
+
+// --- MIGRATION ---
+pub const LEGACY_CONTRACT_SET: Symbol = symbol_short!("legacy_v2");
+pub const MIGRATED: Symbol = symbol_short!("migrated");
+
+// --- TRUST ORACLE ---
+pub const CONSUMER_SET: Symbol = symbol_short!("consumer");
+pub const ORACLE_QUERY: Symbol = symbol_short!("oracle_q");
+
+// --- REWARDS ---
+pub const REWARD_ACCRUED: Symbol = symbol_short!("reward");
+pub const REWARD_RATES_SET: Symbol = symbol_short!("rwd_rates");
+pub const REWARDS_FUNDED: Symbol = symbol_short!("rwd_fund");
+pub const REWARDS_CLAIMED: Symbol = symbol_short!("rwd_paid");
+
+// --- USER CONFIG ---
+pub const USER_CONFIG_SET: Symbol = symbol_short!("user_cfg");
+
+// --- SUBSCRIPTIONS ---
+pub const SUBSCRIPTION_CREATED: Symbol = symbol_short!("sub_new");
+pub const SUBSCRIPTION_FUNDED: Symbol = symbol_short!("sub_fund");
+pub const SUBSCRIPTION_COLLECTED: Symbol = symbol_short!("sub_paid");
+pub const SUBSCRIPTION_CANCELLED: Symbol = symbol_short!("sub_stop");
+
+// --- SAVINGS GOALS ---
+pub const GOAL_CREATED: Symbol = symbol_short!("goal_new");
+pub const GOAL_DEPOSIT: Symbol = symbol_short!("goal_in");
+pub const GOAL_REACHED: Symbol = symbol_short!("goal_met");
+pub const GOAL_WITHDRAWN: Symbol = symbol_short!("goal_out");
+pub const GOAL_BROKEN: Symbol = symbol_short!("goal_brk");
+
+// --- GIFT POOLS ---
+pub const POOL_CREATED: Symbol = symbol_short!("pool_new");
+pub const POOL_CONTRIBUTED: Symbol = symbol_short!("pool_in");
+pub const POOL_FINALIZED: Symbol = symbol_short!("pool_done");
+pub const POOL_REFUNDED: Symbol = symbol_short!("pool_ref");
+
+// --- LOANS ---
+pub const LOAN_REQUESTED: Symbol = symbol_short!("loan_req");
+pub const LOAN_CANCELLED: Symbol = symbol_short!("loan_x");
+pub const LOAN_FUNDED: Symbol = symbol_short!("loan_fund");
+pub const LOAN_REPAID: Symbol = symbol_short!("loan_paid");
+pub const LOAN_DEFAULTED: Symbol = symbol_short!("loan_dflt");
+
+// --- YIELD STRATEGIES ---
+pub const STRATEGY_SET: Symbol = symbol_short!("strat_set");
+pub const STRATEGY_DEPOSIT: Symbol = symbol_short!("strat_in");
+pub const STRATEGY_WITHDRAW: Symbol = symbol_short!("strat_out");
+
+// --- SPENDING POLICY ---
+pub const SPENDING_LIMIT_SET: Symbol = symbol_short!("spend_lim");
+pub const WITHDRAWAL_REQUESTED: Symbol = symbol_short!("wd_req");
+pub const WITHDRAWAL_APPROVED: Symbol = symbol_short!("wd_ok");
+
+// --- BUYER TRUST ---
+pub const BUYER_TRUST_CHANGED: Symbol = symbol_short!("buyer_tr");
+
+// --- CATEGORY TRUST ---
+pub const CATEGORY_SET: Symbol = symbol_short!("cat_set");
+pub const CATEGORY_TRUST_CHANGED: Symbol = symbol_short!("cat_trust");
+
+// --- VOUCH STAKES ---
+pub const VOUCH_STAKED: Symbol = symbol_short!("vch_stake");
+pub const VOUCH_STAKE_BURNED: Symbol = symbol_short!("vch_burn");
+pub const VOUCH_STAKE_RELEASED: Symbol = symbol_short!("vch_back");
+
+// --- DISPUTE EVIDENCE ---
+pub const EVIDENCE_SUBMITTED: Symbol = symbol_short!("evidence");
+pub const EVIDENCE_ACKNOWLEDGED: Symbol = symbol_short!("evid_ack");
+
+// --- ARBITER REGISTRY ---
+pub const ARBITER_REGISTERED: Symbol = symbol_short!("arb_join");
+pub const ARBITER_LEAVING: Symbol = symbol_short!("arb_leave");
+pub const ARBITER_LEFT: Symbol = symbol_short!("arb_left");
+pub const ARBITER_EXPELLED: Symbol = symbol_short!("arb_kick");
+pub const ARBITER_ASSIGNED: Symbol = symbol_short!("arb_pick");
+pub const RULING_OVERTURNED: Symbol = symbol_short!("overturn");
+
+// --- GOVERNANCE ---
+pub const PROPOSAL_CREATED: Symbol = symbol_short!("prop_new");
+pub const PROPOSAL_VOTE: Symbol = symbol_short!("prop_vote");
+pub const PROPOSAL_EXECUTED: Symbol = symbol_short!("prop_exec");
+
+// --- GRANTS ---
+pub const GRANT_PROPOSED: Symbol = symbol_short!("grant_new");
+pub const GRANT_APPROVED: Symbol = symbol_short!("grant_ok");
+pub const GRANT_PAID: Symbol = symbol_short!("grant_pay");
+pub const GRANT_CANCELLED: Symbol = symbol_short!("grant_x");
+
+// --- REFERRALS ---
+pub const REFERRED: Symbol = symbol_short!("referred");
+pub const REFERRAL_MILESTONE: Symbol = symbol_short!("ref_mile");
+
+// --- AWAY MODE ---
+pub const AWAY_SET: Symbol = symbol_short!("away");
+
+// --- DENY LIST ---
+pub const BLACKLISTED: Symbol = symbol_short!("denied");
+pub const UNBLACKLISTED: Symbol = symbol_short!("undenied");
+
+// --- WITNESS CONTACTS ---
+pub const CONTACT_SET: Symbol = symbol_short!("contact");
+pub const CIRCLE_ALERT: Symbol = symbol_short!("alert");
+
+// --- CLAIM RELAYER ---
+pub const RELAYER_SET: Symbol = symbol_short!("relayer");
+pub const RELAYER_PAID: Symbol = symbol_short!("relay_fee");
+
+// --- BZR WALLET ---
+pub const BZR_DEPOSITED: Symbol = symbol_short!("bzr_in");
+pub const BZR_TRANSFERRED: Symbol = symbol_short!("bzr_move");
+pub const BZR_WITHDRAWN: Symbol = symbol_short!("bzr_out");
+
+// --- PASSIVE HEARTBEAT ---
+pub const PASSIVE_HEARTBEAT: Symbol = symbol_short!("hb_touch");
+pub const PASSIVE_HEARTBEAT_SET: Symbol = symbol_short!("hb_mode");
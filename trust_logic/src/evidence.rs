@@ -0,0 +1,74 @@
+// 🏛️ PROJECT BAZAAR | DISPUTE EVIDENCE
+// Buyer and merchant each file up to five evidence hashes in the first five days of a
+// dispute; the documents themselves live off-chain and every hash is announced for the
+// arbitration UI. Panel jurors must acknowledge the evidence on file before they vote,
+// and evidence filed after an acknowledgement has to be acknowledged again.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+const MAX_EVIDENCE_PER_PARTY: u32 = 5;
+const EVIDENCE_WINDOW_SECONDS: u64 = 432_000; // 5 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Evidence {
+    pub submitter: Address,
+    pub hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+fn load_evidence(env: &Env, dispute_id: u64) -> Vec<Evidence> {
+    storage::read(env, &DataKey::Evidence(dispute_id)).unwrap_or(Vec::new(env))
+}
+
+/// Fails unless `juror` acknowledged every item currently on file.
+pub(crate) fn require_acknowledged(env: &Env, dispute_id: u64, juror: &Address) -> Result<(), Error> {
+    let seen: u32 = storage::read(env, &DataKey::EvidenceAck(dispute_id, juror.clone())).unwrap_or(0);
+    if seen < load_evidence(env, dispute_id).len() { return Err(Error::EvidenceNotAcknowledged); }
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 40: DISPUTE EVIDENCE ---
+
+    pub fn submit_evidence(env: Env, party: Address, dispute_id: u64, hash: BytesN<32>) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        party.require_auth();
+        touch_heartbeat(&env, &party);
+        let dispute = disputes::load_dispute(&env, dispute_id)?;
+        if party != dispute.buyer && party != dispute.merchant { return Err(Error::NotOrderParty); }
+        if dispute.status != DisputeStatus::Open { return Err(Error::DisputeClosed); }
+        if env.ledger().timestamp() >= dispute.opened_at + EVIDENCE_WINDOW_SECONDS { return Err(Error::EvidenceWindowClosed); }
+
+        let mut evidence = load_evidence(&env, dispute_id);
+        let filed = evidence.iter().filter(|item| item.submitter == party).count() as u32;
+        if filed >= MAX_EVIDENCE_PER_PARTY { return Err(Error::TooMuchEvidence); }
+
+        evidence.push_back(Evidence { submitter: party.clone(), hash: hash.clone(), submitted_at: env.ledger().timestamp() });
+        storage::write(&env, &DataKey::Evidence(dispute_id), &evidence);
+        env.events().publish((events::EVIDENCE_SUBMITTED, party), (dispute_id, hash));
+        Ok(evidence.len())
+    }
+
+    /// A panel juror confirms they reviewed everything filed so far. Returns the item count.
+    pub fn acknowledge_evidence(env: Env, juror: Address, dispute_id: u64) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        juror.require_auth();
+        touch_heartbeat(&env, &juror);
+        let panel = arbitration::load_panel(&env, dispute_id)?;
+        if !panel.jurors.contains(&juror) { return Err(Error::NotJuror); }
+
+        let count = load_evidence(&env, dispute_id).len();
+        storage::write(&env, &DataKey::EvidenceAck(dispute_id, juror.clone()), &count);
+        env.events().publish((events::EVIDENCE_ACKNOWLEDGED, juror), (dispute_id, count));
+        Ok(count)
+    }
+
+    /// Oldest first.
+    pub fn get_evidence(env: Env, dispute_id: u64) -> Vec<Evidence> {
+        load_evidence(&env, dispute_id)
+    }
+}
@@ -0,0 +1,219 @@
+// 🏛️ PROJECT BAZAAR | GOVERNANCE
+// Bonded merchants steer the protocol alongside the admin. A Gold-tier merchant opens a
+// proposal to change the fees, the protocol config, the vault defaults or governance's
+// own thresholds; bonded merchants vote with their current trust score as weight, and
+// once the window closes anyone can execute a proposal that reached quorum and passed.
+// Vault defaults only apply to vaults created after the change. Treasury grants are
+// approved and cancelled, and the deny list managed, through the same proposals.
+
+use super::*;
+
+const PROPOSER_MIN_TIER: TrustTier = TrustTier::Gold;
+const EXECUTION_WINDOW_SECONDS: u64 = 1_209_600; // 14 Days
+const MIN_VOTING_SECONDS: u64 = 86_400;          // 1 Day
+const MAX_VOTING_SECONDS: u64 = 2_592_000;       // 30 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GovernanceParams {
+    pub voting_seconds: u64,
+    /// Trust points that must take part for a result to count.
+    pub quorum_power: u64,
+    /// Share of the votes cast that must be in favour.
+    pub pass_bps: u32,
+}
+
+/// Timers a new vault starts with.
+#[contracttype]
+#[derive(Clone)]
+pub struct VaultDefaults {
+    pub deadman_seconds: u64,
+    pub panic_response_seconds: u64,
+    pub backup_grace_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalAction {
+    SetFees(FeeConfig),
+    SetProtocolConfig(ProtocolConfig),
+    SetVaultDefaults(VaultDefaults),
+    SetGovernance(GovernanceParams),
+    RemoveArbiter(Address),
+    ApproveGrant(u64),
+    CancelGrant(u64),
+    Blacklist(Address, u32),
+    Unblacklist(Address),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub voting_ends_at: u64,
+    pub power_for: u64,
+    pub power_against: u64,
+    pub executed: bool,
+}
+
+pub(crate) fn governance_params(env: &Env) -> GovernanceParams {
+    env.storage().instance().get(&DataKey::GovernanceParams).unwrap_or(GovernanceParams {
+        voting_seconds: 604_800, // 7 Days
+        quorum_power: 300,
+        pass_bps: 5_000,
+    })
+}
+
+pub(crate) fn vault_defaults(env: &Env) -> VaultDefaults {
+    env.storage().instance().get(&DataKey::VaultDefaults).unwrap_or(VaultDefaults {
+        deadman_seconds: DEFAULT_DEADMAN_SECONDS,
+        panic_response_seconds: DEFAULT_PANIC_RESPONSE_SECONDS,
+        backup_grace_seconds: DEFAULT_BACKUP_GRACE_SECONDS,
+    })
+}
+
+fn load_proposal(env: &Env, id: u64) -> Result<Proposal, Error> {
+    storage::read(env, &DataKey::Proposal(id)).ok_or(Error::ProposalNotFound)
+}
+
+/// Bonded merchants vote with their decayed trust score; everyone else has no say.
+fn voting_power(env: &Env, voter: &Address) -> u64 {
+    if !load_merchant(env, voter).bond_staked { return 0; }
+    current_trust(env, voter) as u64
+}
+
+fn validate_action(env: &Env, action: &ProposalAction) -> Result<(), Error> {
+    match action {
+        ProposalAction::SetFees(config) => treasury::validate_fee_config(config),
+        ProposalAction::SetProtocolConfig(config) => admin::validate_config(config),
+        ProposalAction::SetVaultDefaults(defaults) => {
+            if defaults.deadman_seconds < MIN_DEADMAN_SECONDS || defaults.deadman_seconds > MAX_DEADMAN_SECONDS { return Err(Error::InvalidTimeout); }
+            if defaults.panic_response_seconds > MAX_PANIC_RESPONSE_SECONDS { return Err(Error::InvalidTimeout); }
+            if defaults.backup_grace_seconds < MIN_BACKUP_GRACE_SECONDS || defaults.backup_grace_seconds > MAX_BACKUP_GRACE_SECONDS { return Err(Error::InvalidTimeout); }
+            Ok(())
+        }
+        ProposalAction::SetGovernance(params) => {
+            if params.voting_seconds < MIN_VOTING_SECONDS || params.voting_seconds > MAX_VOTING_SECONDS { return Err(Error::InvalidConfig); }
+            if params.quorum_power == 0 || params.pass_bps < 5_000 || params.pass_bps > FULL_SHARE_BPS { return Err(Error::InvalidConfig); }
+            Ok(())
+        }
+        ProposalAction::RemoveArbiter(_) => Ok(()),
+        ProposalAction::ApproveGrant(id) => grants::validate_approval(env, *id),
+        ProposalAction::CancelGrant(id) => grants::validate_cancel(env, *id),
+        ProposalAction::Blacklist(_, _) => Ok(()),
+        ProposalAction::Unblacklist(address) => {
+            if !blacklist::is_blacklisted(env, address) { return Err(Error::NotBlacklisted); }
+            Ok(())
+        }
+    }
+}
+
+fn apply_action(env: &Env, action: &ProposalAction) -> Result<(), Error> {
+    match action {
+        ProposalAction::SetFees(config) => env.storage().instance().set(&DataKey::FeeConfig, config),
+        ProposalAction::SetProtocolConfig(config) => env.storage().instance().set(&DataKey::Config, config),
+        ProposalAction::SetVaultDefaults(defaults) => env.storage().instance().set(&DataKey::VaultDefaults, defaults),
+        ProposalAction::SetGovernance(params) => env.storage().instance().set(&DataKey::GovernanceParams, params),
+        ProposalAction::RemoveArbiter(arbiter) => {
+            arbiters::expel(env, arbiter)?;
+            storage::remove(env, &DataKey::Arbiter(arbiter.clone()));
+            env.events().publish((events::ARBITER_SET, arbiter.clone()), false);
+        }
+        ProposalAction::ApproveGrant(id) => grants::approve(env, *id)?,
+        ProposalAction::CancelGrant(id) => grants::cancel(env, *id)?,
+        ProposalAction::Blacklist(address, reason_code) => blacklist::add(env, address, *reason_code),
+        ProposalAction::Unblacklist(address) => blacklist::remove(env, address),
+    }
+    Ok(())
+}
+
+/// Shared by `create_proposal` and `propose_grant`; the caller has checked the proposer's auth.
+pub(crate) fn open_proposal(env: &Env, proposer: &Address, action: ProposalAction) -> Result<u64, Error> {
+    if voting_power(env, proposer) == 0 || tier_of(current_trust(env, proposer)) < PROPOSER_MIN_TIER { return Err(Error::NoVotingPower); }
+    validate_action(env, &action)?;
+
+    let id: u64 = env.storage().instance().get(&DataKey::ProposalCount).unwrap_or(0) + 1;
+    env.storage().instance().set(&DataKey::ProposalCount, &id);
+
+    let proposal = Proposal {
+        id,
+        proposer: proposer.clone(),
+        action,
+        voting_ends_at: env.ledger().timestamp() + governance_params(env).voting_seconds,
+        power_for: 0,
+        power_against: 0,
+        executed: false,
+    };
+    storage::write(env, &DataKey::Proposal(id), &proposal);
+    env.events().publish((events::PROPOSAL_CREATED, proposer.clone()), (id, proposal.voting_ends_at));
+    Ok(id)
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 42: GOVERNANCE ---
+
+    pub fn create_proposal(env: Env, proposer: Address, action: ProposalAction) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        proposer.require_auth();
+        touch_heartbeat(&env, &proposer);
+        open_proposal(&env, &proposer, action)
+    }
+
+    /// Weight is the voter's trust score at the moment they vote. Returns the power cast.
+    pub fn vote(env: Env, voter: Address, proposal_id: u64, support: bool) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        voter.require_auth();
+        touch_heartbeat(&env, &voter);
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if env.ledger().timestamp() >= proposal.voting_ends_at { return Err(Error::VotingClosed); }
+        let vote_key = DataKey::ProposalVote(proposal_id, voter.clone());
+        if storage::has(&env, &vote_key) { return Err(Error::AlreadyVoted); }
+        let power = voting_power(&env, &voter);
+        if power == 0 { return Err(Error::NoVotingPower); }
+
+        if support { proposal.power_for += power; } else { proposal.power_against += power; }
+        storage::write(&env, &vote_key, &support);
+        storage::write(&env, &DataKey::Proposal(proposal_id), &proposal);
+        env.events().publish((events::PROPOSAL_VOTE, voter), (proposal_id, support, power));
+        Ok(power)
+    }
+
+    /// Anyone may execute a passed proposal in the 14 days after voting closes. Thresholds
+    /// are read at execution time.
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal.executed { return Err(Error::ProposalExecuted); }
+        let now = env.ledger().timestamp();
+        if now < proposal.voting_ends_at { return Err(Error::VotingOpen); }
+        if now >= proposal.voting_ends_at + EXECUTION_WINDOW_SECONDS { return Err(Error::ProposalExpired); }
+
+        let params = governance_params(&env);
+        let cast = proposal.power_for + proposal.power_against;
+        if cast < params.quorum_power { return Err(Error::ProposalRejected); }
+        if (proposal.power_for as u128) * (FULL_SHARE_BPS as u128) <= (cast as u128) * (params.pass_bps as u128) { return Err(Error::ProposalRejected); }
+        validate_action(&env, &proposal.action)?;
+
+        proposal.executed = true;
+        storage::write(&env, &DataKey::Proposal(proposal_id), &proposal);
+        apply_action(&env, &proposal.action)?;
+        env.events().publish((events::PROPOSAL_EXECUTED, proposal.proposer), proposal_id);
+        Ok(())
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, Error> {
+        load_proposal(&env, proposal_id)
+    }
+
+    pub fn get_governance_params(env: Env) -> GovernanceParams {
+        governance_params(&env)
+    }
+
+    pub fn get_vault_defaults(env: Env) -> VaultDefaults {
+        vault_defaults(&env)
+    }
+}
@@ -0,0 +1,137 @@
+// 🏛️ PROJECT BAZAAR | COMMUNITY GRANTS
+// Collected fees can fund community projects. A grant is proposed with its purpose
+// document's hash and goes to a governance vote; once approved, the full amount is
+// reserved out of the treasury and paid in equal milestones, one per interval, to
+// whoever calls `release_milestone`. Governance can cancel a grant, returning whatever
+// is still unpaid to the treasury.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+const MAX_MILESTONES: u32 = 12;
+const MIN_MILESTONE_SECONDS: u64 = 86_400; // 1 Day
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Grant {
+    pub id: u64,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub milestones: u32,
+    pub milestone_seconds: u64,
+    pub purpose_hash: BytesN<32>,
+    pub proposal_id: u64,
+    pub approved_at: Option<u64>,
+    pub released: u32,
+    pub is_cancelled: bool,
+}
+
+fn load_grant(env: &Env, id: u64) -> Result<Grant, Error> {
+    storage::read(env, &DataKey::Grant(id)).ok_or(Error::GrantNotFound)
+}
+
+/// Tranche `index` (0-based); the last one carries the rounding remainder.
+fn tranche(grant: &Grant, index: u32) -> i128 {
+    let share = grant.amount / grant.milestones as i128;
+    if index == grant.milestones - 1 { grant.amount - share * (grant.milestones as i128 - 1) } else { share }
+}
+
+fn unpaid(grant: &Grant) -> i128 {
+    (grant.released..grant.milestones).map(|i| tranche(grant, i)).sum()
+}
+
+pub(crate) fn validate_approval(env: &Env, id: u64) -> Result<(), Error> {
+    let grant = load_grant(env, id)?;
+    if grant.approved_at.is_some() || grant.is_cancelled { return Err(Error::InvalidGrant); }
+    Ok(())
+}
+
+pub(crate) fn validate_cancel(env: &Env, id: u64) -> Result<(), Error> {
+    let grant = load_grant(env, id)?;
+    if grant.is_cancelled || grant.released == grant.milestones { return Err(Error::InvalidGrant); }
+    Ok(())
+}
+
+/// Reserves the whole grant out of the treasury and starts the milestone clock.
+pub(crate) fn approve(env: &Env, id: u64) -> Result<(), Error> {
+    let mut grant = load_grant(env, id)?;
+    treasury::debit(env, &grant.token, grant.amount)?;
+    grant.approved_at = Some(env.ledger().timestamp());
+    storage::write(env, &DataKey::Grant(id), &grant);
+    env.events().publish((events::GRANT_APPROVED, grant.recipient), (id, grant.amount));
+    Ok(())
+}
+
+pub(crate) fn cancel(env: &Env, id: u64) -> Result<(), Error> {
+    let mut grant = load_grant(env, id)?;
+    let returned = if grant.approved_at.is_some() { unpaid(&grant) } else { 0 };
+    grant.is_cancelled = true;
+    storage::write(env, &DataKey::Grant(id), &grant);
+    treasury::credit(env, &grant.token, &env.current_contract_address(), returned);
+    env.events().publish((events::GRANT_CANCELLED, grant.recipient), (id, returned));
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 43: GRANTS ---
+
+    /// Opens the grant together with the governance proposal that approves it.
+    /// Returns the grant id; the proposal id is on the grant.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_grant(env: Env, proposer: Address, recipient: Address, token: Address, amount: i128, milestones: u32, milestone_seconds: u64, purpose_hash: BytesN<32>) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        proposer.require_auth();
+        touch_heartbeat(&env, &proposer);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if milestones == 0 || milestones > MAX_MILESTONES || milestone_seconds < MIN_MILESTONE_SECONDS { return Err(Error::InvalidGrant); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::GrantCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::GrantCount, &id);
+
+        let mut grant = Grant {
+            id,
+            proposer: proposer.clone(),
+            recipient: recipient.clone(),
+            token,
+            amount,
+            milestones,
+            milestone_seconds,
+            purpose_hash: purpose_hash.clone(),
+            proposal_id: 0,
+            approved_at: None,
+            released: 0,
+            is_cancelled: false,
+        };
+        storage::write(&env, &DataKey::Grant(id), &grant);
+        grant.proposal_id = governance::open_proposal(&env, &proposer, ProposalAction::ApproveGrant(id))?;
+        storage::write(&env, &DataKey::Grant(id), &grant);
+        env.events().publish((events::GRANT_PROPOSED, recipient), (id, amount, purpose_hash));
+        Ok(id)
+    }
+
+    /// Pays the next milestone once its interval has passed since approval. Anyone may call it.
+    pub fn release_milestone(env: Env, grant_id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        let mut grant = load_grant(&env, grant_id)?;
+        if grant.is_cancelled { return Err(Error::InvalidGrant); }
+        let approved_at = grant.approved_at.ok_or(Error::GrantNotApproved)?;
+        if grant.released >= grant.milestones { return Err(Error::GrantComplete); }
+        let unlocks_at = approved_at.saturating_add(grant.milestone_seconds.saturating_mul(grant.released as u64 + 1));
+        if env.ledger().timestamp() < unlocks_at { return Err(Error::MilestoneLocked); }
+
+        let amount = tranche(&grant, grant.released);
+        grant.released += 1;
+        storage::write(&env, &DataKey::Grant(grant_id), &grant);
+        token::Client::new(&env, &grant.token).transfer(&env.current_contract_address(), &grant.recipient, &amount);
+        env.events().publish((events::GRANT_PAID, grant.recipient), (grant_id, grant.released, amount));
+        Ok(amount)
+    }
+
+    pub fn get_grant(env: Env, grant_id: u64) -> Result<Grant, Error> {
+        load_grant(&env, grant_id)
+    }
+}
@@ -0,0 +1,149 @@
+// 🏛️ PROJECT BAZAAR | MINOR-HEIR GUARDIANSHIP
+// An owner can name an heir as a minor with a guardian and an unlock date. Anything
+// that heir inherits before the date is held here; the guardian may draw a capped
+// monthly allowance, and the heir takes the rest once they come of age.
+
+use super::*;
+
+const MAX_ALLOWANCE_BPS: u32 = 1_000;
+const ALLOWANCE_PERIOD_SECONDS: u64 = 2_592_000; // 30 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MinorTerms {
+    pub guardian: Address,
+    pub unlock_at: u64,
+    pub allowance_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GuardedFunds {
+    pub terms: MinorTerms,
+    pub balances: Map<Address, i128>,
+    pub last_allowance: Map<Address, u64>,
+}
+
+fn load_guarded(env: &Env, owner: &Address, heir: &Address) -> Result<GuardedFunds, Error> {
+    storage::read(env, &DataKey::GuardedFunds(owner.clone(), heir.clone())).ok_or(Error::NoGuardedFunds)
+}
+
+/// Final hop of every inheritance payout: minors still under guardianship get their
+/// share parked in `GuardedFunds`, everyone else is paid directly.
+pub(crate) fn deliver(env: &Env, owner: &Address, vault_id: u32, heir: &Address, token_id: &Address, amount: i128) {
+    let terms = load_vault(env, owner, vault_id).ok().and_then(|vault| vault.minor_heirs.get(heir.clone()));
+    let Some(terms) = terms.filter(|terms| env.ledger().timestamp() < terms.unlock_at) else {
+        token::Client::new(env, token_id).transfer(&env.current_contract_address(), heir, &amount);
+        return;
+    };
+
+    let key = DataKey::GuardedFunds(owner.clone(), heir.clone());
+    let mut funds = storage::read(env, &key).unwrap_or(GuardedFunds {
+        terms,
+        balances: Map::new(env),
+        last_allowance: Map::new(env),
+    });
+    funds.balances.set(token_id.clone(), funds.balances.get(token_id.clone()).unwrap_or(0) + amount);
+    storage::write(env, &key, &funds);
+    storage::push_unique(env, &DataKey::GuardedHeirs(owner.clone()), heir.clone());
+    storage::push_unique(env, &DataKey::GuardedFor(heir.clone()), owner.clone());
+    env.events().publish((events::FUNDS_GUARDED, heir.clone()), (token_id.clone(), amount));
+}
+
+/// Re-keys funds the recovered address holds as owner or as heir, and the index at the
+/// far end of each. Funds it only looks after as guardian keep the old guardian.
+pub(crate) fn migrate_party(env: &Env, old: &Address, new: &Address) {
+    let heirs: Vec<Address> = storage::read(env, &DataKey::GuardedHeirs(new.clone())).unwrap_or(Vec::new(env));
+    for heir in heirs.iter() {
+        storage::rename(env, &DataKey::GuardedFunds(old.clone(), heir.clone()), &DataKey::GuardedFunds(new.clone(), heir.clone()));
+        storage::replace_item(env, &DataKey::GuardedFor(heir), old, new.clone());
+    }
+    let owners: Vec<Address> = storage::read(env, &DataKey::GuardedFor(new.clone())).unwrap_or(Vec::new(env));
+    for owner in owners.iter() {
+        storage::rename(env, &DataKey::GuardedFunds(owner.clone(), old.clone()), &DataKey::GuardedFunds(owner.clone(), new.clone()));
+        storage::replace_item(env, &DataKey::GuardedHeirs(owner), old, new.clone());
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 12: GUARDIANSHIP ---
+
+    pub fn set_minor_heir(env: Env, user: Address, vault_id: u32, heir: Address, terms: MinorTerms) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if terms.unlock_at <= env.ledger().timestamp() { return Err(Error::InvalidGuardianship); }
+        if terms.allowance_bps > MAX_ALLOWANCE_BPS || terms.guardian == heir { return Err(Error::InvalidGuardianship); }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.minor_heirs.set(heir.clone(), terms.clone());
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::MINOR_HEIR_SET, user, vault_id), (heir, terms.guardian, terms.unlock_at));
+        Ok(())
+    }
+
+    /// Funds already held for the heir stay under the terms they were deposited with.
+    pub fn remove_minor_heir(env: Env, user: Address, vault_id: u32, heir: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.minor_heirs.remove(heir.clone());
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::MINOR_HEIR_REMOVED, user, vault_id), heir);
+        Ok(())
+    }
+
+    /// Guardian draws up to `allowance_bps` of one held asset, at most once per 30 days per asset.
+    pub fn guardian_withdraw(env: Env, guardian: Address, owner: Address, heir: Address, token: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        guardian.require_auth();
+        touch_heartbeat(&env, &guardian);
+        let mut funds = load_guarded(&env, &owner, &heir)?;
+        if funds.terms.guardian != guardian { return Err(Error::NotGuardian); }
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        let now = env.ledger().timestamp();
+        if let Some(last) = funds.last_allowance.get(token.clone()) {
+            if now < last + ALLOWANCE_PERIOD_SECONDS { return Err(Error::AllowanceCooldown); }
+        }
+        let balance = funds.balances.get(token.clone()).unwrap_or(0);
+        if amount > balance * funds.terms.allowance_bps as i128 / FULL_SHARE_BPS as i128 { return Err(Error::AllowanceExceeded); }
+
+        funds.balances.set(token.clone(), balance - amount);
+        funds.last_allowance.set(token.clone(), now);
+        storage::write(&env, &DataKey::GuardedFunds(owner, heir.clone()), &funds);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &guardian, &amount);
+        env.events().publish((events::ALLOWANCE_PAID, heir), (guardian, token, amount));
+        Ok(())
+    }
+
+    /// The heir takes everything once the unlock date has passed.
+    pub fn release_guarded_funds(env: Env, heir: Address, owner: Address) -> Result<Map<Address, i128>, Error> {
+        require_not_paused(&env)?;
+        heir.require_auth();
+        touch_heartbeat(&env, &heir);
+        let key = DataKey::GuardedFunds(owner.clone(), heir.clone());
+        let funds = storage::read::<GuardedFunds>(&env, &key).ok_or(Error::NoGuardedFunds)?;
+        if env.ledger().timestamp() < funds.terms.unlock_at { return Err(Error::FundsLocked); }
+
+        storage::remove(&env, &key);
+        storage::remove_item(&env, &DataKey::GuardedHeirs(owner.clone()), &heir);
+        storage::remove_item(&env, &DataKey::GuardedFor(heir.clone()), &owner);
+        for (token_id, amount) in funds.balances.iter() {
+            if amount > 0 {
+                token::Client::new(&env, &token_id).transfer(&env.current_contract_address(), &heir, &amount);
+            }
+        }
+        env.events().publish((events::GUARDED_RELEASED, heir), funds.balances.clone());
+        Ok(funds.balances)
+    }
+
+    pub fn get_guarded_funds(env: Env, owner: Address, heir: Address) -> Result<GuardedFunds, Error> {
+        load_guarded(&env, &owner, &heir)
+    }
+}
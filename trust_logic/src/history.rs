@@ -0,0 +1,75 @@
+// 🏛️ PROJECT BAZAAR | TRUST HISTORY
+// Append-only log of every trust score change per merchant, so "why did my score drop"
+// can be answered from chain data. Like the inbox, only the latest entries are kept and
+// the oldest is evicted once the log is full.
+
+use super::*;
+
+const MAX_TRUST_HISTORY: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 20;
+
+/// Stable reason codes; append new variants at the end.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrustReason {
+    Bond,
+    Unbond,
+    Vouch,
+    Unvouch,
+    VouchDiscounted,
+    Decay,
+    OrderReleased,
+    Remittance,
+    Rating,
+    PaluwaganMissed,
+    Migration,
+    Verification,
+    PartialRefund,
+    LoanDefault,
+    OrderLapsed,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TrustChange {
+    pub delta: i32,
+    pub reason: TrustReason,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+fn load_history(env: &Env, user: &Address) -> Vec<TrustChange> {
+    storage::read(env, &DataKey::TrustHistory(user.clone())).unwrap_or(Vec::new(env))
+}
+
+fn append(env: &Env, user: &Address, delta: i32, reason: TrustReason, actor: &Address) {
+    let mut history = load_history(env, user);
+    if history.len() >= MAX_TRUST_HISTORY { history.pop_front(); }
+    history.push_back(TrustChange { delta, reason, actor: actor.clone(), timestamp: env.ledger().timestamp() });
+    storage::write(env, &DataKey::TrustHistory(user.clone()), &history);
+}
+
+/// Logs `before -> after` for `user`. No-op when the score did not actually move.
+pub(crate) fn record(env: &Env, user: &Address, before: u32, after: u32, reason: TrustReason, actor: &Address) {
+    if before == after { return; }
+    append(env, user, after as i32 - before as i32, reason, actor);
+}
+
+/// Zero-delta entry for outcomes worth keeping on file that don't move the score.
+pub(crate) fn note(env: &Env, user: &Address, reason: TrustReason, actor: &Address) {
+    append(env, user, 0, reason, actor);
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 22: TRUST HISTORY ---
+
+    /// Oldest first. `limit` is capped at 20 per page.
+    pub fn get_trust_history(env: Env, user: Address, offset: u32, limit: u32) -> Vec<TrustChange> {
+        let history = load_history(&env, &user);
+        let start = offset.min(history.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(history.len());
+        history.slice(start..end)
+    }
+}
@@ -0,0 +1,51 @@
+// 🏛️ PROJECT BAZAAR | LEGACY NOTE
+// A last-will document that travels with the funds. The owner stores the IPFS digest of
+// an encrypted document; the contract only hands it out once a claim finalizes, when it
+// is announced to the heirs alongside the payout. Chain state is public, so the
+// document itself must be encrypted off-chain.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+/// Announces the note, if any, once the vault has been claimed.
+pub(crate) fn release_note(env: &Env, user: &Address, claimant: &Address) {
+    if let Some(doc_hash) = storage::read::<BytesN<32>>(env, &DataKey::LegacyNote(user.clone())) {
+        env.events().publish((events::LEGACY_NOTE_RELEASED, user.clone()), (claimant.clone(), doc_hash));
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 34: LEGACY NOTE ---
+
+    pub fn set_legacy_note(env: Env, user: Address, doc_hash: BytesN<32>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if vault_ids(&env, &user).is_empty() { return Err(Error::VaultNotFound); }
+
+        storage::write(&env, &DataKey::LegacyNote(user.clone()), &doc_hash);
+        env.events().publish((events::LEGACY_NOTE_SET, user), true);
+        Ok(())
+    }
+
+    pub fn clear_legacy_note(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+
+        storage::remove(&env, &DataKey::LegacyNote(user.clone()));
+        env.events().publish((events::LEGACY_NOTE_SET, user), false);
+        Ok(())
+    }
+
+    /// `None` until one of the owner's vaults has been claimed.
+    pub fn get_legacy_note(env: Env, user: Address) -> Option<BytesN<32>> {
+        let claimed = vault_ids(&env, &user).iter().any(|id| load_vault(&env, &user, id).is_ok_and(|vault| vault.is_claimed));
+        if !claimed { return None; }
+        storage::read(&env, &DataKey::LegacyNote(user))
+    }
+}
@@ -2,56 +2,996 @@
 // Includes: Academy Trust Score, Legacy Vault, Medical Emergency, and Panic Protocol.
 
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, token, vec, Address, Env, Map, String, Symbol, Vec};
+
+mod admin;
+mod arbiters;
+mod arbitration;
+mod away;
+mod badges;
+mod blacklist;
+mod buyers;
+mod bzr_wallet;
+mod categories;
+mod checks;
+mod claim_relayer;
+mod claims;
+mod contacts;
+mod disputes;
+mod errors;
+mod escrow;
+mod evidence;
+pub mod events;
+mod governance;
+mod grants;
+mod guardianship;
+mod history;
+mod legacy_note;
+mod listings;
+mod liveness;
+mod loans;
+mod messages;
+mod migration;
+mod oracle;
+mod paluwagan;
+mod panic_status;
+mod pools;
+mod profile;
+mod rate_limit;
+mod recovery;
+mod referrals;
+mod remittance;
+mod reviews;
+mod rewards;
+mod routing;
+mod savings;
+mod spending;
+mod storage;
+mod strategies;
+mod subscriptions;
+mod summary;
+mod tests;
+pub mod testutils;
+mod timer_props;
+mod treasury;
+mod user_config;
+mod verification;
+mod vesting;
+mod vouch_stakes;
+mod witness_stats;
+pub use admin::ProtocolConfig;
+pub use arbiters::ArbiterRecord;
+pub use arbitration::{Juror, Panel};
+pub use away::AwayWindow;
+pub use badges::{BadgeInfo, BadgeMinter, BadgeMinterClient, BADGE_BONDED, BADGE_GOAL_MET, BADGE_GUARDIAN, BADGE_ONE_YEAR, BADGE_SELLER_100, BADGE_TRUSTED, BADGE_VERIFIED};
+pub use blacklist::BlacklistEntry;
+pub use buyers::BuyerProfile;
+pub use checks::{ClaimCheck, EmergencyCheck, WithdrawCheck};
+pub use claim_relayer::ClaimRelayer;
+pub use claims::PendingClaim;
+pub use disputes::{Dispute, DisputeStatus};
+use admin::require_not_paused;
+pub use errors::Error;
+pub use escrow::{Order, OrderState, RefundProposal};
+pub use evidence::Evidence;
+pub use governance::{GovernanceParams, Proposal, ProposalAction, VaultDefaults};
+pub use grants::Grant;
+pub use guardianship::{GuardedFunds, MinorTerms};
+pub use history::{TrustChange, TrustReason};
+pub use listings::Listing;
+pub use liveness::AliveAttestations;
+pub use loans::{Loan, LoanState};
+pub use messages::Message;
+pub use migration::V2Snapshot;
+pub use oracle::{TrustOracle, TrustOracleClient};
+pub use paluwagan::Paluwagan;
+pub use panic_status::PanicStatus;
+pub use pools::{GiftPool, PoolStatus};
+pub use profile::MerchantProfile;
+pub use rate_limit::RateAction;
+pub use recovery::RecoveryRequest;
+pub use referrals::{Referral, ReferralMilestone, ReferralMonth};
+pub use remittance::Remittance;
+pub use reviews::{RatingSummary, Review};
+pub use rewards::{RewardKind, RewardRates};
+pub use routing::TokenRoute;
+pub use savings::SavingsGoal;
+pub use spending::WithdrawalRequest;
+pub use strategies::{StrategyPosition, YieldStrategy, YieldStrategyClient};
+pub use subscriptions::Subscription;
+pub use summary::AccountSummary;
+pub use treasury::FeeConfig;
+pub use user_config::{StoredUserConfig, UserConfig};
+pub use verification::VerificationLevel;
+pub use vesting::{VestingGrant, VestingSchedule};
+pub use vouch_stakes::VouchStake;
+pub use witness_stats::WitnessStats;
+
+// ============================================================
+// ⏱️ TIMERS
+// ============================================================
+
+const DEFAULT_DEADMAN_SECONDS: u64 = 15_552_000; // 180 Days
+const MIN_DEADMAN_SECONDS: u64 = 7_776_000;      // 90 Days
+const MAX_DEADMAN_SECONDS: u64 = 31_536_000;     // 365 Days
+const PANIC_GRACE_SECONDS: u64 = 604_800;        // 7 Days
+const PANIC_VOTE_WINDOW_SECONDS: u64 = 259_200;  // 72 Hours
+const CLAIM_CHALLENGE_SECONDS: u64 = 1_209_600;  // 14 Days
+const HEIR_CHANGE_DELAY_SECONDS: u64 = 2_592_000; // 30 Days
+const WARNING_WINDOW_SECONDS: u64 = 2_592_000;   // 30 Days
+const REMINDER_COOLDOWN_SECONDS: u64 = 259_200;  // 72 Hours
+const DEFAULT_PANIC_RESPONSE_SECONDS: u64 = 259_200; // 72 Hours
+const MAX_PANIC_RESPONSE_SECONDS: u64 = 2_592_000;   // 30 Days
+const BOND_LOCK_SECONDS: u64 = 2_592_000;        // 30 Days
+const TRUST_DECAY_PERIOD_SECONDS: u64 = 2_592_000; // 30 Days
+const EMERGENCY_TTL_SECONDS: u64 = 2_592_000;    // 30 Days
+const EMERGENCY_VOTE_SECONDS: u64 = 1_209_600;   // 14 Days
+const WITNESS_ACTIVATION_SECONDS: u64 = 604_800; // 7 Days
+const YEAR_SECONDS: u64 = 31_536_000;            // 365 Days
+const DEFAULT_BACKUP_GRACE_SECONDS: u64 = 2_592_000; // 30 Days
+const MIN_BACKUP_GRACE_SECONDS: u64 = 604_800;       // 7 Days
+const MAX_BACKUP_GRACE_SECONDS: u64 = 15_552_000;    // 180 Days
+
+const FULL_SHARE_BPS: u32 = 10_000;
+/// (votes, share of the vault): 15% at 3 votes, 30% at 4, 50% with the full circle of 5.
+/// These are the defaults; an owner's `medical_release_bps` scales all three.
+const MEDICAL_TIERS: [(u32, u32); 3] = [(3, 1_500), (4, 3_000), (5, 5_000)];
+const MEDICAL_YEARLY_CAP_BPS: u32 = 5_000; // 50% of all vaults per year, across emergencies
+const MAX_HEIRS: u32 = 10;
+const MAX_BACKUP_HEIRS: u32 = 5;
+const MAX_VAULT_ASSETS: u32 = 10;
+const MAX_VAULTS: u32 = 5;
+/// Owner-wide settings (user config, proof of life) read their timers from the first vault.
+const PRIMARY_VAULT: u32 = 0;
+const MAX_WITNESSES: u32 = 5;
+const DEFAULT_QUORUM: u32 = 3;
+const MAX_WITNESS_WEIGHT: u32 = 3;
+const BOND_TRUST_POINTS: u32 = 10;
+const MAX_TRUST_SCORE: u32 = 100;
+const MAX_VOUCH_WEIGHT: u32 = 5;
+const MAX_ACTIVE_VOUCHES: u32 = 10;
+const VOUCHER_COLLAPSE_SCORE: u32 = 10;
+const MAX_BATCH_SIZE: u32 = 10;
+/// Lower bounds of Silver, Gold and Platinum.
+const TRUST_THRESHOLDS: [u32; 3] = [25, 50, 75];
 
 // ============================================================
 // 📦 DATA STRUCTURES
 // ============================================================
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum TrustTier {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+/// Lifecycle of a vault as wallets should display it. Warning covers the last 30 days
+/// before the deadman switch fires, i.e. day 150 onwards with the default 180-day timeout.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VaultState {
+    Active,
+    Warning,
+    Claimable,
+    Claimed,
+    Frozen,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Merchant {
     pub trust_score: u32,
     pub bond_staked: bool,
-    pub bzr_balance: i128,
+    /// Rewards accrued but not yet paid out of the pool; see `rewards`. Not to be confused
+    /// with the spendable wallet balance under `DataKey::BzrBalance`.
+    pub pending_rewards: i128,
     pub badges: Vec<Symbol>,
     pub is_disputed: bool,
     pub nickname: Symbol,
-    pub messages: Vec<Message>,
+    pub staked_amount: i128,
+    pub stake_timestamp: u64,
+    pub last_activity: u64,
+    pub completed_orders: u32,
+    pub joined_at: u64,
+    pub remittances_sent: u32,
+    pub verification: VerificationLevel,
 }
 
+/// Hot copy of the score kept under its own key, so trust lookups don't decode the whole
+/// merchant record. Written only by `write_merchant`.
 #[contracttype]
 #[derive(Clone)]
-pub struct Message {
-    pub sender: Address,
-    pub text: String,
-    pub timestamp: u64,
+pub struct TrustScore {
+    pub score: u32,
+    pub last_activity: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct HeirShare {
+    pub address: Address,
+    pub bps: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct LegacyVault {
-    pub heir: Option<Address>,
+    pub heirs: Vec<HeirShare>,
     pub last_heartbeat: u64,
     pub is_locked: bool,
     pub is_frozen: bool,
+    pub balances: Map<Address, i128>,
+    pub timeout_seconds: u64,
+    /// Set by `finalize_claim` before any payout leaves the contract, cleared by `reclaim_vault`.
+    pub is_claimed: bool,
+    pub backup_heirs: Vec<Address>,
+    pub backup_grace_seconds: u64,
+    pub pre_panic_heartbeat: Option<u64>,
+    /// Set while a panic freeze is in force.
+    pub frozen_at: Option<u64>,
+    pub panic_response_seconds: u64,
+    pub accepted_heirs: Vec<Address>,
+    pub minor_heirs: Map<Address, MinorTerms>,
+    pub medical_payee: Option<Address>,
+    /// Whether the owner's other authenticated actions count as heartbeats; on by default.
+    pub passive_heartbeat: bool,
+}
+
+/// Heir change waiting out its delay. A panic freeze discards it.
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingHeirs {
+    pub heirs: Vec<HeirShare>,
+    pub effective_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VaultView {
+    pub vault: LegacyVault,
+    pub claimable_at: u64,
+    pub seconds_until_claimable: u64,
+    pub is_claimable: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Witness {
+    pub address: Address,
+    pub active_from: u64,
+    pub accepted: bool,
+    /// How much this witness's vote counts toward the medical, panic and recovery quorums.
+    pub weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct CircleConfig {
+    pub max_witnesses: u32,
+    pub medical_quorum: u32,
+    pub panic_quorum: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct MedicalEmergency {
     pub target_user: Address,
+    pub declared_by: Address,
+    /// Combined weight of `voters`, not their number.
     pub votes_collected: u32,
     pub is_unlocked: bool,
+    pub voters: Vec<Address>,
+    pub is_consumed: bool,
+    pub declared_at: u64,
+    pub voting_ends_at: u64,
+    pub expires_at: u64,
+    pub unlocked_bps: u32,
+    pub released: Map<Address, i128>,
+    pub recipient_votes: Map<Address, Address>,
 }
 
+/// Medical withdrawals in the rolling year that started at `started_at`, per asset.
 #[contracttype]
+#[derive(Clone)]
+pub struct MedicalYear {
+    pub started_at: u64,
+    pub spent: Map<Address, i128>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EmergencyStatus {
+    pub votes_collected: u32,
+    pub is_unlocked: bool,
+    pub voters: Vec<Address>,
+    pub pending_witnesses: Vec<Address>,
+    pub voting_ends_at: u64,
+    pub expires_at: u64,
+    pub unlocked_bps: u32,
+    pub released: Map<Address, i128>,
+}
+
+/// Storage keys only; never part of the interface, and past the spec's 50-case cap.
+#[contracttype(export = false)]
+#[derive(Clone)]
 pub enum DataKey {
+    Admin,
+    Deployer,
+    BzrToken,
+    Config,
+    Paused,
     Merchant(Address),
-    Vault(Address),
+    Vault(Address, u32),
     Witnesses(Address),
     Emergency(Address),
     PanicVotes(Address),
+    CircleConfig(Address),
+    DisputeCount,
+    Dispute(u64),
+    OpenDisputes(Address),
+    Arbiter(Address),
+    Compensation(Address),
+    BadgeRevoked(Address, Symbol),
+    Inbox(Address),
+    OrderCount,
+    Order(u64),
+    ListingCount,
+    Listing(u64),
+    MerchantListings(Address),
+    JurorPool,
+    Juror(Address),
+    Panel(u64),
+    HeartbeatDelegate(Address),
+    NamedHeirIn(Address),
+    PendingClaim(Address, u32),
+    /// `(claimant, vetoed_at)` of the last claim the circle vetoed on this vault.
+    ClaimVetoed(Address, u32),
+    Vesting(Address),
+    VaultVesting(Address, u32),
+    PendingHeirs(Address, u32),
+    GuardedFunds(Address, Address),
+    GuardedHeirs(Address),
+    GuardedFor(Address),
+    Recovery(Address),
+    LastReminder(Address, u32),
+    RemitHistory(Address),
+    Treasury(Address),
+    FeeConfig,
+    PaluwaganCount,
+    Paluwagan(u64),
+    PaluwaganMemberships(Address),
+    Nickname(Symbol),
+    Profile(Address),
+    Review(u64),
+    Rating(Address),
+    VouchesGiven(Address),
+    Vouchers(Address),
+    VaultState(Address, u32),
+    MedicalYear(Address),
+    WitnessFor(Address),
+    RateLimit(RateAction, Address, Address),
+    LegacyContract,
+    Migrated(Address),
+    OracleConsumer(Address),
+    RewardRates,
+    RewardPool,
+    TrustHistory(Address),
+    AliveAttestations(Address),
+    UserConfig(Address),
+    GrantHeirs(Address),
+    ShippingOracle(Address),
+    RefundProposal(u64),
+    SubscriptionCount,
+    Subscription(u64),
+    SubscriptionsOf(Address),
+    GoalCount,
+    SavingsGoal(u64),
+    GoalsOf(Address),
+    PoolCount,
+    GiftPool(u64),
+    PoolsOf(Address),
+    LoanCount,
+    Loan(u64),
+    ActiveLoan(Address),
+    Strategy(Address),
+    StrategyPositions(Address, u32),
+    WitnessStats(Address),
+    SpendingLimits(Address),
+    SpendingUsed(Address, Address),
+    WithdrawalCount,
+    WithdrawalRequest(u64),
+    LegacyNote(Address),
+    TokenRouting(Address, u32),
+    TrustScore(Address),
+    BuyerProfile(Address),
+    CategoryTrust(Address),
+    VouchStakes(Address),
+    Evidence(u64),
+    EvidenceAck(u64, Address),
+    ArbiterRecord(Address),
+    ArbiterRegistry,
+    AssignedArbiter(u64),
+    Overturned(u64),
+    ProposalCount,
+    Proposal(u64),
+    ProposalVote(u64, Address),
+    GovernanceParams,
+    VaultDefaults,
+    GrantCount,
+    Grant(u64),
+    Referral(Address),
+    ReferralMonth(Address),
+    Away(Address),
+    Blacklist(Address),
+    WitnessContact(Address),
+    ClaimRelayer(Address, u32),
+    VaultCount(Address),
+    BadgeMinter,
+    BadgeIssued(Address, Symbol),
+    BzrBalance(Address),
+}
+
+// ============================================================
+// 🔧 HELPERS
+// ============================================================
+
+fn load_vault(env: &Env, user: &Address, vault_id: u32) -> Result<LegacyVault, Error> {
+    storage::read(env, &DataKey::Vault(user.clone(), vault_id)).ok_or(Error::VaultNotFound)
+}
+
+/// Ids of every vault `user` owns, oldest first. Vaults are never deleted, so the ids
+/// simply count up from 0.
+fn vault_ids(env: &Env, user: &Address) -> Vec<u32> {
+    let count: u32 = storage::read(env, &DataKey::VaultCount(user.clone())).unwrap_or(0);
+    let mut ids = Vec::new(env);
+    for id in 0..count { ids.push_back(id); }
+    ids
+}
+
+/// Whether any of `owner`'s vaults lists `heir` among its primary heirs.
+fn names_heir(env: &Env, owner: &Address, heir: &Address) -> bool {
+    vault_ids(env, owner).iter().any(|id| {
+        load_vault(env, owner, id).is_ok_and(|vault| vault.heirs.iter().any(|share| share.address == *heir))
+    })
+}
+
+/// Fresh vault naming `heir` as sole heir, with every timer at the governed default.
+fn new_vault(env: &Env, heir: &Address) -> LegacyVault {
+    let defaults = governance::vault_defaults(env);
+    LegacyVault {
+        heirs: vec![env, HeirShare { address: heir.clone(), bps: FULL_SHARE_BPS }],
+        last_heartbeat: env.ledger().timestamp(),
+        is_locked: true,
+        is_frozen: false,
+        balances: Map::new(env),
+        timeout_seconds: defaults.deadman_seconds,
+        is_claimed: false,
+        backup_heirs: Vec::new(env),
+        backup_grace_seconds: defaults.backup_grace_seconds,
+        pre_panic_heartbeat: None,
+        frozen_at: None,
+        panic_response_seconds: defaults.panic_response_seconds,
+        accepted_heirs: Vec::new(env),
+        minor_heirs: Map::new(env),
+        medical_payee: None,
+        passive_heartbeat: true,
+    }
+}
+
+/// Single write path for vaults, so every state transition gets announced.
+fn write_vault(env: &Env, user: &Address, vault_id: u32, vault: &LegacyVault) {
+    storage::write(env, &DataKey::Vault(user.clone(), vault_id), vault);
+    announce_vault_state(env, user, vault_id, vault);
+}
+
+/// Creates `user`'s next vault with `heir` as sole heir and returns its id.
+fn open_vault(env: &Env, user: &Address, heir: &Address) -> Result<u32, Error> {
+    let vault_id: u32 = storage::read(env, &DataKey::VaultCount(user.clone())).unwrap_or(0);
+    if vault_id >= MAX_VAULTS { return Err(Error::TooManyVaults); }
+    storage::write(env, &DataKey::VaultCount(user.clone()), &(vault_id + 1));
+    write_vault(env, user, vault_id, &new_vault(env, heir));
+    index_heir(env, heir, user);
+    env.events().publish((events::VAULT_CREATED, user.clone(), vault_id), heir.clone());
+    Ok(vault_id)
+}
+
+fn vault_state(env: &Env, vault: &LegacyVault) -> VaultState {
+    if vault.is_frozen { return VaultState::Frozen; }
+    if vault.is_claimed { return VaultState::Claimed; }
+    let now = env.ledger().timestamp();
+    let fires_at = claimable_at(vault);
+    if now >= fires_at {
+        VaultState::Claimable
+    } else if now + WARNING_WINDOW_SECONDS >= fires_at {
+        VaultState::Warning
+    } else {
+        VaultState::Active
+    }
+}
+
+/// Time-driven transitions happen without a write, so the last announced state is kept
+/// alongside the vault and compared on every write and on every `sync_vault_state` call.
+fn announce_vault_state(env: &Env, user: &Address, vault_id: u32, vault: &LegacyVault) -> VaultState {
+    let state = vault_state(env, vault);
+    let key = DataKey::VaultState(user.clone(), vault_id);
+    let announced: Option<VaultState> = storage::read(env, &key);
+    if announced != Some(state) {
+        storage::write(env, &key, &state);
+        env.events().publish((events::VAULT_STATE, user.clone(), vault_id), (announced, state));
+    }
+    state
+}
+
+/// Panic freeze guard for owner actions: while witnesses hold the vault frozen, a stolen
+/// key can neither move funds out nor rewrite the heirs and circle. A panic freezes all
+/// of an owner's vaults together, so any frozen vault blocks. No vault, no freeze.
+fn require_not_frozen(env: &Env, user: &Address) -> Result<(), Error> {
+    for id in vault_ids(env, user).iter() {
+        if load_vault(env, user, id).is_ok_and(|vault| vault.is_frozen) { return Err(Error::VaultFrozen); }
+    }
+    Ok(())
+}
+
+/// Pays `amount` of `token` from the vault back to its owner. Callers check auth and limits.
+fn withdraw_from_vault(env: &Env, user: &Address, vault_id: u32, token: &Address, amount: i128) -> Result<(), Error> {
+    let mut vault = load_vault(env, user, vault_id)?;
+    if vault.is_frozen { return Err(Error::VaultFrozen); }
+
+    let balance = vault.balances.get(token.clone()).unwrap_or(0);
+    if amount > balance { return Err(Error::InsufficientBalance); }
+
+    if balance == amount {
+        vault.balances.remove(token.clone());
+    } else {
+        vault.balances.set(token.clone(), balance - amount);
+    }
+    write_vault(env, user, vault_id, &vault);
+
+    token::Client::new(env, token).transfer(&env.current_contract_address(), user, &amount);
+    env.events().publish((events::VAULT_WITHDRAW, user.clone(), vault_id), (token.clone(), amount));
+    Ok(())
+}
+
+/// Pro-rata split of one asset; the last heir absorbs the rounding dust.
+fn split_among_heirs(env: &Env, heirs: &Vec<HeirShare>, amount: i128) -> Vec<(Address, i128)> {
+    let last = heirs.len() - 1;
+    let mut portions = Vec::new(env);
+    let mut distributed: i128 = 0;
+    for (i, share) in heirs.iter().enumerate() {
+        let portion = if i as u32 == last {
+            amount - distributed
+        } else {
+            amount * share.bps as i128 / FULL_SHARE_BPS as i128
+        };
+        distributed += portion;
+        portions.push_back((share.address, portion));
+    }
+    portions
+}
+
+fn validate_heirs(heirs: &Vec<HeirShare>) -> Result<(), Error> {
+    if heirs.is_empty() { return Err(Error::NoHeir); }
+    if heirs.len() > MAX_HEIRS { return Err(Error::InvalidHeirShares); }
+
+    let mut total: u32 = 0;
+    for (i, share) in heirs.iter().enumerate() {
+        if share.bps == 0 { return Err(Error::InvalidHeirShares); }
+        for other in heirs.iter().skip(i + 1) {
+            if other.address == share.address { return Err(Error::InvalidHeirShares); }
+        }
+        total += share.bps;
+    }
+    if total != FULL_SHARE_BPS { return Err(Error::InvalidHeirShares); }
+    Ok(())
+}
+
+/// Scales the remaining shares back up to 100%; the last heir absorbs the rounding dust.
+fn rescale_shares(env: &Env, heirs: &Vec<HeirShare>) -> Vec<HeirShare> {
+    let total: u32 = heirs.iter().map(|share| share.bps).sum();
+    let mut scaled = Vec::new(env);
+    let mut assigned: u32 = 0;
+    for (i, share) in heirs.iter().enumerate() {
+        let bps = if i as u32 == heirs.len() - 1 {
+            FULL_SHARE_BPS - assigned
+        } else {
+            (share.bps as u64 * FULL_SHARE_BPS as u64 / total as u64) as u32
+        };
+        assigned += bps;
+        scaled.push_back(HeirShare { address: share.address, bps });
+    }
+    scaled
+}
+
+/// Reverse indexes (owners naming an heir, users guarded by a witness, heirs holding an
+/// owner's vesting grants) are plain address lists.
+fn add_to_index(env: &Env, key: &DataKey, owner: &Address) {
+    let mut owners: Vec<Address> = storage::read(env, key).unwrap_or(Vec::new(env));
+    if owners.contains(owner) { return; }
+    owners.push_back(owner.clone());
+    storage::write(env, key, &owners);
+}
+
+fn remove_from_index(env: &Env, key: &DataKey, owner: &Address) {
+    let mut owners: Vec<Address> = storage::read(env, key).unwrap_or(Vec::new(env));
+    let Some(index) = owners.first_index_of(owner) else { return; };
+    owners.remove(index);
+    if owners.is_empty() { storage::remove(env, key); } else { storage::write(env, key, &owners); }
+}
+
+/// Reverse index so an heir can discover every vault that names them.
+fn index_heir(env: &Env, heir: &Address, owner: &Address) {
+    add_to_index(env, &DataKey::NamedHeirIn(heir.clone()), owner);
+}
+
+/// Call after the vault is written: the owner only leaves the index once none of their
+/// vaults names the heir any more.
+fn unindex_heir(env: &Env, heir: &Address, owner: &Address) {
+    if names_heir(env, owner, heir) { return; }
+    remove_from_index(env, &DataKey::NamedHeirIn(heir.clone()), owner);
+}
+
+/// Moment the deadman switch fires and the primary heirs may claim. Timer math saturates
+/// throughout: an overflow must push a deadline later, never wrap it into the past.
+fn claimable_at(vault: &LegacyVault) -> u64 {
+    vault.last_heartbeat.saturating_add(vault.timeout_seconds)
+}
+
+/// Backup `index` (0-based) waits one extra grace period per heir ahead of it.
+fn backup_claimable_at(vault: &LegacyVault, index: u32) -> u64 {
+    claimable_at(vault).saturating_add((index as u64 + 1).saturating_mul(vault.backup_grace_seconds))
+}
+
+/// Heartbeat a panic freeze rewinds to, so the deadman switch fires once the owner's
+/// response window and the panic grace have both passed. Floors at 0 instead of
+/// underflowing, which only ever lands the deadline later.
+fn panic_rewind(now: u64, response_seconds: u64, timeout_seconds: u64, grace_seconds: u64) -> u64 {
+    let time_warp = timeout_seconds.saturating_sub(grace_seconds);
+    now.saturating_add(response_seconds).saturating_sub(time_warp)
+}
+
+/// Merchants are created lazily: a missing profile reads as a blank one.
+/// Inactivity decay is applied in memory, so views always see the current score.
+fn load_merchant(env: &Env, user: &Address) -> Merchant {
+    let mut merchant = storage::read(env, &DataKey::Merchant(user.clone())).unwrap_or(Merchant {
+        trust_score: 0, bond_staked: false, pending_rewards: 0, badges: Vec::new(env), 
+        is_disputed: false, nickname: Symbol::new(env, "User"),
+        staked_amount: 0, stake_timestamp: 0, last_activity: 0,
+        completed_orders: 0, joined_at: env.ledger().timestamp(), remittances_sent: 0,
+        verification: VerificationLevel::None,
+    });
+    apply_decay(env, user, &mut merchant);
+    merchant
+}
+
+/// Same as `load_merchant`, for callers about to write the profile back: any decay
+/// that becomes permanent is announced, including drops below a badge threshold.
+fn load_merchant_for_update(env: &Env, user: &Address) -> Merchant {
+    let stored: Option<Merchant> = storage::read(env, &DataKey::Merchant(user.clone()));
+    let merchant = load_merchant(env, user);
+    let before = stored.map(|stored| stored.trust_score).unwrap_or(0);
+    if merchant.trust_score < before {
+        env.events().publish((events::TRUST_DECAYED, user.clone()), (before, merchant.trust_score));
+        history::record(env, user, before, merchant.trust_score, TrustReason::Decay, &env.current_contract_address());
+        for threshold in TRUST_THRESHOLDS {
+            if before >= threshold && merchant.trust_score < threshold {
+                env.events().publish((events::TRUST_THRESHOLD_LOST, user.clone()), threshold);
+            }
+        }
+    }
+    merchant
+}
+
+pub(crate) fn tier_of(score: u32) -> TrustTier {
+    match score {
+        s if s >= TRUST_THRESHOLDS[2] => TrustTier::Platinum,
+        s if s >= TRUST_THRESHOLDS[1] => TrustTier::Gold,
+        s if s >= TRUST_THRESHOLDS[0] => TrustTier::Silver,
+        _ => TrustTier::Bronze,
+    }
+}
+
+/// Single write path for merchant profiles, so every tier change gets announced and the
+/// hot score copy never drifts from the full record.
+fn write_merchant(env: &Env, user: &Address, merchant: &Merchant) {
+    let score_key = DataKey::TrustScore(user.clone());
+    let stored = match storage::read::<TrustScore>(env, &score_key) {
+        Some(hot) => Some(hot.score),
+        None => storage::read::<Merchant>(env, &DataKey::Merchant(user.clone())).map(|stored| stored.trust_score),
+    };
+    let before = stored.map(tier_of).unwrap_or(TrustTier::Bronze);
+    let after = tier_of(merchant.trust_score);
+    storage::write(env, &DataKey::Merchant(user.clone()), merchant);
+    storage::write(env, &score_key, &TrustScore { score: merchant.trust_score, last_activity: merchant.last_activity });
+    if before != after { env.events().publish((events::TIER_CHANGED, user.clone()), after); }
+}
+
+/// Current trust score, decay included, read from the hot key. Merchants last written
+/// before the key existed fall back to the full record.
+pub(crate) fn current_trust(env: &Env, user: &Address) -> u32 {
+    match storage::read::<TrustScore>(env, &DataKey::TrustScore(user.clone())) {
+        Some(hot) => away::decayed_for(env, user, hot.score, hot.last_activity, env.ledger().timestamp()).0,
+        None => load_merchant(env, user).trust_score,
+    }
+}
+
+/// -1 trust per full 30 days since `last_activity`. The checkpoint advances by the
+/// periods consumed so the remainder keeps counting towards the next point.
+/// Returns the decayed score and the new checkpoint.
+fn decayed(score: u32, last_activity: u64, now: u64) -> (u32, u64) {
+    if last_activity == 0 || now <= last_activity { return (score, last_activity); }
+
+    let periods = (now - last_activity) / TRUST_DECAY_PERIOD_SECONDS;
+    if periods == 0 { return (score, last_activity); }
+    (score.saturating_sub(periods.min(u32::MAX as u64) as u32), last_activity + periods * TRUST_DECAY_PERIOD_SECONDS)
+}
+
+fn apply_decay(env: &Env, user: &Address, merchant: &mut Merchant) {
+    (merchant.trust_score, merchant.last_activity) = away::decayed_for(env, user, merchant.trust_score, merchant.last_activity, env.ledger().timestamp());
+}
+
+/// Shared by `vouch` and `vouch_many`; the caller has already checked the voucher's auth.
+/// Each new endorsement also locks a BZR vouch stake.
+fn record_vouch(env: &Env, voucher: &Address, target: &Address) -> Result<(), Error> {
+    if voucher == target { return Err(Error::SelfVouch); }
+    blacklist::require_not_blacklisted(env, voucher)?;
+
+    let mut voucher_data = load_merchant_for_update(env, voucher);
+    if !voucher_data.bond_staked { return Err(Error::NotBonded); }
+
+    let now = env.ledger().timestamp();
+    rate_limit::consume(env, RateAction::Vouch, voucher, target)?;
+
+    let given_key = DataKey::VouchesGiven(voucher.clone());
+    let mut given: Vec<Address> = storage::read(env, &given_key).unwrap_or(Vec::new(env));
+    if given.contains(target) { return Err(Error::AlreadyVouched); }
+    if given.len() >= MAX_ACTIVE_VOUCHES { return Err(Error::TooManyVouches); }
+
+    // Endorsements from trusted merchants count for more: score/20, between 1 and 5 points
+    let weight = (voucher_data.trust_score / 20).clamp(1, MAX_VOUCH_WEIGHT);
+
+    // Vouching counts as activity for the voucher, not the target
+    voucher_data.last_activity = now;
+    badges::check_and_award_badges(env, voucher, &mut voucher_data);
+    write_merchant(env, voucher, &voucher_data);
+
+    // SAFE INITIALIZATION: No more "Target not found" traps
+    let mut target_data = load_merchant_for_update(env, target);
+    let before = target_data.trust_score;
+    target_data.trust_score = (target_data.trust_score + weight).min(MAX_TRUST_SCORE);
+    rewards::accrue(env, target, &mut target_data, RewardKind::Vouch);
+    badges::check_and_award_badges(env, target, &mut target_data);
+    write_merchant(env, target, &target_data);
+    history::record(env, target, before, target_data.trust_score, TrustReason::Vouch, voucher);
+
+    // Record the points that actually landed so unvouch/discount can take back exactly those
+    given.push_back(target.clone());
+    storage::write(env, &given_key, &given);
+    vouch_stakes::lock(env, voucher, target)?;
+    let vouchers_key = DataKey::Vouchers(target.clone());
+    let mut vouchers: Map<Address, u32> = storage::read(env, &vouchers_key).unwrap_or(Map::new(env));
+    vouchers.set(voucher.clone(), target_data.trust_score - before);
+    storage::write(env, &vouchers_key, &vouchers);
+    env.events().publish((events::TRUST_CHANGED, target.clone()), target_data.trust_score);
+    Ok(())
+}
+
+/// Drops the voucher -> target edge and takes its points back off the target's score.
+fn remove_vouch_edge(env: &Env, voucher: &Address, target: &Address, reason: TrustReason) -> u32 {
+    let vouchers_key = DataKey::Vouchers(target.clone());
+    let mut vouchers: Map<Address, u32> = storage::read(env, &vouchers_key).unwrap_or(Map::new(env));
+    let points = vouchers.get(voucher.clone()).unwrap_or(0);
+    vouchers.remove(voucher.clone());
+    if vouchers.is_empty() { storage::remove(env, &vouchers_key); } else { storage::write(env, &vouchers_key, &vouchers); }
+
+    if points > 0 {
+        let mut target_data = load_merchant_for_update(env, target);
+        let before = target_data.trust_score;
+        target_data.trust_score = target_data.trust_score.saturating_sub(points);
+        write_merchant(env, target, &target_data);
+        history::record(env, target, before, target_data.trust_score, reason, voucher);
+        env.events().publish((events::TRUST_CHANGED, target.clone()), target_data.trust_score);
+    }
+    points
+}
+
+/// Call after any write that lowers `voucher`'s standing. Once they lose their bond or fall
+/// under 10 trust, every vouch they gave is withdrawn along with its points. Targets are
+/// not re-checked in turn, so one collapse never cascades through the graph.
+fn discount_if_collapsed(env: &Env, voucher: &Address, merchant: &Merchant) {
+    if merchant.bond_staked && merchant.trust_score >= VOUCHER_COLLAPSE_SCORE { return; }
+    let given_key = DataKey::VouchesGiven(voucher.clone());
+    let given: Vec<Address> = storage::read(env, &given_key).unwrap_or(Vec::new(env));
+    if given.is_empty() { return; }
+
+    storage::remove(env, &given_key);
+    for target in given.iter() {
+        let points = remove_vouch_edge(env, voucher, &target, TrustReason::VouchDiscounted);
+        env.events().publish((events::VOUCH_DISCOUNTED, target), (voucher.clone(), points));
+    }
+}
+
+fn load_circle(env: &Env, user: &Address) -> Result<Vec<Witness>, Error> {
+    storage::read(env, &DataKey::Witnesses(user.clone())).ok_or(Error::NoCircle)
+}
+
+/// Live emergency for `user`; stale ones, and ones that missed the quorum before the
+/// voting deadline, read as expired so they can be replaced.
+fn load_emergency(env: &Env, user: &Address) -> Result<MedicalEmergency, Error> {
+    let emergency: MedicalEmergency = storage::read(env, &DataKey::Emergency(user.clone())).ok_or(Error::EmergencyNotFound)?;
+    let now = env.ledger().timestamp();
+    if now >= emergency.expires_at { return Err(Error::EmergencyExpired); }
+    if !emergency.is_unlocked && now >= emergency.voting_ends_at { return Err(Error::EmergencyExpired); }
+    Ok(emergency)
+}
+
+/// Combined weight of `voters` in `user`'s circle; anyone no longer in it counts for nothing.
+fn vote_weight(env: &Env, user: &Address, voters: &Vec<Address>) -> u32 {
+    let circle = load_circle(env, user).unwrap_or(Vec::new(env));
+    circle.iter().filter(|member| voters.contains(&member.address)).map(|member| member.weight).sum()
+}
+
+/// A circle change may not leave the medical or panic quorum out of the circle's reach.
+fn require_quorum_reachable(env: &Env, user: &Address, circle: &Vec<Witness>) -> Result<(), Error> {
+    let total: u32 = circle.iter().map(|member| member.weight).sum();
+    let config = circle_config(env, user);
+    if total < config.medical_quorum.max(config.panic_quorum) { return Err(Error::InvalidQuorum); }
+    Ok(())
+}
+
+/// Both quorums must be at least 1 and within the circle's reach. Before the first circle
+/// they are held to what `max_witnesses` witnesses could weigh at most; `assign_witnesses`
+/// checks them against the real circle once there is one.
+fn require_valid_quorums(env: &Env, user: &Address, config: &CircleConfig) -> Result<(), Error> {
+    let reach = match load_circle(env, user) {
+        Ok(circle) => circle.iter().map(|member| member.weight).sum(),
+        Err(_) => config.max_witnesses * MAX_WITNESS_WEIGHT,
+    };
+    for quorum in [config.medical_quorum, config.panic_quorum] {
+        if quorum == 0 || quorum > reach { return Err(Error::InvalidQuorum); }
+    }
+    Ok(())
+}
+
+/// Highest weight the whole circle can bring, so quorums above it are rejected.
+fn circle_weight(env: &Env, user: &Address) -> u32 {
+    load_circle(env, user).map(|circle| circle.iter().map(|member| member.weight).sum()).unwrap_or(0)
+}
+
+/// Tier reached by `votes`, scaled to the owner's first-tier share. Circles with a
+/// quorum below 3 still start at the first tier.
+fn medical_tier_bps(votes: u32, release_bps: u32) -> u32 {
+    let mut bps = MEDICAL_TIERS[0].1;
+    for (needed, tier_bps) in MEDICAL_TIERS {
+        if votes >= needed { bps = tier_bps; }
+    }
+    bps * release_bps / MEDICAL_TIERS[0].1
+}
+
+/// Share of `token` the emergency still allows. Percentages are taken against the owner's
+/// holdings before this emergency's releases, so drawing in parts, or from different
+/// vaults in any order, never shrinks the total.
+fn medical_headroom(emergency: &MedicalEmergency, token: &Address, balance: i128) -> i128 {
+    let released = emergency.released.get(token.clone()).unwrap_or(0);
+    (balance + released) * emergency.unlocked_bps as i128 / FULL_SHARE_BPS as i128 - released
+}
+
+/// Everything the owner holds per asset across their vaults, parked principal included.
+/// Emergencies and the yearly cap are owner-wide, so their shares are taken of this total;
+/// `current` stands in for the stored copy of vault `vault_id`.
+fn medical_holdings(env: &Env, user: &Address, vault_id: u32, current: &LegacyVault) -> Map<Address, i128> {
+    let mut holdings: Map<Address, i128> = Map::new(env);
+    for id in vault_ids(env, user).iter() {
+        let (balances, parked) = if id == vault_id {
+            (current.balances.clone(), strategies::parked_principal(env, user, id))
+        } else {
+            let Ok(vault) = load_vault(env, user, id) else { continue; };
+            (vault.balances, strategies::parked_principal(env, user, id))
+        };
+        for (token, amount) in balances.iter().chain(parked.iter()) {
+            holdings.set(token.clone(), holdings.get(token).unwrap_or(0) + amount);
+        }
+    }
+    holdings
+}
+
+/// Where medical funds go: the owner's designated payee, otherwise whichever recipient
+/// a quorum of the witnesses who voted for the emergency has agreed on.
+fn medical_recipient(env: &Env, vault: &LegacyVault, emergency: &MedicalEmergency) -> Option<Address> {
+    if let Some(payee) = &vault.medical_payee { return Some(payee.clone()); }
+    let quorum = circle_config(env, &emergency.target_user).medical_quorum;
+    for candidate in emergency.recipient_votes.values().iter() {
+        let mut backers = Vec::new(env);
+        for (witness, vote) in emergency.recipient_votes.iter() {
+            if vote == candidate { backers.push_back(witness); }
+        }
+        if vote_weight(env, &emergency.target_user, &backers) >= quorum { return Some(candidate); }
+    }
+    None
+}
+
+fn medical_year(env: &Env, user: &Address) -> MedicalYear {
+    let now = env.ledger().timestamp();
+    match storage::read::<MedicalYear>(env, &DataKey::MedicalYear(user.clone())) {
+        Some(year) if now < year.started_at + YEAR_SECONDS => year,
+        _ => MedicalYear { started_at: now, spent: Map::new(env) },
+    }
+}
+
+fn circle_config(env: &Env, user: &Address) -> CircleConfig {
+    storage::read(env, &DataKey::CircleConfig(user.clone())).unwrap_or(CircleConfig {
+        max_witnesses: MAX_WITNESSES,
+        medical_quorum: DEFAULT_QUORUM,
+        panic_quorum: DEFAULT_QUORUM,
+    })
+}
+
+/// Accepted witnesses whose 7-day activation delay has elapsed.
+fn active_witnesses(env: &Env, user: &Address) -> Vec<Address> {
+    let now = env.ledger().timestamp();
+    let mut active = Vec::new(env);
+    if let Ok(circle) = load_circle(env, user) {
+        for member in circle.iter() {
+            if member.accepted && member.active_from <= now { active.push_back(member.address); }
+        }
+    }
+    active
+}
+
+/// Panic votes keyed by witness with the time they were cast. Votes older than 72 hours
+/// no longer count and are dropped here, so stale votes can't combine months apart.
+fn live_panic_votes(env: &Env, target_user: &Address) -> Map<Address, u64> {
+    let votes: Map<Address, u64> = storage::read(env, &DataKey::PanicVotes(target_user.clone())).unwrap_or(Map::new(env));
+    let now = env.ledger().timestamp();
+    let mut live = Map::new(env);
+    for (voter, cast_at) in votes.iter() {
+        if now < cast_at + PANIC_VOTE_WINDOW_SECONDS { live.set(voter, cast_at); }
+    }
+    live
+}
+
+fn require_witness(env: &Env, witness: &Address, target_user: &Address) -> Result<(), Error> {
+    let circle = load_circle(env, target_user)?;
+    let member = circle.iter().find(|member| member.address == *witness).ok_or(Error::NotWitness)?;
+    if !member.accepted { return Err(Error::WitnessNotAccepted); }
+    if member.active_from > env.ledger().timestamp() { return Err(Error::WitnessPending); }
+    Ok(())
+}
+
+/// A heartbeat proves the owner is alive, so it resets the timer of every vault they own.
+fn record_heartbeat(env: &Env, user: &Address) -> Result<(), Error> {
+    let ids = vault_ids(env, user);
+    if ids.is_empty() { return Err(Error::VaultNotFound); }
+    let now = env.ledger().timestamp();
+    for id in ids.iter() {
+        let mut vault = load_vault(env, user, id)?;
+        // A frozen vault must be resolved explicitly through cancel_panic
+        if vault.is_frozen { return Err(Error::VaultFrozen); }
+        vault.last_heartbeat = now;
+        write_vault(env, user, id, &vault);
+        claims::cancel_pending_claim(env, user, id);
+    }
+    env.events().publish((events::HEARTBEAT, user.clone()), now);
+    Ok(())
+}
+
+/// Any other authenticated action by the owner refreshes the vaults that opted in. Unlike
+/// `record_heartbeat` it never fails and leaves frozen, claimed and challenged vaults alone:
+/// a pending claim still needs an explicit heartbeat or veto.
+fn touch_heartbeat(env: &Env, user: &Address) {
+    let now = env.ledger().timestamp();
+    let mut touched = false;
+    for id in vault_ids(env, user).iter() {
+        let Ok(mut vault) = load_vault(env, user, id) else { continue; };
+        if !vault.passive_heartbeat || vault.is_frozen || vault.is_claimed || vault.last_heartbeat >= now { continue; }
+        if storage::has(env, &DataKey::PendingClaim(user.clone(), id)) { continue; }
+        vault.last_heartbeat = now;
+        write_vault(env, user, id, &vault);
+        touched = true;
+    }
+    if touched { env.events().publish((events::PASSIVE_HEARTBEAT, user.clone()), now); }
 }
 
 // ============================================================
@@ -65,124 +1005,940 @@ impl TrustContract {
 
     // --- FEATURE 1: LEGACY VAULT ---
 
-    pub fn create_vault(env: Env, user: Address, heir: Address) {
+    /// Owners can keep several vaults (say, family and business), each with its own heirs,
+    /// timers and balances. Returns the new vault's id; the first one is 0.
+    pub fn create_vault(env: Env, user: Address, heir: Address) -> Result<u32, Error> {
+        require_not_paused(&env)?;
         user.require_auth();
-        let vault = LegacyVault {
-            heir: Some(heir),
-            last_heartbeat: env.ledger().timestamp(),
-            is_locked: true,
-            is_frozen: false,
-        };
-        env.storage().persistent().set(&DataKey::Vault(user), &vault);
+        touch_heartbeat(&env, &user);
+        open_vault(&env, &user, &heir)
     }
 
-    pub fn ping_heartbeat(env: Env, user: Address) {
+    pub fn list_vaults(env: Env, user: Address) -> Vec<u32> {
+        vault_ids(&env, &user)
+    }
+
+    pub fn deposit_vault(env: Env, user: Address, vault_id: u32, token: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        let balance = vault.balances.get(token.clone()).unwrap_or(0);
+        if balance == 0 && vault.balances.len() >= MAX_VAULT_ASSETS { return Err(Error::TooManyAssets); }
+
+        token::Client::new(&env, &token).transfer(&user, &env.current_contract_address(), &amount);
+        vault.balances.set(token.clone(), balance + amount);
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::VAULT_DEPOSIT, user, vault_id), (token, amount));
+        Ok(())
+    }
+
+    /// The deposit half of the vault API, paired with `withdraw`. Same as `deposit_vault`,
+    /// which the claim flow introduced first; both stay so neither set of callers breaks.
+    /// Balances live on the vault itself (`LegacyVault.balances`), one entry per asset.
+    pub fn deposit(env: Env, user: Address, vault_id: u32, token: Address, amount: i128) -> Result<(), Error> {
+        Self::deposit_vault(env, user, vault_id, token, amount)
+    }
+
+    /// Amounts that would take the day's total above the owner's spending limit go through
+    /// `request_withdrawal` instead.
+    pub fn withdraw(env: Env, user: Address, vault_id: u32, token: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        spending::require_within_limit(&env, &user, &token, amount)?;
+        withdraw_from_vault(&env, &user, vault_id, &token, amount)?;
+        spending::record_spend(&env, &user, &token, amount);
+        Ok(())
+    }
+
+    pub fn get_vault(env: Env, user: Address, vault_id: u32) -> Result<LegacyVault, Error> {
+        load_vault(&env, &user, vault_id)
+    }
+
+    /// Vault plus the countdown the frontend renders next to it.
+    pub fn get_vault_view(env: Env, user: Address, vault_id: u32) -> Result<VaultView, Error> {
+        let vault = load_vault(&env, &user, vault_id)?;
+        let claimable_at = claimable_at(&vault);
+        let seconds_until_claimable = claimable_at.saturating_sub(env.ledger().timestamp());
+        Ok(VaultView {
+            vault,
+            claimable_at,
+            seconds_until_claimable,
+            is_claimable: seconds_until_claimable == 0,
+        })
+    }
+
+    pub fn get_vault_state(env: Env, user: Address, vault_id: u32) -> Result<VaultState, Error> {
+        Ok(vault_state(&env, &load_vault(&env, &user, vault_id)?))
+    }
+
+    /// Permissionless: publishes VAULT_STATE if the vault has drifted into a new state
+    /// since the last announcement, e.g. Active -> Warning as the deadline approaches.
+    pub fn sync_vault_state(env: Env, user: Address, vault_id: u32) -> Result<VaultState, Error> {
+        let vault = load_vault(&env, &user, vault_id)?;
+        Ok(announce_vault_state(&env, &user, vault_id, &vault))
+    }
+
+    /// Hospital or caregiver that receives medical releases, so they never detour via an heir.
+    pub fn set_medical_payee(env: Env, user: Address, vault_id: u32, payee: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.medical_payee = Some(payee.clone());
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::MEDICAL_PAYEE_SET, user, vault_id), payee);
+        Ok(())
+    }
+
+    pub fn get_vault_balance(env: Env, user: Address, vault_id: u32, token: Address) -> i128 {
+        match load_vault(&env, &user, vault_id) {
+            Ok(vault) => vault.balances.get(token).unwrap_or(0),
+            Err(_) => 0,
+        }
+    }
+
+    pub fn list_vault_assets(env: Env, user: Address, vault_id: u32) -> Result<Vec<Address>, Error> {
+        Ok(load_vault(&env, &user, vault_id)?.balances.keys())
+    }
+
+    /// Queues a new heir list. It only takes effect through `apply_heir_change` after 30 days,
+    /// so a stolen key can't swap the heirs and wait out the deadman timer unnoticed.
+    pub fn set_heirs(env: Env, user: Address, vault_id: u32, heirs: Vec<HeirShare>) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        validate_heirs(&heirs)?;
+
+        load_vault(&env, &user, vault_id)?;
+        let effective_at = env.ledger().timestamp() + HEIR_CHANGE_DELAY_SECONDS;
+        storage::write(&env, &DataKey::PendingHeirs(user.clone(), vault_id), &PendingHeirs { heirs: heirs.clone(), effective_at });
+        env.events().publish((events::HEIRS_QUEUED, user, vault_id), (heirs, effective_at));
+        Ok(effective_at)
+    }
+
+    /// Single-heir shorthand for `set_heirs`.
+    pub fn update_heir(env: Env, user: Address, vault_id: u32, new_heir: Address) -> Result<u64, Error> {
+        let heirs = vec![&env, HeirShare { address: new_heir, bps: FULL_SHARE_BPS }];
+        Self::set_heirs(env, user, vault_id, heirs)
+    }
+
+    pub fn cancel_heir_change(env: Env, user: Address, vault_id: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        load_vault(&env, &user, vault_id)?;
+        let key = DataKey::PendingHeirs(user.clone(), vault_id);
+        if !storage::has(&env, &key) { return Err(Error::NoHeirChange); }
+        storage::remove(&env, &key);
+        env.events().publish((events::HEIR_CHANGE_CANCELLED, user, vault_id), env.ledger().timestamp());
+        Ok(())
+    }
+
+    /// Permissionless once the delay has passed; refused while the vault is frozen.
+    pub fn apply_heir_change(env: Env, user: Address, vault_id: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        if vault.is_frozen { return Err(Error::VaultFrozen); }
+        let key = DataKey::PendingHeirs(user.clone(), vault_id);
+        let pending: PendingHeirs = storage::read(&env, &key).ok_or(Error::NoHeirChange)?;
+        if env.ledger().timestamp() < pending.effective_at { return Err(Error::HeirChangeLocked); }
+
+        let heirs = pending.heirs;
+        let previous = vault.heirs.clone();
+        // Heirs who stay on keep their acceptance; newcomers start out pending
+        let mut accepted = Vec::new(&env);
+        for address in vault.accepted_heirs.iter() {
+            if heirs.iter().any(|share| share.address == address) { accepted.push_back(address); }
+        }
+        vault.heirs = heirs.clone();
+        vault.accepted_heirs = accepted;
+        write_vault(&env, &user, vault_id, &vault);
+        storage::remove(&env, &key);
+
+        for old in previous.iter() {
+            if !heirs.iter().any(|share| share.address == old.address) { unindex_heir(&env, &old.address, &user); }
+        }
+        for share in heirs.iter() { index_heir(&env, &share.address, &user); }
+        env.events().publish((events::HEIRS_SET, user, vault_id), heirs);
+        Ok(())
+    }
+
+    pub fn get_pending_heirs(env: Env, user: Address, vault_id: u32) -> Option<PendingHeirs> {
+        storage::read(&env, &DataKey::PendingHeirs(user, vault_id))
+    }
+
+    pub fn set_backup_heirs(env: Env, user: Address, vault_id: u32, backups: Vec<Address>, grace_seconds: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if backups.len() > MAX_BACKUP_HEIRS { return Err(Error::InvalidHeirShares); }
+        if !(MIN_BACKUP_GRACE_SECONDS..=MAX_BACKUP_GRACE_SECONDS).contains(&grace_seconds) {
+            return Err(Error::InvalidTimeout);
+        }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.backup_heirs = backups.clone();
+        vault.backup_grace_seconds = grace_seconds;
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::BACKUPS_SET, user, vault_id), (backups, grace_seconds));
+        Ok(())
+    }
+
+    /// Every heir in the vault paired with the timestamp they first become eligible to claim.
+    pub fn get_heir_eligibility(env: Env, user: Address, vault_id: u32) -> Result<Vec<(Address, u64)>, Error> {
+        let vault = load_vault(&env, &user, vault_id)?;
+        let mut eligibility = Vec::new(&env);
+        for share in vault.heirs.iter() {
+            eligibility.push_back((share.address, claimable_at(&vault)));
+        }
+        for (i, backup) in vault.backup_heirs.iter().enumerate() {
+            eligibility.push_back((backup, backup_claimable_at(&vault, i as u32)));
+        }
+        Ok(eligibility)
+    }
+
+    /// Like the timeout in `set_user_config`, a change needs an active witness to co-sign.
+    pub fn set_vault_timeout(env: Env, user: Address, vault_id: u32, seconds: u64, cosigner: Option<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if !(MIN_DEADMAN_SECONDS..=MAX_DEADMAN_SECONDS).contains(&seconds) {
+            return Err(Error::InvalidTimeout);
+        }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        if seconds != vault.timeout_seconds { user_config::require_cosign(&env, &user, cosigner)?; }
+        vault.timeout_seconds = seconds;
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::TIMEOUT_SET, user, vault_id), seconds);
+        Ok(())
+    }
+
+    /// Turning it off leaves `ping_heartbeat` as the only thing that resets this vault's timer.
+    pub fn set_passive_heartbeat(env: Env, user: Address, vault_id: u32, enabled: bool) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.passive_heartbeat = enabled;
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::PASSIVE_HEARTBEAT_SET, user, vault_id), enabled);
+        Ok(())
+    }
+
+    /// Resets the timers of all the owner's vaults at once.
+    pub fn ping_heartbeat(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        record_heartbeat(&env, &user)
+    }
+
+    /// A trusted pinger (e.g. a family member) can keep the vault alive. It can do nothing else.
+    pub fn set_heartbeat_delegate(env: Env, user: Address, delegate: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if vault_ids(&env, &user).is_empty() { return Err(Error::VaultNotFound); }
+        storage::write(&env, &DataKey::HeartbeatDelegate(user.clone()), &delegate);
+        env.events().publish((events::DELEGATE_SET, user), Some(delegate));
+        Ok(())
+    }
+
+    pub fn revoke_heartbeat_delegate(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
         user.require_auth();
-        let mut vault: LegacyVault = env.storage().persistent().get(&DataKey::Vault(user.clone())).expect("Vault not found");
-        if vault.is_frozen {
-            vault.is_frozen = false; 
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        storage::remove(&env, &DataKey::HeartbeatDelegate(user.clone()));
+        env.events().publish((events::DELEGATE_SET, user), None::<Address>);
+        Ok(())
+    }
+
+    pub fn ping_heartbeat_as_delegate(env: Env, delegate: Address, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        delegate.require_auth();
+        let current: Option<Address> = storage::read(&env, &DataKey::HeartbeatDelegate(user.clone()));
+        if current != Some(delegate) { return Err(Error::NotDelegate); }
+        record_heartbeat(&env, &user)
+    }
+
+    /// Custodial family setups: one signer keeps several vaults alive, as the owner or as
+    /// each vault's heartbeat delegate. Any vault the caller can't ping reverts the batch.
+    pub fn ping_heartbeat_many(env: Env, caller: Address, users: Vec<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        caller.require_auth();
+        if users.len() > MAX_BATCH_SIZE { return Err(Error::BatchTooLarge); }
+        for user in users.iter() {
+            if user != caller {
+                let delegate: Option<Address> = storage::read(&env, &DataKey::HeartbeatDelegate(user.clone()));
+                if delegate != Some(caller.clone()) { return Err(Error::NotDelegate); }
+            }
+            record_heartbeat(&env, &user)?;
         }
-        vault.last_heartbeat = env.ledger().timestamp();
-        env.storage().persistent().set(&DataKey::Vault(user), &vault);
+        Ok(())
+    }
+
+    pub fn get_heartbeat_delegate(env: Env, user: Address) -> Option<Address> {
+        storage::read(&env, &DataKey::HeartbeatDelegate(user))
     }
 
-    pub fn claim_legacy(env: Env, target_user: Address) {
-        let vault: LegacyVault = env.storage().persistent().get(&DataKey::Vault(target_user.clone())).expect("Vault not found");
-        let heir = vault.heir.unwrap();
-        heir.require_auth(); 
+    /// Seconds left before the deadman switch fires; 0 once the vault is claimable.
+    pub fn heartbeat_due(env: Env, user: Address, vault_id: u32) -> Result<u64, Error> {
+        let vault = load_vault(&env, &user, vault_id)?;
+        Ok(claimable_at(&vault).saturating_sub(env.ledger().timestamp()))
+    }
 
-        let deadman_limit = 15_552_000; // 180 Days
-        let time_elapsed = env.ledger().timestamp() - vault.last_heartbeat;
+    /// Permissionless nudge for notification bots. Publishes HEARTBEAT_DUE once fewer
+    /// than 30 days remain, at most once every 72 hours per vault.
+    pub fn emit_reminder(env: Env, user: Address, vault_id: u32) -> Result<u64, Error> {
+        let vault = load_vault(&env, &user, vault_id)?;
+        let now = env.ledger().timestamp();
+        let remaining = claimable_at(&vault).saturating_sub(now);
+        if remaining >= WARNING_WINDOW_SECONDS { return Err(Error::ReminderNotDue); }
 
-        if time_elapsed < deadman_limit {
-            panic!("Owner is still alive");
+        let key = DataKey::LastReminder(user.clone(), vault_id);
+        if let Some(last) = storage::read::<u64>(&env, &key) {
+            if now < last + REMINDER_COOLDOWN_SECONDS { return Err(Error::ReminderCooldown); }
         }
+        storage::write(&env, &key, &now);
+        env.events().publish((events::HEARTBEAT_DUE, user, vault_id), remaining);
+        Ok(remaining)
+    }
+
+    /// Confirms a pending heir. Only accepted heirs can open a claim.
+    pub fn accept_heirship(env: Env, heir: Address, owner: Address, vault_id: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        heir.require_auth();
+        touch_heartbeat(&env, &heir);
+        let mut vault = load_vault(&env, &owner, vault_id)?;
+        if !vault.heirs.iter().any(|share| share.address == heir) { return Err(Error::NotHeir); }
+        if vault.accepted_heirs.contains(&heir) { return Err(Error::HeirAlreadyAccepted); }
+
+        vault.accepted_heirs.push_back(heir.clone());
+        write_vault(&env, &owner, vault_id, &vault);
+        env.events().publish((events::HEIR_ACCEPTED, owner, vault_id), heir);
+        Ok(())
+    }
+
+    /// Removes the caller from the heirs. Their share is spread pro-rata over the rest;
+    /// a sole heir can't decline, as that would leave the vault with nobody to pay out to.
+    pub fn decline_heirship(env: Env, heir: Address, owner: Address, vault_id: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        heir.require_auth();
+        touch_heartbeat(&env, &heir);
+        let mut vault = load_vault(&env, &owner, vault_id)?;
+        let index = vault.heirs.iter().position(|share| share.address == heir).ok_or(Error::NotHeir)? as u32;
+        if vault.heirs.len() == 1 { return Err(Error::NoHeir); }
+
+        vault.heirs.remove(index);
+        vault.heirs = rescale_shares(&env, &vault.heirs);
+        if let Some(i) = vault.accepted_heirs.first_index_of(&heir) { vault.accepted_heirs.remove(i); }
+        write_vault(&env, &owner, vault_id, &vault);
+        unindex_heir(&env, &heir, &owner);
+        env.events().publish((events::HEIR_DECLINED, owner, vault_id), heir);
+        Ok(())
+    }
+
+    /// Owners, not vaults: `list_vaults` and `get_vault` show which of an owner's vaults it is.
+    pub fn get_vaults_naming_me(env: Env, heir: Address) -> Vec<Address> {
+        storage::read(&env, &DataKey::NamedHeirIn(heir)).unwrap_or(Vec::new(&env))
     }
 
     // --- FEATURE 2: SECURITY CIRCLE ---
 
-    pub fn assign_witnesses(env: Env, user: Address, witnesses: Vec<Address>) {
+    /// Replaces the whole circle, each witness with their vote weight. The very first circle
+    /// is active at once; on later calls existing members keep their standing and newcomers
+    /// wait out the activation delay. The weights must add up to both quorums; a circle
+    /// lighter than the default quorum of 3 lowers them with `set_circle_config` first.
+    /// Newcomers are only notified here and count once they call `accept_witness_role`.
+    pub fn assign_witnesses(env: Env, user: Address, witnesses: Vec<(Address, u32)>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if witnesses.len() > circle_config(&env, &user).max_witnesses { return Err(Error::TooManyWitnesses); }
+
+        let now = env.ledger().timestamp();
+        let previous = load_circle(&env, &user).ok();
+        let mut circle: Vec<Witness> = Vec::new(&env);
+        for (address, weight) in witnesses.iter() {
+            if weight == 0 || weight > MAX_WITNESS_WEIGHT { return Err(Error::InvalidWitnessWeight); }
+            if circle.iter().any(|member| member.address == address) { continue; }
+            let existing = previous.as_ref().and_then(|previous| previous.iter().find(|member| member.address == address));
+            let member = match existing {
+                Some(existing) => Witness { weight, ..existing },
+                None => {
+                    let active_from = if previous.is_none() { now } else { now + WITNESS_ACTIVATION_SECONDS };
+                    add_to_index(&env, &DataKey::WitnessFor(address.clone()), &user);
+                    env.events().publish((events::WITNESS_ADDED, user.clone()), (address.clone(), active_from));
+                    Witness { address, active_from, accepted: false, weight }
+                }
+            };
+            circle.push_back(member);
+        }
+        require_quorum_reachable(&env, &user, &circle)?;
+        for old in previous.iter().flat_map(|previous| previous.iter()) {
+            if !circle.iter().any(|member| member.address == old.address) {
+                remove_from_index(&env, &DataKey::WitnessFor(old.address.clone()), &user);
+            }
+        }
+
+        storage::write(&env, &DataKey::Witnesses(user.clone()), &circle);
+        env.events().publish((events::CIRCLE_SET, user), witnesses);
+        Ok(())
+    }
+
+    pub fn add_witness(env: Env, user: Address, witness: Address, weight: u32) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if weight == 0 || weight > MAX_WITNESS_WEIGHT { return Err(Error::InvalidWitnessWeight); }
+        let mut circle = load_circle(&env, &user).unwrap_or(Vec::new(&env));
+        if circle.iter().any(|member| member.address == witness) { return Err(Error::AlreadyWitness); }
+        if circle.len() >= circle_config(&env, &user).max_witnesses { return Err(Error::TooManyWitnesses); }
+
+        let active_from = env.ledger().timestamp() + WITNESS_ACTIVATION_SECONDS;
+        circle.push_back(Witness { address: witness.clone(), active_from, accepted: false, weight });
+        storage::write(&env, &DataKey::Witnesses(user.clone()), &circle);
+        add_to_index(&env, &DataKey::WitnessFor(witness.clone()), &user);
+        env.events().publish((events::WITNESS_ADDED, user), (witness, active_from));
+        Ok(active_from)
+    }
+
+    pub fn remove_witness(env: Env, user: Address, witness: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        let mut circle = load_circle(&env, &user)?;
+        let index = circle.iter().position(|member| member.address == witness).ok_or(Error::NotWitness)?;
+        circle.remove(index as u32);
+        require_quorum_reachable(&env, &user, &circle)?;
+        storage::write(&env, &DataKey::Witnesses(user.clone()), &circle);
+        remove_from_index(&env, &DataKey::WitnessFor(witness.clone()), &user);
+        env.events().publish((events::WITNESS_REMOVED, user), witness);
+        Ok(())
+    }
+
+    /// Lets a spouse outweigh acquaintances. Lowering a weight may not leave a quorum out of reach.
+    pub fn set_witness_weight(env: Env, user: Address, witness: Address, weight: u32) -> Result<(), Error> {
+        require_not_paused(&env)?;
         user.require_auth();
-        if witnesses.len() > 5 { panic!("Max 5 witnesses"); }
-        env.storage().persistent().set(&DataKey::Witnesses(user), &witnesses);
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if weight == 0 || weight > MAX_WITNESS_WEIGHT { return Err(Error::InvalidWitnessWeight); }
+
+        let mut circle = load_circle(&env, &user)?;
+        let index = circle.iter().position(|member| member.address == witness).ok_or(Error::NotWitness)? as u32;
+        let mut member = circle.get(index).unwrap();
+        member.weight = weight;
+        circle.set(index, member);
+        require_quorum_reachable(&env, &user, &circle)?;
+
+        storage::write(&env, &DataKey::Witnesses(user.clone()), &circle);
+        env.events().publish((events::WITNESS_WEIGHT_SET, user), (witness, weight));
+        Ok(())
+    }
+
+    /// A named witness confirms they know they guard `user`. Until then they can't vote.
+    pub fn accept_witness_role(env: Env, witness: Address, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        let mut circle = load_circle(&env, &user)?;
+        let index = circle.iter().position(|member| member.address == witness).ok_or(Error::NotWitness)? as u32;
+        let mut member = circle.get(index).unwrap();
+        if member.accepted { return Err(Error::WitnessAlreadyAccepted); }
+
+        member.accepted = true;
+        circle.set(index, member);
+        storage::write(&env, &DataKey::Witnesses(user.clone()), &circle);
+        env.events().publish((events::WITNESS_ACCEPTED, user), witness);
+        Ok(())
+    }
+
+    pub fn get_circles_i_protect(env: Env, witness: Address) -> Vec<Address> {
+        storage::read(&env, &DataKey::WitnessFor(witness)).unwrap_or(Vec::new(&env))
+    }
+
+    /// Can run before the first circle, e.g. to lower the quorums for a circle of two.
+    /// Once a circle exists, changing either quorum needs an active witness to co-sign,
+    /// as in `set_user_config`.
+    pub fn set_circle_config(env: Env, user: Address, config: CircleConfig, cosigner: Option<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if config.max_witnesses == 0 || config.max_witnesses > MAX_WITNESSES { return Err(Error::TooManyWitnesses); }
+
+        if let Ok(circle) = load_circle(&env, &user) {
+            if circle.len() > config.max_witnesses { return Err(Error::TooManyWitnesses); }
+            let current = circle_config(&env, &user);
+            if config.medical_quorum != current.medical_quorum || config.panic_quorum != current.panic_quorum {
+                user_config::require_cosign(&env, &user, cosigner)?;
+            }
+        }
+        require_valid_quorums(&env, &user, &config)?;
+
+        storage::write(&env, &DataKey::CircleConfig(user.clone()), &config);
+        env.events().publish((events::CIRCLE_CONFIG_SET, user), (config.medical_quorum, config.panic_quorum));
+        Ok(())
+    }
+
+    pub fn get_circle_config(env: Env, user: Address) -> CircleConfig {
+        circle_config(&env, &user)
+    }
+
+    pub fn get_witnesses(env: Env, user: Address) -> Vec<Witness> {
+        load_circle(&env, &user).unwrap_or(Vec::new(&env))
     }
 
-    pub fn declare_emergency(env: Env, target_user: Address) {
+    pub fn declare_emergency(env: Env, declarer: Address, target_user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        declarer.require_auth();
+        touch_heartbeat(&env, &declarer);
+        if declarer != target_user { require_witness(&env, &declarer, &target_user)?; }
+
         let key = DataKey::Emergency(target_user.clone());
-        if env.storage().persistent().has(&key) { panic!("Emergency active"); }
+        // Stale or already-paid emergencies no longer block a new declaration
+        if let Ok(existing) = load_emergency(&env, &target_user) {
+            if !existing.is_consumed { return Err(Error::EmergencyActive); }
+        }
+        rate_limit::consume(&env, RateAction::Emergency, &declarer, &declarer)?;
 
+        let now = env.ledger().timestamp();
         let emergency = MedicalEmergency {
-            target_user: target_user,
+            target_user: target_user.clone(),
+            declared_by: declarer.clone(),
             votes_collected: 0,
             is_unlocked: false,
+            voters: Vec::new(&env),
+            is_consumed: false,
+            declared_at: now,
+            voting_ends_at: now + EMERGENCY_VOTE_SECONDS,
+            expires_at: now + EMERGENCY_TTL_SECONDS,
+            unlocked_bps: 0,
+            released: Map::new(&env),
+            recipient_votes: Map::new(&env),
         };
-        env.storage().persistent().set(&key, &emergency);
+        storage::write(&env, &key, &emergency);
+        witness_stats::mark_eligible(&env, &target_user);
+        contacts::alert_circle(&env, &target_user, events::EMERGENCY_DECLARED);
+        env.events().publish((events::EMERGENCY_DECLARED, target_user), (declarer, emergency.expires_at));
+        Ok(())
+    }
+
+    pub fn cancel_emergency(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let key = DataKey::Emergency(user.clone());
+        if !storage::has(&env, &key) { return Err(Error::EmergencyNotFound); }
+        storage::remove(&env, &key);
+        env.events().publish((events::EMERGENCY_CANCELLED, user), env.ledger().timestamp());
+        Ok(())
     }
 
-    pub fn witness_vote_medical(env: Env, witness: Address, target_user: Address) {
+    pub fn witness_vote_medical(env: Env, witness: Address, target_user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
         witness.require_auth();
-        let circle: Vec<Address> = env.storage().persistent().get(&DataKey::Witnesses(target_user.clone())).expect("No Circle");
-        if !circle.contains(witness.clone()) { panic!("Not a witness"); }
+        touch_heartbeat(&env, &witness);
+        require_witness(&env, &witness, &target_user)?;
 
         let key = DataKey::Emergency(target_user.clone());
-        let mut emergency: MedicalEmergency = env.storage().persistent().get(&key).expect("No emergency");
-        emergency.votes_collected += 1;
-        if emergency.votes_collected >= 3 {
-            emergency.is_unlocked = true; 
+        let mut emergency = load_emergency(&env, &target_user)?;
+        if emergency.voters.contains(&witness) { return Err(Error::AlreadyVoted); }
+        emergency.voters.push_back(witness.clone());
+        emergency.votes_collected = vote_weight(&env, &target_user, &emergency.voters);
+        // Each further vote past the quorum can lift the emergency into a higher tier
+        let mut tier_raised = false;
+        if emergency.votes_collected >= circle_config(&env, &target_user).medical_quorum {
+            emergency.is_unlocked = true;
+            let bps = medical_tier_bps(emergency.votes_collected, user_config::medical_release_bps(&env, &target_user));
+            tier_raised = bps > emergency.unlocked_bps;
+            emergency.unlocked_bps = bps;
+        }
+        storage::write(&env, &key, &emergency);
+        witness_stats::record_vote(&env, &witness, emergency.declared_at);
+
+        env.events().publish((events::MEDICAL_VOTE, target_user.clone()), (witness, emergency.votes_collected));
+        if tier_raised {
+            env.events().publish((events::MEDICAL_UNLOCKED, target_user), (emergency.votes_collected, emergency.unlocked_bps));
+        }
+        Ok(())
+    }
+
+    /// Releases up to the unlocked tier of one vault asset, in as many parts as needed.
+    /// Only a witness who voted for the emergency may trigger it, funds only ever go to the
+    /// medical payee, and medical releases never exceed half of any asset within a rolling year.
+    /// The emergency is the owner's, so its tier covers every vault; `vault_id` picks the
+    /// vault the release is drawn from.
+    pub fn withdraw_medical(env: Env, witness: Address, target_user: Address, vault_id: u32, token: Address, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        let key = DataKey::Emergency(target_user.clone());
+        let mut emergency = load_emergency(&env, &target_user)?;
+        if !emergency.voters.contains(&witness) { return Err(Error::NotWitness); }
+        if !emergency.is_unlocked { return Err(Error::EmergencyLocked); }
+        if emergency.is_consumed { return Err(Error::EmergencyConsumed); }
+
+        let mut vault = load_vault(&env, &target_user, vault_id)?;
+        let recipient = medical_recipient(&env, &vault, &emergency).ok_or(Error::NoMedicalPayee)?;
+        strategies::unwind(&env, &target_user, vault_id, &mut vault, &token);
+        let balance = vault.balances.get(token.clone()).unwrap_or(0);
+        if amount > balance { return Err(Error::InsufficientBalance); }
+        let held = medical_holdings(&env, &target_user, vault_id, &vault).get(token.clone()).unwrap_or(0);
+        if amount > medical_headroom(&emergency, &token, held) { return Err(Error::MedicalTierExceeded); }
+
+        let mut year = medical_year(&env, &target_user);
+        let spent = year.spent.get(token.clone()).unwrap_or(0);
+        let yearly_cap = (held + spent) * MEDICAL_YEARLY_CAP_BPS as i128 / FULL_SHARE_BPS as i128;
+        if spent + amount > yearly_cap { return Err(Error::MedicalYearCapReached); }
+
+        if balance == amount { vault.balances.remove(token.clone()); } else { vault.balances.set(token.clone(), balance - amount); }
+        year.spent.set(token.clone(), spent + amount);
+        let released = emergency.released.get(token.clone()).unwrap_or(0);
+        emergency.released.set(token.clone(), released + amount);
+        // Spent once the top tier is reached and nothing is left to draw on any asset in any vault
+        let top_bps = medical_tier_bps(u32::MAX, user_config::medical_release_bps(&env, &target_user));
+        emergency.is_consumed = emergency.unlocked_bps >= top_bps
+            && medical_holdings(&env, &target_user, vault_id, &vault).iter().all(|(asset, left)| medical_headroom(&emergency, &asset, left) <= 0);
+
+        storage::write(&env, &key, &emergency);
+        storage::write(&env, &DataKey::MedicalYear(target_user.clone()), &year);
+        write_vault(&env, &target_user, vault_id, &vault);
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+        env.events().publish((events::MEDICAL_RELEASED, target_user, vault_id), (recipient, token, amount));
+        Ok(amount)
+    }
+
+    /// Fallback for vaults without a medical payee: each voting witness names a recipient
+    /// (e.g. the hospital), and funds can flow once a quorum of them name the same one.
+    pub fn approve_medical_recipient(env: Env, witness: Address, target_user: Address, recipient: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        let mut emergency = load_emergency(&env, &target_user)?;
+        if !emergency.voters.contains(&witness) { return Err(Error::NotWitness); }
+
+        emergency.recipient_votes.set(witness.clone(), recipient.clone());
+        storage::write(&env, &DataKey::Emergency(target_user.clone()), &emergency);
+        env.events().publish((events::MEDICAL_RECIPIENT_VOTE, target_user), (witness, recipient));
+        Ok(())
+    }
+
+    pub fn get_emergency_status(env: Env, target_user: Address) -> Result<EmergencyStatus, Error> {
+        let emergency: MedicalEmergency = storage::read(&env, &DataKey::Emergency(target_user.clone())).ok_or(Error::EmergencyNotFound)?;
+        let circle = active_witnesses(&env, &target_user);
+
+        let mut pending_witnesses = Vec::new(&env);
+        for witness in circle.iter() {
+            if !emergency.voters.contains(&witness) {
+                pending_witnesses.push_back(witness);
+            }
         }
-        env.storage().persistent().set(&key, &emergency);
+
+        Ok(EmergencyStatus {
+            votes_collected: emergency.votes_collected,
+            is_unlocked: emergency.is_unlocked,
+            voters: emergency.voters,
+            pending_witnesses,
+            voting_ends_at: emergency.voting_ends_at,
+            expires_at: emergency.expires_at,
+            unlocked_bps: emergency.unlocked_bps,
+            released: emergency.released,
+        })
     }
 
-    pub fn panic_button(env: Env, witness: Address, target_user: Address) {
+    /// A quorum freezes every vault the owner has: a stolen key threatens all of them alike.
+    pub fn panic_button(env: Env, witness: Address, target_user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
         witness.require_auth();
-        let circle: Vec<Address> = env.storage().persistent().get(&DataKey::Witnesses(target_user.clone())).expect("No Circle");
-        if !circle.contains(witness.clone()) { panic!("Not a witness"); }
+        touch_heartbeat(&env, &witness);
+        require_witness(&env, &witness, &target_user)?;
 
         let key = DataKey::PanicVotes(target_user.clone());
-        let mut votes: u32 = env.storage().persistent().get(&key).unwrap_or(0);
-        votes += 1;
-        env.storage().persistent().set(&key, &votes);
+        let mut votes = live_panic_votes(&env, &target_user);
+        if votes.contains_key(witness.clone()) { return Err(Error::AlreadyVoted); }
+        // The first live vote opens a new panic attempt against the circle's yearly allowance
+        let now = env.ledger().timestamp();
+        if votes.is_empty() {
+            rate_limit::consume(&env, RateAction::Panic, &target_user, &target_user)?;
+            witness_stats::mark_eligible(&env, &target_user);
+            contacts::alert_circle(&env, &target_user, events::PANIC_VOTE);
+        }
+        let opened_at = votes.values().iter().fold(now, |earliest, cast_at| earliest.min(cast_at));
+        votes.set(witness.clone(), now);
+        storage::write(&env, &key, &votes);
+        witness_stats::record_vote(&env, &witness, opened_at);
+        let weight = vote_weight(&env, &target_user, &votes.keys());
+        env.events().publish((events::PANIC_VOTE, target_user.clone()), (witness, weight));
+
+        if weight >= circle_config(&env, &target_user).panic_quorum {
+            let ids = vault_ids(&env, &target_user);
+            if ids.is_empty() { return Err(Error::VaultNotFound); }
+            let grace = user_config::panic_grace_seconds(&env, &target_user);
+            for id in ids.iter() {
+                let mut vault = load_vault(&env, &target_user, id)?;
+                if vault.is_frozen { continue; }
+                vault.is_frozen = true;
+                vault.pre_panic_heartbeat = Some(vault.last_heartbeat);
+                vault.frozen_at = Some(now);
+                // A freeze also kills any heir change still waiting out its delay
+                storage::remove(&env, &DataKey::PendingHeirs(target_user.clone(), id));
+                // Rewind so the heirs can claim once the owner-response window plus the panic grace pass
+                vault.last_heartbeat = panic_rewind(now, vault.panic_response_seconds, vault.timeout_seconds, grace);
+                write_vault(&env, &target_user, id, &vault);
+                env.events().publish((events::PANIC_FREEZE, target_user.clone(), id), panic_status::status_of(&env, id, &vault));
+            }
+        }
+        Ok(())
+    }
 
-        if votes >= 3 {
-            let mut vault: LegacyVault = env.storage().persistent().get(&DataKey::Vault(target_user.clone())).expect("Vault not found");
-            vault.is_frozen = true;
-            let time_warp = 15_552_000 - 604_800; 
-            vault.last_heartbeat = env.ledger().timestamp() - time_warp;
-            env.storage().persistent().set(&DataKey::Vault(target_user), &vault);
+    /// Owner veto: lifts the freeze on every vault and undoes the timer rewinds.
+    pub fn cancel_panic(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut lifted = false;
+        for id in vault_ids(&env, &user).iter() {
+            let mut vault = load_vault(&env, &user, id)?;
+            if !vault.is_frozen { continue; }
+            vault.is_frozen = false;
+            vault.last_heartbeat = vault.pre_panic_heartbeat.unwrap_or(vault.last_heartbeat);
+            vault.pre_panic_heartbeat = None;
+            vault.frozen_at = None;
+            write_vault(&env, &user, id, &vault);
+            env.events().publish((events::PANIC_CANCELLED, user.clone(), id), vault.last_heartbeat);
+            lifted = true;
         }
+        if !lifted { return Err(Error::NotFrozen); }
+        storage::remove(&env, &DataKey::PanicVotes(user.clone()));
+        Ok(())
+    }
+
+    pub fn set_panic_response_window(env: Env, user: Address, vault_id: u32, seconds: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if seconds > MAX_PANIC_RESPONSE_SECONDS { return Err(Error::InvalidTimeout); }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        vault.panic_response_seconds = seconds;
+        write_vault(&env, &user, vault_id, &vault);
+        env.events().publish((events::PANIC_WINDOW_SET, user, vault_id), seconds);
+        Ok(())
+    }
+
+    /// Witnesses whose panic vote is still inside the 72-hour window.
+    pub fn get_panic_voters(env: Env, target_user: Address) -> Vec<Address> {
+        live_panic_votes(&env, &target_user).keys()
+    }
+
+    pub fn get_panic_votes(env: Env, target_user: Address) -> Map<Address, u64> {
+        live_panic_votes(&env, &target_user)
+    }
+
+    /// Keeps every entry belonging to `user` alive. Anyone may call it, e.g. an heir or a keeper bot.
+    pub fn bump_storage(env: Env, user: Address) {
+        for id in vault_ids(&env, &user).iter() {
+            for key in [
+                DataKey::Vault(user.clone(), id),
+                DataKey::VaultState(user.clone(), id),
+                DataKey::PendingClaim(user.clone(), id),
+                DataKey::ClaimVetoed(user.clone(), id),
+                DataKey::LastReminder(user.clone(), id),
+                DataKey::StrategyPositions(user.clone(), id),
+                DataKey::VaultVesting(user.clone(), id),
+                DataKey::PendingHeirs(user.clone(), id),
+                DataKey::TokenRouting(user.clone(), id),
+                DataKey::ClaimRelayer(user.clone(), id),
+            ] {
+                storage::extend(&env, &key);
+            }
+        }
+        for badge in load_merchant(&env, &user).badges.iter() {
+            storage::extend(&env, &DataKey::BadgeIssued(user.clone(), badge));
+        }
+        spending::extend_windows(&env, &user);
+        for key in [
+            DataKey::VaultCount(user.clone()),
+            DataKey::Merchant(user.clone()),
+            DataKey::BzrBalance(user.clone()),
+            DataKey::TrustScore(user.clone()),
+            DataKey::Witnesses(user.clone()),
+            DataKey::Emergency(user.clone()),
+            DataKey::PanicVotes(user.clone()),
+            DataKey::CircleConfig(user.clone()),
+            DataKey::Inbox(user.clone()),
+            DataKey::HeartbeatDelegate(user.clone()),
+            DataKey::NamedHeirIn(user.clone()),
+            DataKey::Vesting(user.clone()),
+            DataKey::Recovery(user.clone()),
+            DataKey::RemitHistory(user.clone()),
+            DataKey::MedicalYear(user.clone()),
+            DataKey::WitnessFor(user.clone()),
+            DataKey::TrustHistory(user.clone()),
+            DataKey::AliveAttestations(user.clone()),
+            DataKey::UserConfig(user.clone()),
+            DataKey::GrantHeirs(user.clone()),
+            DataKey::ActiveLoan(user.clone()),
+            DataKey::PaluwaganMemberships(user.clone()),
+            DataKey::GoalsOf(user.clone()),
+            DataKey::SubscriptionsOf(user.clone()),
+            DataKey::PoolsOf(user.clone()),
+            DataKey::GuardedHeirs(user.clone()),
+            DataKey::GuardedFor(user.clone()),
+            DataKey::WitnessStats(user.clone()),
+            DataKey::SpendingLimits(user.clone()),
+            DataKey::LegacyNote(user.clone()),
+            DataKey::Profile(user.clone()),
+            DataKey::BuyerProfile(user.clone()),
+            DataKey::CategoryTrust(user.clone()),
+            DataKey::VouchStakes(user.clone()),
+            DataKey::Referral(user.clone()),
+            DataKey::Away(user.clone()),
+            DataKey::WitnessContact(user.clone()),
+        ] {
+            storage::extend(&env, &key);
+        }
+        storage::extend_instance(&env);
     }
 
     // --- FEATURE 3: MERCHANT TRUST ---
 
-    pub fn stake(env: Env, user: Address) {
+    /// Pulls the configured BZR bond from the merchant and locks it for 30 days.
+    pub fn stake(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
         user.require_auth();
-        let mut merchant = env.storage().persistent().get(&DataKey::Merchant(user.clone())).unwrap_or(Merchant {
-            trust_score: 0, bond_staked: false, bzr_balance: 0, badges: Vec::new(&env), 
-            is_disputed: false, nickname: Symbol::new(&env, "User"), messages: Vec::new(&env)
-        });
-        if merchant.bond_staked { panic!("Already bonded"); }
+        touch_heartbeat(&env, &user);
+        let mut merchant = load_merchant_for_update(&env, &user);
+        if merchant.bond_staked { return Err(Error::AlreadyBonded); }
+
+        let bond_amount = admin::protocol_config(&env)?.bond_amount;
+        if bond_amount > 0 {
+            token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&user, &env.current_contract_address(), &bond_amount);
+        }
+
         merchant.bond_staked = true;
-        merchant.trust_score += 10;
-        env.storage().persistent().set(&DataKey::Merchant(user), &merchant);
+        merchant.staked_amount = bond_amount;
+        merchant.stake_timestamp = env.ledger().timestamp();
+        merchant.last_activity = merchant.stake_timestamp;
+        let before = merchant.trust_score;
+        merchant.trust_score = (merchant.trust_score + BOND_TRUST_POINTS).min(MAX_TRUST_SCORE);
+        badges::check_and_award_badges(&env, &user, &mut merchant);
+        write_merchant(&env, &user, &merchant);
+        history::record(&env, &user, before, merchant.trust_score, TrustReason::Bond, &user);
+        referrals::reach(&env, &user, ReferralMilestone::FirstStake);
+        env.events().publish((events::STAKED, user.clone()), bond_amount);
+        env.events().publish((events::TRUST_CHANGED, user), merchant.trust_score);
+        Ok(())
+    }
+
+    pub fn unstake(env: Env, user: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        let mut merchant = load_merchant_for_update(&env, &user);
+        if !merchant.bond_staked { return Err(Error::NotBonded); }
+        if env.ledger().timestamp() < merchant.stake_timestamp + BOND_LOCK_SECONDS { return Err(Error::BondLocked); }
+        if storage::has(&env, &DataKey::ActiveLoan(user.clone())) { return Err(Error::LoanOutstanding); }
+        if paluwagan::has_open_membership(&env, &user) { return Err(Error::PaluwaganOutstanding); }
+        // The bond backs any ruling still to come
+        if storage::read::<u32>(&env, &DataKey::OpenDisputes(user.clone())).unwrap_or(0) > 0 { return Err(Error::DisputeOutstanding); }
+
+        let amount = merchant.staked_amount;
+        merchant.bond_staked = false;
+        merchant.staked_amount = 0;
+        merchant.last_activity = env.ledger().timestamp();
+        let before = merchant.trust_score;
+        merchant.trust_score = merchant.trust_score.saturating_sub(BOND_TRUST_POINTS);
+        write_merchant(&env, &user, &merchant);
+        history::record(&env, &user, before, merchant.trust_score, TrustReason::Unbond, &user);
+        discount_if_collapsed(&env, &user, &merchant);
+
+        if amount > 0 {
+            token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &user, &amount);
+        }
+        env.events().publish((events::UNSTAKED, user.clone()), amount);
+        env.events().publish((events::TRUST_CHANGED, user), merchant.trust_score);
+        Ok(amount)
+    }
+
+    pub fn is_bonded(env: Env, user: Address) -> bool {
+        load_merchant(&env, &user).bond_staked
     }
 
-    pub fn vouch(env: Env, voucher: Address, target: Address) {
+    pub fn vouch(env: Env, voucher: Address, target: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
         voucher.require_auth();
-        // SAFE INITIALIZATION: No more "Target not found" traps
-        let mut target_data = env.storage().persistent().get(&DataKey::Merchant(target.clone())).unwrap_or(Merchant {
-            trust_score: 0, bond_staked: false, bzr_balance: 0, badges: Vec::new(&env), 
-            is_disputed: false, nickname: Symbol::new(&env, "NewUser"), messages: Vec::new(&env)
-        });
+        touch_heartbeat(&env, &voucher);
+        record_vouch(&env, &voucher, &target)
+    }
 
-        if target_data.trust_score < 100 { target_data.trust_score += 1; }
-        env.storage().persistent().set(&DataKey::Merchant(target), &target_data);
+    /// One signature for up to 10 endorsements. Any failing target reverts the whole batch.
+    pub fn vouch_many(env: Env, voucher: Address, targets: Vec<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        voucher.require_auth();
+        touch_heartbeat(&env, &voucher);
+        if targets.len() > MAX_BATCH_SIZE { return Err(Error::BatchTooLarge); }
+        for target in targets.iter() {
+            record_vouch(&env, &voucher, &target)?;
+        }
+        Ok(())
+    }
+
+    /// Withdraws an endorsement and the points it contributed. The pair cooldown still applies
+    /// to vouching again.
+    pub fn unvouch(env: Env, voucher: Address, target: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        voucher.require_auth();
+        touch_heartbeat(&env, &voucher);
+        let given_key = DataKey::VouchesGiven(voucher.clone());
+        let mut given: Vec<Address> = storage::read(&env, &given_key).unwrap_or(Vec::new(&env));
+        let index = given.first_index_of(&target).ok_or(Error::NotVouched)?;
+        given.remove(index);
+        storage::write(&env, &given_key, &given);
+
+        let points = remove_vouch_edge(&env, &voucher, &target, TrustReason::Unvouch);
+        env.events().publish((events::VOUCH_REVOKED, target), (voucher, points));
+        Ok(())
+    }
+
+    /// Every active voucher of `target` with the points their vouch currently contributes.
+    pub fn get_vouchers(env: Env, target: Address) -> Map<Address, u32> {
+        storage::read(&env, &DataKey::Vouchers(target)).unwrap_or(Map::new(&env))
+    }
+
+    pub fn get_vouches_given(env: Env, voucher: Address) -> Vec<Address> {
+        storage::read(&env, &DataKey::VouchesGiven(voucher)).unwrap_or(Vec::new(&env))
     }
 
     pub fn get_trust(env: Env, user: Address) -> u32 {
-        let merchant = env.storage().persistent().get(&DataKey::Merchant(user)).unwrap_or(Merchant {
-            trust_score: 0, bond_staked: false, bzr_balance: 0, badges: Vec::new(&env), 
-            is_disputed: false, nickname: Symbol::new(&env, "User"), messages: Vec::new(&env)
-        });
-        merchant.trust_score
+        current_trust(&env, &user)
+    }
+
+    pub fn get_tier(env: Env, user: Address) -> TrustTier {
+        tier_of(current_trust(&env, &user))
     }
 }
\ No newline at end of file
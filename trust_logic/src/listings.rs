@@ -0,0 +1,140 @@
+// 🏛️ PROJECT BAZAAR | LISTINGS
+// Merchants publish priced listings; escrow orders are opened against a listing,
+// so every trade and trust point traces back to a real product. A listing's category
+// decides which category sub-score its orders feed.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+const MAX_LISTINGS_PER_MERCHANT: u32 = 100;
+const MAX_TITLE_LEN: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 20;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Listing {
+    pub id: u64,
+    pub merchant: Address,
+    pub title: String,
+    pub price: i128,
+    pub token: Address,
+    pub metadata_hash: BytesN<32>,
+    pub is_active: bool,
+    pub created_at: u64,
+    pub category: Option<Symbol>,
+}
+
+pub(crate) fn load_listing(env: &Env, listing_id: u64) -> Result<Listing, Error> {
+    storage::read(env, &DataKey::Listing(listing_id)).ok_or(Error::ListingNotFound)
+}
+
+fn load_owned_listing(env: &Env, merchant: &Address, listing_id: u64) -> Result<Listing, Error> {
+    let listing = load_listing(env, listing_id)?;
+    if listing.merchant != *merchant { return Err(Error::NotListingOwner); }
+    Ok(listing)
+}
+
+/// Listings priced above the protocol's value cap are reserved for Silver tier and up.
+fn validate_listing(env: &Env, merchant: &Address, title: &String, price: i128) -> Result<(), Error> {
+    if title.is_empty() || title.len() > MAX_TITLE_LEN { return Err(Error::InvalidListing); }
+    if price <= 0 { return Err(Error::InvalidAmount); }
+    if price > admin::protocol_config(env)?.listing_value_cap && tier_of(current_trust(env, merchant)) < TrustTier::Silver {
+        return Err(Error::TierTooLow);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 8: LISTINGS ---
+
+    pub fn create_listing(env: Env, merchant: Address, title: String, price: i128, token: Address, metadata_hash: BytesN<32>) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        validate_listing(&env, &merchant, &title, price)?;
+
+        let index_key = DataKey::MerchantListings(merchant.clone());
+        let mut ids: Vec<u64> = storage::read(&env, &index_key).unwrap_or(Vec::new(&env));
+        if ids.len() >= MAX_LISTINGS_PER_MERCHANT { return Err(Error::TooManyListings); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::ListingCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ListingCount, &id);
+
+        let listing = Listing {
+            id,
+            merchant: merchant.clone(),
+            title,
+            price,
+            token,
+            metadata_hash,
+            is_active: true,
+            created_at: env.ledger().timestamp(),
+            category: None,
+        };
+        storage::write(&env, &DataKey::Listing(id), &listing);
+        ids.push_back(id);
+        storage::write(&env, &index_key, &ids);
+        env.events().publish((events::LISTING_SET, merchant), (id, price));
+        Ok(id)
+    }
+
+    /// Open orders keep the price they were created at.
+    pub fn update_listing(env: Env, merchant: Address, listing_id: u64, title: String, price: i128, metadata_hash: BytesN<32>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        validate_listing(&env, &merchant, &title, price)?;
+        let mut listing = load_owned_listing(&env, &merchant, listing_id)?;
+
+        listing.title = title;
+        listing.price = price;
+        listing.metadata_hash = metadata_hash;
+        storage::write(&env, &DataKey::Listing(listing_id), &listing);
+        env.events().publish((events::LISTING_SET, merchant), (listing_id, price));
+        Ok(())
+    }
+
+    /// Open orders keep the category they were created under.
+    pub fn set_listing_category(env: Env, merchant: Address, listing_id: u64, category: Option<Symbol>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        let mut listing = load_owned_listing(&env, &merchant, listing_id)?;
+
+        listing.category = category.clone();
+        storage::write(&env, &DataKey::Listing(listing_id), &listing);
+        env.events().publish((events::CATEGORY_SET, merchant), (listing_id, category));
+        Ok(())
+    }
+
+    pub fn deactivate_listing(env: Env, merchant: Address, listing_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        merchant.require_auth();
+        touch_heartbeat(&env, &merchant);
+        let mut listing = load_owned_listing(&env, &merchant, listing_id)?;
+
+        listing.is_active = false;
+        storage::write(&env, &DataKey::Listing(listing_id), &listing);
+        env.events().publish((events::LISTING_CLOSED, merchant), listing_id);
+        Ok(())
+    }
+
+    pub fn get_listing(env: Env, listing_id: u64) -> Result<Listing, Error> {
+        load_listing(&env, listing_id)
+    }
+
+    /// Oldest first, active and inactive alike. `limit` is capped at 20 per page.
+    pub fn get_listings(env: Env, merchant: Address, offset: u32, limit: u32) -> Vec<Listing> {
+        let ids: Vec<u64> = storage::read(&env, &DataKey::MerchantListings(merchant)).unwrap_or(Vec::new(&env));
+        let start = offset.min(ids.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(ids.len());
+
+        let mut page = Vec::new(&env);
+        for id in ids.slice(start..end).iter() {
+            if let Ok(listing) = load_listing(&env, id) { page.push_back(listing); }
+        }
+        page
+    }
+}
@@ -0,0 +1,82 @@
+// 🏛️ PROJECT BAZAAR | PROOF OF LIFE
+// Owners who are alive but can't sign (hospitalized, lost device) can have their
+// witnesses vouch for them: attestations within 48 hours that reach the panic quorum,
+// weighted like every other circle vote, count as a heartbeat.
+// A cooldown and a cap on back-to-back attestations keep a colluding circle from
+// holding off rightful heirs forever; after two, the owner has to sign again.
+
+use super::*;
+
+const ALIVE_VOTE_WINDOW_SECONDS: u64 = 172_800; // 48 Hours
+const ALIVE_COOLDOWN_SECONDS: u64 = 7_776_000;  // 90 Days
+const MAX_ATTESTED_HEARTBEATS: u32 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AliveAttestations {
+    pub votes: Map<Address, u64>,
+    pub last_attested_at: u64,
+    /// Heartbeat written by the last attestation; if the vault still shows it, the
+    /// owner has not signed since and the next attestation extends the streak.
+    pub attested_heartbeat: u64,
+    pub streak: u32,
+}
+
+fn load_attestations(env: &Env, user: &Address) -> AliveAttestations {
+    storage::read(env, &DataKey::AliveAttestations(user.clone())).unwrap_or(AliveAttestations {
+        votes: Map::new(env),
+        last_attested_at: 0,
+        attested_heartbeat: 0,
+        streak: 0,
+    })
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 23: PROOF OF LIFE ---
+
+    /// Returns the weight of the live attestations, this one included. Votes from witnesses
+    /// who have left the circle since casting them no longer count.
+    pub fn attest_alive(env: Env, witness: Address, target_user: Address) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        require_witness(&env, &witness, &target_user)?;
+
+        let now = env.ledger().timestamp();
+        // Heartbeats reset every vault together, so the first one speaks for all
+        let vault = load_vault(&env, &target_user, PRIMARY_VAULT)?;
+        let mut state = load_attestations(&env, &target_user);
+        if state.last_attested_at > 0 && now < state.last_attested_at + ALIVE_COOLDOWN_SECONDS { return Err(Error::AttestationCooldown); }
+        let owner_silent = state.streak > 0 && vault.last_heartbeat == state.attested_heartbeat;
+        if owner_silent && state.streak >= MAX_ATTESTED_HEARTBEATS { return Err(Error::AttestationLimit); }
+
+        let active = active_witnesses(&env, &target_user);
+        let mut votes = Map::new(&env);
+        for (voter, cast_at) in state.votes.iter() {
+            if now < cast_at + ALIVE_VOTE_WINDOW_SECONDS && active.contains(&voter) { votes.set(voter, cast_at); }
+        }
+        if votes.contains_key(witness.clone()) { return Err(Error::AlreadyVoted); }
+        votes.set(witness.clone(), now);
+        let weight = vote_weight(&env, &target_user, &votes.keys());
+        env.events().publish((events::ALIVE_VOTE, target_user.clone()), (witness, weight));
+
+        if weight < circle_config(&env, &target_user).panic_quorum {
+            state.votes = votes;
+            storage::write(&env, &DataKey::AliveAttestations(target_user.clone()), &state);
+            return Ok(weight);
+        }
+
+        record_heartbeat(&env, &target_user)?;
+        let streak = if owner_silent { state.streak + 1 } else { 1 };
+        let attested = AliveAttestations { votes: Map::new(&env), last_attested_at: now, attested_heartbeat: now, streak };
+        storage::write(&env, &DataKey::AliveAttestations(target_user.clone()), &attested);
+        env.events().publish((events::ALIVE_ATTESTED, target_user), streak);
+        Ok(weight)
+    }
+
+    pub fn get_alive_attestations(env: Env, target_user: Address) -> AliveAttestations {
+        load_attestations(&env, &target_user)
+    }
+}
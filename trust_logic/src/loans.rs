@@ -0,0 +1,170 @@
+// 🏛️ PROJECT BAZAAR | MICRO-LOANS
+// Peer-to-peer BZR loans backed by the borrower's bond: the most anyone can ask for is
+// their staked bond times a multiple set by their trust tier. Bronze merchants can't
+// borrow. A loan left unpaid past its term is slashed from the bond and tanks trust.
+
+use super::*;
+
+/// Loan ceiling as a multiple of the staked bond, indexed by tier (Bronze..Platinum).
+const LOAN_LIMIT_BPS: [u32; 4] = [0, 10_000, 20_000, 30_000];
+const MAX_INTEREST_BPS: u32 = 3_000;
+const MIN_LOAN_TERM_SECONDS: u64 = 86_400;     // 1 Day
+const MAX_LOAN_TERM_SECONDS: u64 = 31_536_000; // 365 Days
+const LOAN_DEFAULT_TRUST_PENALTY: u32 = 25;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoanState {
+    Requested,
+    Active,
+    Repaid,
+    Defaulted,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Loan {
+    pub id: u64,
+    pub borrower: Address,
+    pub lender: Option<Address>,
+    pub amount: i128,
+    pub interest_bps: u32,
+    pub term_seconds: u64,
+    pub due_at: u64,
+    pub state: LoanState,
+}
+
+pub(crate) fn load_loan(env: &Env, id: u64) -> Result<Loan, Error> {
+    storage::read(env, &DataKey::Loan(id)).ok_or(Error::LoanNotFound)
+}
+
+/// Principal plus interest.
+fn amount_owed(loan: &Loan) -> i128 {
+    loan.amount + loan.amount * loan.interest_bps as i128 / FULL_SHARE_BPS as i128
+}
+
+pub(crate) fn max_loan(merchant: &Merchant) -> i128 {
+    if !merchant.bond_staked { return 0; }
+    let limit_bps = LOAN_LIMIT_BPS[tier_of(merchant.trust_score) as usize];
+    merchant.staked_amount * limit_bps as i128 / FULL_SHARE_BPS as i128
+}
+
+fn close_loan(env: &Env, loan: &mut Loan, state: LoanState) {
+    loan.state = state;
+    storage::write(env, &DataKey::Loan(loan.id), loan);
+    storage::remove(env, &DataKey::ActiveLoan(loan.borrower.clone()));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 29: MICRO-LOANS ---
+
+    /// One open loan per borrower; the bond stays locked until it is repaid or cancelled.
+    pub fn request_loan(env: Env, borrower: Address, amount: i128, interest_bps: u32, term_seconds: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        borrower.require_auth();
+        touch_heartbeat(&env, &borrower);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if interest_bps > MAX_INTEREST_BPS { return Err(Error::InvalidLoan); }
+        if !(MIN_LOAN_TERM_SECONDS..=MAX_LOAN_TERM_SECONDS).contains(&term_seconds) { return Err(Error::InvalidLoan); }
+        if storage::has(&env, &DataKey::ActiveLoan(borrower.clone())) { return Err(Error::LoanOutstanding); }
+        if amount > max_loan(&load_merchant(&env, &borrower)) { return Err(Error::LoanLimitExceeded); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::LoanCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::LoanCount, &id);
+
+        let loan = Loan { id, borrower: borrower.clone(), lender: None, amount, interest_bps, term_seconds, due_at: 0, state: LoanState::Requested };
+        storage::write(&env, &DataKey::Loan(id), &loan);
+        storage::write(&env, &DataKey::ActiveLoan(borrower.clone()), &id);
+        env.events().publish((events::LOAN_REQUESTED, borrower), (id, amount, interest_bps, term_seconds));
+        Ok(id)
+    }
+
+    pub fn cancel_loan(env: Env, borrower: Address, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        borrower.require_auth();
+        touch_heartbeat(&env, &borrower);
+        let mut loan = load_loan(&env, id)?;
+        if loan.borrower != borrower { return Err(Error::NotBorrower); }
+        if loan.state != LoanState::Requested { return Err(Error::InvalidLoanState); }
+
+        close_loan(&env, &mut loan, LoanState::Cancelled);
+        env.events().publish((events::LOAN_CANCELLED, borrower), id);
+        Ok(())
+    }
+
+    /// Sends the principal straight to the borrower and starts the term.
+    pub fn fund_loan(env: Env, lender: Address, id: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        lender.require_auth();
+        touch_heartbeat(&env, &lender);
+        let mut loan = load_loan(&env, id)?;
+        if loan.state != LoanState::Requested { return Err(Error::InvalidLoanState); }
+        if loan.borrower == lender { return Err(Error::InvalidLoan); }
+
+        loan.lender = Some(lender.clone());
+        loan.due_at = env.ledger().timestamp() + loan.term_seconds;
+        loan.state = LoanState::Active;
+        storage::write(&env, &DataKey::Loan(id), &loan);
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&lender, &loan.borrower, &loan.amount);
+        env.events().publish((events::LOAN_FUNDED, lender), (id, loan.due_at));
+        Ok(loan.due_at)
+    }
+
+    /// Pays principal and interest to the lender in one transfer. Late repayment is still
+    /// accepted until someone marks the loan defaulted.
+    pub fn repay(env: Env, borrower: Address, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        borrower.require_auth();
+        touch_heartbeat(&env, &borrower);
+        let mut loan = load_loan(&env, id)?;
+        if loan.borrower != borrower { return Err(Error::NotBorrower); }
+        if loan.state != LoanState::Active { return Err(Error::InvalidLoanState); }
+        let lender = loan.lender.clone().ok_or(Error::InvalidLoanState)?;
+
+        let owed = amount_owed(&loan);
+        close_loan(&env, &mut loan, LoanState::Repaid);
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&borrower, &lender, &owed);
+        env.events().publish((events::LOAN_REPAID, borrower), (id, owed));
+        Ok(owed)
+    }
+
+    /// Permissionless once the term has run out: the lender is made whole from the bond as
+    /// far as it goes, and the borrower loses trust.
+    pub fn mark_default(env: Env, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        let mut loan = load_loan(&env, id)?;
+        if loan.state != LoanState::Active { return Err(Error::InvalidLoanState); }
+        if env.ledger().timestamp() <= loan.due_at { return Err(Error::LoanNotDue); }
+        let lender = loan.lender.clone().ok_or(Error::InvalidLoanState)?;
+
+        close_loan(&env, &mut loan, LoanState::Defaulted);
+        let borrower = loan.borrower.clone();
+        let mut merchant = load_merchant_for_update(&env, &borrower);
+        let slashed = merchant.staked_amount.min(amount_owed(&loan));
+        merchant.staked_amount -= slashed;
+        if merchant.staked_amount == 0 { merchant.bond_staked = false; }
+        let before = merchant.trust_score;
+        merchant.trust_score = merchant.trust_score.saturating_sub(LOAN_DEFAULT_TRUST_PENALTY);
+        write_merchant(&env, &borrower, &merchant);
+        history::record(&env, &borrower, before, merchant.trust_score, TrustReason::LoanDefault, &env.current_contract_address());
+        discount_if_collapsed(&env, &borrower, &merchant);
+
+        if slashed > 0 {
+            token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &lender, &slashed);
+        }
+        env.events().publish((events::LOAN_DEFAULTED, borrower.clone()), (id, slashed));
+        env.events().publish((events::TRUST_CHANGED, borrower), merchant.trust_score);
+        Ok(slashed)
+    }
+
+    pub fn get_loan(env: Env, id: u64) -> Result<Loan, Error> {
+        load_loan(&env, id)
+    }
+
+    pub fn get_max_loan(env: Env, user: Address) -> i128 {
+        max_loan(&load_merchant(&env, &user))
+    }
+}
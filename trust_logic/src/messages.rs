@@ -0,0 +1,61 @@
+// 🏛️ PROJECT BAZAAR | MERCHANT MESSAGING
+// Each user has a bounded inbox under its own storage key; once full, the oldest
+// message is evicted so the entry never outgrows ledger limits.
+
+use super::*;
+
+const MAX_INBOX_MESSAGES: u32 = 50;
+const MAX_MESSAGE_LEN: u32 = 280;
+const MAX_PAGE_SIZE: u32 = 20;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Message {
+    pub sender: Address,
+    pub text: String,
+    pub timestamp: u64,
+}
+
+fn load_inbox(env: &Env, user: &Address) -> Vec<Message> {
+    storage::read(env, &DataKey::Inbox(user.clone())).unwrap_or(Vec::new(env))
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 6: MESSAGING ---
+
+    pub fn send_message(env: Env, sender: Address, recipient: Address, text: String) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        sender.require_auth();
+        touch_heartbeat(&env, &sender);
+        if text.is_empty() || text.len() > MAX_MESSAGE_LEN { return Err(Error::InvalidMessage); }
+
+        let mut inbox = load_inbox(&env, &recipient);
+        if inbox.len() >= MAX_INBOX_MESSAGES { inbox.pop_front(); }
+        inbox.push_back(Message { sender: sender.clone(), text, timestamp: env.ledger().timestamp() });
+        storage::write(&env, &DataKey::Inbox(recipient.clone()), &inbox);
+        env.events().publish((events::MESSAGE_SENT, recipient), sender);
+        Ok(())
+    }
+
+    /// Oldest first. `limit` is capped at 20 per page.
+    pub fn get_messages(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Message> {
+        let inbox = load_inbox(&env, &user);
+        let start = offset.min(inbox.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(inbox.len());
+        inbox.slice(start..end)
+    }
+
+    pub fn get_message_count(env: Env, user: Address) -> u32 {
+        load_inbox(&env, &user).len()
+    }
+
+    pub fn clear_inbox(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        storage::remove(&env, &DataKey::Inbox(user));
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+// 🏛️ PROJECT BAZAAR | V2 MIGRATION
+// Carries users over from the pre-v3 contract. The v2 contract only exposes `get_trust`,
+// so a self-service migration pulls the trust score across; anything else (badges, the
+// heir of a v2 vault) comes in through an admin-signed snapshot. Each user migrates once.
+
+use super::*;
+use soroban_sdk::IntoVal;
+
+/// What the v2 layout held for one user that still means something in v3.
+/// v2 bonds were never backed by tokens, so bond status does not carry over.
+#[contracttype]
+#[derive(Clone)]
+pub struct V2Snapshot {
+    pub trust_score: u32,
+    pub badges: Vec<Symbol>,
+    pub heir: Option<Address>,
+}
+
+fn begin_migration(env: &Env, user: &Address) -> Result<(), Error> {
+    let key = DataKey::Migrated(user.clone());
+    if storage::has(env, &key) { return Err(Error::AlreadyMigrated); }
+    if storage::has(env, &DataKey::Merchant(user.clone())) { return Err(Error::MigrationTargetInUse); }
+    storage::write(env, &key, &env.ledger().timestamp());
+    Ok(())
+}
+
+/// Writes the v3 merchant. Decay and the one-year badge count from the migration, not from v2.
+fn import_merchant(env: &Env, user: &Address, trust_score: u32, imported_badges: Vec<Symbol>) -> Merchant {
+    let mut merchant = load_merchant(env, user);
+    let before = merchant.trust_score;
+    merchant.trust_score = trust_score.min(MAX_TRUST_SCORE);
+    merchant.last_activity = env.ledger().timestamp();
+    for badge in imported_badges.iter() {
+        if !merchant.badges.contains(&badge) { badges::issue(env, user, &mut merchant, badge); }
+    }
+    badges::check_and_award_badges(env, user, &mut merchant);
+    write_merchant(env, user, &merchant);
+    history::record(env, user, before, merchant.trust_score, TrustReason::Migration, user);
+    merchant
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 19: MIGRATION ---
+
+    /// Registers the deployed v2 contract that `migrate_merchant` is allowed to read from.
+    pub fn set_legacy_contract(env: Env, old_contract: Address) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        env.storage().instance().set(&DataKey::LegacyContract, &old_contract);
+        env.events().publish((events::LEGACY_CONTRACT_SET, admin), old_contract);
+        Ok(())
+    }
+
+    /// Self-service path: reads the user's trust score from the registered v2 contract.
+    pub fn migrate_merchant(env: Env, user: Address, old_contract: Address) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let legacy: Option<Address> = env.storage().instance().get(&DataKey::LegacyContract);
+        if legacy != Some(old_contract.clone()) { return Err(Error::NotLegacyContract); }
+        begin_migration(&env, &user)?;
+
+        let trust_score: u32 = env.invoke_contract(&old_contract, &Symbol::new(&env, "get_trust"), vec![&env, user.into_val(&env)]);
+        let merchant = import_merchant(&env, &user, trust_score, Vec::new(&env));
+        env.events().publish((events::MIGRATED, user), merchant.trust_score);
+        Ok(merchant.trust_score)
+    }
+
+    /// Admin path for state the v2 getters never exposed. A v2 heir becomes the sole,
+    /// still-pending heir of a fresh v3 vault whose deadman timer starts now.
+    pub fn migrate_from_snapshot(env: Env, user: Address, snapshot: V2Snapshot) -> Result<u32, Error> {
+        admin::require_admin(&env)?;
+        begin_migration(&env, &user)?;
+        if snapshot.heir.is_some() && !vault_ids(&env, &user).is_empty() { return Err(Error::MigrationTargetInUse); }
+
+        let merchant = import_merchant(&env, &user, snapshot.trust_score, snapshot.badges);
+        if let Some(heir) = snapshot.heir {
+            open_vault(&env, &user, &heir)?;
+        }
+        env.events().publish((events::MIGRATED, user), merchant.trust_score);
+        Ok(merchant.trust_score)
+    }
+
+    pub fn is_migrated(env: Env, user: Address) -> bool {
+        storage::has(&env, &DataKey::Migrated(user))
+    }
+}
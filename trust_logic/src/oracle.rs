@@ -0,0 +1,66 @@
+// 🏛️ PROJECT BAZAAR | TRUST ORACLE
+// Stable read interface for other Soroban contracts (lending, rentals) that want to price
+// risk off Bazaar trust. Consumers are allowlisted by the admin and every query is logged,
+// so integrations stay visible on-chain. Integrators call it through `TrustOracleClient`.
+
+use super::*;
+use soroban_sdk::{contractclient, symbol_short};
+
+/// The oracle surface. Method names and signatures are frozen: new reads get new methods.
+#[contractclient(name = "TrustOracleClient")]
+pub trait TrustOracle {
+    fn oracle_get_trust(env: Env, consumer: Address, user: Address) -> Result<u32, Error>;
+    fn oracle_get_tier(env: Env, consumer: Address, user: Address) -> Result<TrustTier, Error>;
+    fn oracle_is_bonded(env: Env, consumer: Address, user: Address) -> Result<bool, Error>;
+}
+
+/// The consumer must authorize the query; for a calling contract that is implicit.
+fn record_query(env: &Env, consumer: &Address, user: &Address, query: Symbol) -> Result<(), Error> {
+    consumer.require_auth();
+    if !storage::has(env, &DataKey::OracleConsumer(consumer.clone())) { return Err(Error::ConsumerNotAllowed); }
+    env.events().publish((events::ORACLE_QUERY, consumer.clone()), (user.clone(), query));
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustOracle for TrustContract {
+
+    // --- FEATURE 20: TRUST ORACLE ---
+
+    fn oracle_get_trust(env: Env, consumer: Address, user: Address) -> Result<u32, Error> {
+        record_query(&env, &consumer, &user, symbol_short!("trust"))?;
+        Ok(current_trust(&env, &user))
+    }
+
+    fn oracle_get_tier(env: Env, consumer: Address, user: Address) -> Result<TrustTier, Error> {
+        record_query(&env, &consumer, &user, symbol_short!("tier"))?;
+        Ok(tier_of(current_trust(&env, &user)))
+    }
+
+    fn oracle_is_bonded(env: Env, consumer: Address, user: Address) -> Result<bool, Error> {
+        record_query(&env, &consumer, &user, symbol_short!("bonded"))?;
+        Ok(load_merchant(&env, &user).bond_staked)
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    pub fn allowlist_consumer(env: Env, consumer: Address) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        storage::write(&env, &DataKey::OracleConsumer(consumer.clone()), &true);
+        env.events().publish((events::CONSUMER_SET, consumer), true);
+        Ok(())
+    }
+
+    pub fn remove_consumer(env: Env, consumer: Address) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        storage::remove(&env, &DataKey::OracleConsumer(consumer.clone()));
+        env.events().publish((events::CONSUMER_SET, consumer), false);
+        Ok(())
+    }
+
+    pub fn is_consumer_allowed(env: Env, consumer: Address) -> bool {
+        storage::has(&env, &DataKey::OracleConsumer(consumer))
+    }
+}
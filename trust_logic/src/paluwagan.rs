@@ -0,0 +1,222 @@
+// 🏛️ PROJECT BAZAAR | PALUWAGAN (ROTATING SAVINGS)
+// A fixed group contributes the same amount every period and the whole pot goes to
+// one member per round, in join order. Members must be bonded: missing a round costs
+// trust and part of the bond, which goes to that round's recipient, so the bond stays
+// locked from joining until the last round closes.
+
+use super::*;
+
+const MIN_MEMBERS: u32 = 2;
+const MAX_MEMBERS: u32 = 20;
+const MIN_PERIOD_SECONDS: u64 = 86_400; // 1 Day
+const MISSED_ROUND_TRUST_PENALTY: u32 = 5;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Paluwagan {
+    pub id: u64,
+    pub organizer: Address,
+    pub token: Address,
+    pub contribution: i128,
+    pub period_seconds: u64,
+    pub members: Vec<Address>,
+    pub joined: Vec<Address>,
+    pub round: u32,
+    pub round_started_at: u64,
+    pub paid: Vec<Address>,
+    pub is_active: bool,
+    pub is_complete: bool,
+}
+
+fn load_paluwagan(env: &Env, id: u64) -> Result<Paluwagan, Error> {
+    storage::read(env, &DataKey::Paluwagan(id)).ok_or(Error::PaluwaganNotFound)
+}
+
+fn memberships(env: &Env, member: &Address) -> Vec<u64> {
+    storage::read(env, &DataKey::PaluwaganMemberships(member.clone())).unwrap_or(Vec::new(env))
+}
+
+/// Joined a group that hasn't finished its last round yet.
+pub(crate) fn has_open_membership(env: &Env, member: &Address) -> bool {
+    !memberships(env, member).is_empty()
+}
+
+/// Rewrites the recovered member in every group it has joined and not yet finished.
+pub(crate) fn migrate_member(env: &Env, old: &Address, new: &Address) {
+    for id in memberships(env, new).iter() {
+        let Ok(mut group) = load_paluwagan(env, id) else { continue; };
+        for list in [&mut group.members, &mut group.joined, &mut group.paid] {
+            if let Some(i) = list.first_index_of(old) { list.set(i, new.clone()); }
+        }
+        if group.organizer == *old { group.organizer = new.clone(); }
+        storage::write(env, &DataKey::Paluwagan(id), &group);
+    }
+}
+
+/// Pays the pot to this round's recipient and rotates to the next member.
+fn close_round(env: &Env, group: &mut Paluwagan) {
+    let recipient = group.members.get_unchecked(group.round);
+    let pot = group.contribution * group.paid.len() as i128;
+    if pot > 0 {
+        token::Client::new(env, &group.token).transfer(&env.current_contract_address(), &recipient, &pot);
+    }
+    env.events().publish((events::PALUWAGAN_ROUND_PAID, recipient), (group.id, group.round, pot));
+
+    group.round += 1;
+    group.paid = Vec::new(env);
+    group.round_started_at = env.ledger().timestamp();
+    if group.round >= group.members.len() {
+        group.is_active = false;
+        group.is_complete = true;
+        for member in group.members.iter() {
+            storage::remove_item(env, &DataKey::PaluwaganMemberships(member), &group.id);
+        }
+    }
+    storage::write(env, &DataKey::Paluwagan(group.id), group);
+}
+
+/// A member who let the round deadline pass: trust penalty plus a `slash_bps` cut of
+/// their bond, paid in BZR to the member who was owed the pot.
+fn penalize_default(env: &Env, group: &Paluwagan, member: &Address, recipient: &Address) -> Result<(), Error> {
+    let slash_bps = admin::protocol_config(env)?.slash_bps;
+    let mut profile = load_merchant_for_update(env, member);
+    let slashed = profile.staked_amount * slash_bps as i128 / FULL_SHARE_BPS as i128;
+    profile.staked_amount -= slashed;
+    let before = profile.trust_score;
+    profile.trust_score = profile.trust_score.saturating_sub(MISSED_ROUND_TRUST_PENALTY);
+    write_merchant(env, member, &profile);
+    history::record(env, member, before, profile.trust_score, TrustReason::PaluwaganMissed, &env.current_contract_address());
+    discount_if_collapsed(env, member, &profile);
+
+    if slashed > 0 {
+        token::Client::new(env, &admin::bzr_token(env)?).transfer(&env.current_contract_address(), recipient, &slashed);
+    }
+    env.events().publish((events::PALUWAGAN_MISSED, member.clone()), (group.id, group.round, slashed));
+    env.events().publish((events::TRUST_CHANGED, member.clone()), profile.trust_score);
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 16: PALUWAGAN ---
+
+    /// `members` doubles as the payout order. The group starts once every member has joined.
+    pub fn create_paluwagan(env: Env, organizer: Address, members: Vec<Address>, token: Address, contribution: i128, period_seconds: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        organizer.require_auth();
+        touch_heartbeat(&env, &organizer);
+        if members.len() < MIN_MEMBERS || members.len() > MAX_MEMBERS { return Err(Error::InvalidPaluwagan); }
+        if period_seconds < MIN_PERIOD_SECONDS { return Err(Error::InvalidPaluwagan); }
+        if contribution <= 0 { return Err(Error::InvalidAmount); }
+        for (i, member) in members.iter().enumerate() {
+            if members.iter().skip(i + 1).any(|other| other == member) { return Err(Error::InvalidPaluwagan); }
+        }
+
+        let id: u64 = env.storage().instance().get(&DataKey::PaluwaganCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::PaluwaganCount, &id);
+
+        let group = Paluwagan {
+            id,
+            organizer: organizer.clone(),
+            token,
+            contribution,
+            period_seconds,
+            members: members.clone(),
+            joined: Vec::new(&env),
+            round: 0,
+            round_started_at: 0,
+            paid: Vec::new(&env),
+            is_active: false,
+            is_complete: false,
+        };
+        storage::write(&env, &DataKey::Paluwagan(id), &group);
+        env.events().publish((events::PALUWAGAN_CREATED, organizer), (id, members));
+        Ok(id)
+    }
+
+    /// Members opt in explicitly, and only with a live bond backing their promise to pay.
+    pub fn join_paluwagan(env: Env, member: Address, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        member.require_auth();
+        touch_heartbeat(&env, &member);
+        let mut group = load_paluwagan(&env, id)?;
+        if !group.members.contains(&member) { return Err(Error::NotMember); }
+        if group.joined.contains(&member) { return Err(Error::AlreadyJoined); }
+        if !load_merchant(&env, &member).bond_staked { return Err(Error::NotBonded); }
+
+        group.joined.push_back(member.clone());
+        if group.joined.len() == group.members.len() {
+            group.is_active = true;
+            group.round_started_at = env.ledger().timestamp();
+        }
+        storage::write(&env, &DataKey::Paluwagan(id), &group);
+        storage::push_unique(&env, &DataKey::PaluwaganMemberships(member.clone()), id);
+        env.events().publish((events::PALUWAGAN_JOINED, member), id);
+        Ok(())
+    }
+
+    /// Backs out before the group starts, which frees the bond again. Once every member
+    /// has joined the rounds are binding.
+    pub fn leave_paluwagan(env: Env, member: Address, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        member.require_auth();
+        touch_heartbeat(&env, &member);
+        let mut group = load_paluwagan(&env, id)?;
+        if group.is_active || group.is_complete { return Err(Error::PaluwaganStarted); }
+        let Some(i) = group.joined.first_index_of(&member) else { return Err(Error::NotMember) };
+
+        group.joined.remove(i);
+        storage::write(&env, &DataKey::Paluwagan(id), &group);
+        storage::remove_item(&env, &DataKey::PaluwaganMemberships(member.clone()), &id);
+        env.events().publish((events::PALUWAGAN_LEFT, member), id);
+        Ok(())
+    }
+
+    /// Pays this round's contribution. The last one in triggers the payout.
+    pub fn contribute(env: Env, member: Address, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        member.require_auth();
+        touch_heartbeat(&env, &member);
+        let mut group = load_paluwagan(&env, id)?;
+        if !group.is_active { return Err(Error::PaluwaganNotActive); }
+        if !group.members.contains(&member) { return Err(Error::NotMember); }
+        if group.paid.contains(&member) { return Err(Error::AlreadyContributed); }
+        if env.ledger().timestamp() > group.round_started_at.saturating_add(group.period_seconds) { return Err(Error::RoundClosed); }
+
+        token::Client::new(&env, &group.token).transfer(&member, &env.current_contract_address(), &group.contribution);
+        group.paid.push_back(member.clone());
+        // Late contributions are refused above, so every one that lands is on time
+        let mut profile = load_merchant_for_update(&env, &member);
+        rewards::accrue(&env, &member, &mut profile, RewardKind::Contribution);
+        write_merchant(&env, &member, &profile);
+        env.events().publish((events::PALUWAGAN_CONTRIBUTED, member), (id, group.round));
+
+        if group.paid.len() == group.members.len() {
+            close_round(&env, &mut group);
+        } else {
+            storage::write(&env, &DataKey::Paluwagan(id), &group);
+        }
+        Ok(())
+    }
+
+    /// Anyone may close a round after its deadline: whoever did not pay is penalized and
+    /// the recipient gets the partial pot plus the slashed bonds.
+    pub fn settle_round(env: Env, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut group = load_paluwagan(&env, id)?;
+        if !group.is_active { return Err(Error::PaluwaganNotActive); }
+        if env.ledger().timestamp() <= group.round_started_at.saturating_add(group.period_seconds) { return Err(Error::RoundOpen); }
+
+        let recipient = group.members.get_unchecked(group.round);
+        for member in group.members.iter() {
+            if !group.paid.contains(&member) { penalize_default(&env, &group, &member, &recipient)?; }
+        }
+        close_round(&env, &mut group);
+        Ok(())
+    }
+
+    pub fn get_paluwagan(env: Env, id: u64) -> Result<Paluwagan, Error> {
+        load_paluwagan(&env, id)
+    }
+}
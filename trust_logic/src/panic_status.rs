@@ -0,0 +1,55 @@
+// 🏛️ PROJECT BAZAAR | PANIC STATUS
+// Countdown for frozen vaults, so off-chain alerting can follow a panic without an
+// indexer. The freeze itself publishes the full status, and anyone may poke an owner's
+// vaults later to publish a fresh checkpoint with the time left on each clock.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PanicStatus {
+    pub vault_id: u32,
+    pub frozen_at: u64,
+    /// End of the window the owner was given to answer with `cancel_panic`.
+    pub response_ends_at: u64,
+    /// When the heirs can start a claim unless the owner cancels first.
+    pub claimable_at: u64,
+    pub response_remaining: u64,
+    pub claimable_in: u64,
+}
+
+/// Countdown of a frozen vault as of now.
+pub(crate) fn status_of(env: &Env, vault_id: u32, vault: &LegacyVault) -> PanicStatus {
+    let now = env.ledger().timestamp();
+    let frozen_at = vault.frozen_at.unwrap_or(now);
+    let response_ends_at = frozen_at.saturating_add(vault.panic_response_seconds);
+    let claimable_at = claimable_at(vault);
+    PanicStatus {
+        vault_id,
+        frozen_at,
+        response_ends_at,
+        claimable_at,
+        response_remaining: response_ends_at.saturating_sub(now),
+        claimable_in: claimable_at.saturating_sub(now),
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 50: PANIC STATUS ---
+
+    /// Permissionless: publishes a checkpoint for each of the owner's frozen vaults and
+    /// returns them. Nothing is written, so a bot can call it on any schedule.
+    pub fn poke_panic_status(env: Env, user: Address) -> Vec<PanicStatus> {
+        let mut statuses = Vec::new(&env);
+        for id in vault_ids(&env, &user).iter() {
+            let Ok(vault) = load_vault(&env, &user, id) else { continue; };
+            if !vault.is_frozen { continue; }
+            let status = status_of(&env, id, &vault);
+            env.events().publish((events::PANIC_CHECKPOINT, user.clone(), id), status.clone());
+            statuses.push_back(status);
+        }
+        statuses
+    }
+}
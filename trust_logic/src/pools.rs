@@ -0,0 +1,141 @@
+// 🏛️ PROJECT BAZAAR | GIFT POOLS (ABULOY)
+// Community collections for funerals and emergencies: contributions are held until the
+// deadline, then go to the beneficiary in one transfer. If the organizer's minimum was
+// not reached, every contributor can take their share back instead.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolStatus {
+    Open,
+    Paid,
+    Failed,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct GiftPool {
+    pub id: u64,
+    pub organizer: Address,
+    pub beneficiary: Address,
+    pub token: Address,
+    pub deadline: u64,
+    pub min_amount: i128,
+    pub total: i128,
+    pub contributions: Map<Address, i128>,
+    pub status: PoolStatus,
+}
+
+fn load_pool(env: &Env, id: u64) -> Result<GiftPool, Error> {
+    storage::read(env, &DataKey::GiftPool(id)).ok_or(Error::PoolNotFound)
+}
+
+/// Rewrites the recovered address as organizer, beneficiary or contributor.
+pub(crate) fn migrate_party(env: &Env, old: &Address, new: &Address) {
+    let ids: Vec<u64> = storage::read(env, &DataKey::PoolsOf(new.clone())).unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        let Ok(mut pool) = load_pool(env, id) else { continue; };
+        if pool.organizer == *old { pool.organizer = new.clone(); }
+        if pool.beneficiary == *old { pool.beneficiary = new.clone(); }
+        if let Some(given) = pool.contributions.get(old.clone()) {
+            pool.contributions.remove(old.clone());
+            pool.contributions.set(new.clone(), given);
+        }
+        storage::write(env, &DataKey::GiftPool(id), &pool);
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 28: GIFT POOLS ---
+
+    /// `min_amount` of 0 means whatever is collected goes to the beneficiary.
+    pub fn create_pool(env: Env, organizer: Address, beneficiary: Address, token: Address, deadline: u64, min_amount: i128) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        organizer.require_auth();
+        touch_heartbeat(&env, &organizer);
+        if deadline <= env.ledger().timestamp() || min_amount < 0 { return Err(Error::InvalidPool); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::PoolCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::PoolCount, &id);
+
+        let pool = GiftPool {
+            id,
+            organizer: organizer.clone(),
+            beneficiary: beneficiary.clone(),
+            token,
+            deadline,
+            min_amount,
+            total: 0,
+            contributions: Map::new(&env),
+            status: PoolStatus::Open,
+        };
+        storage::write(&env, &DataKey::GiftPool(id), &pool);
+        storage::push_unique(&env, &DataKey::PoolsOf(organizer.clone()), id);
+        storage::push_unique(&env, &DataKey::PoolsOf(beneficiary.clone()), id);
+        env.events().publish((events::POOL_CREATED, organizer), (id, beneficiary, deadline, min_amount));
+        Ok(id)
+    }
+
+    pub fn contribute_pool(env: Env, contributor: Address, id: u64, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        contributor.require_auth();
+        touch_heartbeat(&env, &contributor);
+        let mut pool = load_pool(&env, id)?;
+        if pool.status != PoolStatus::Open || env.ledger().timestamp() >= pool.deadline { return Err(Error::PoolClosed); }
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        token::Client::new(&env, &pool.token).transfer(&contributor, &env.current_contract_address(), &amount);
+        let given = pool.contributions.get(contributor.clone()).unwrap_or(0);
+        pool.contributions.set(contributor.clone(), given + amount);
+        storage::push_unique(&env, &DataKey::PoolsOf(contributor.clone()), id);
+        pool.total += amount;
+        storage::write(&env, &DataKey::GiftPool(id), &pool);
+        env.events().publish((events::POOL_CONTRIBUTED, contributor), (id, amount, pool.total));
+        Ok(pool.total)
+    }
+
+    /// Anyone may finalize after the deadline; the organizer may also close early once
+    /// the minimum is in, since funeral costs rarely wait.
+    pub fn finalize_pool(env: Env, caller: Address, id: u64) -> Result<PoolStatus, Error> {
+        require_not_paused(&env)?;
+        caller.require_auth();
+        touch_heartbeat(&env, &caller);
+        let mut pool = load_pool(&env, id)?;
+        if pool.status != PoolStatus::Open { return Err(Error::PoolClosed); }
+        let met = pool.total >= pool.min_amount;
+        let early_close = caller == pool.organizer && met;
+        if env.ledger().timestamp() < pool.deadline && !early_close { return Err(Error::PoolOpen); }
+
+        pool.status = if met { PoolStatus::Paid } else { PoolStatus::Failed };
+        storage::write(&env, &DataKey::GiftPool(id), &pool);
+        if met && pool.total > 0 {
+            token::Client::new(&env, &pool.token).transfer(&env.current_contract_address(), &pool.beneficiary, &pool.total);
+        }
+        env.events().publish((events::POOL_FINALIZED, pool.beneficiary), (id, pool.status, pool.total));
+        Ok(pool.status)
+    }
+
+    /// After a failed pool each contributor pulls their own refund, so finalizing never
+    /// has to loop over an unbounded contributor list.
+    pub fn refund_pool(env: Env, contributor: Address, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        contributor.require_auth();
+        touch_heartbeat(&env, &contributor);
+        let mut pool = load_pool(&env, id)?;
+        if pool.status != PoolStatus::Failed { return Err(Error::PoolNotFailed); }
+        let amount = pool.contributions.get(contributor.clone()).ok_or(Error::NothingToRefund)?;
+
+        pool.contributions.remove(contributor.clone());
+        storage::write(&env, &DataKey::GiftPool(id), &pool);
+        token::Client::new(&env, &pool.token).transfer(&env.current_contract_address(), &contributor, &amount);
+        env.events().publish((events::POOL_REFUNDED, contributor), (id, amount));
+        Ok(amount)
+    }
+
+    pub fn get_pool(env: Env, id: u64) -> Result<GiftPool, Error> {
+        load_pool(&env, id)
+    }
+}
@@ -0,0 +1,70 @@
+// 🏛️ PROJECT BAZAAR | MERCHANT PROFILES
+// Human-readable handles and storefront metadata for the marketplace. Each nickname
+// maps back to exactly one address; heavy content (bio, images) lives off-chain under
+// a content hash so the dApp can render a storefront from chain state alone.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+/// Placeholder every fresh profile starts with; it can never be registered.
+const DEFAULT_NICKNAME: &str = "User";
+const MAX_DISPLAY_NAME_LEN: u32 = 50;
+const MAX_CATEGORY_TAGS: u32 = 5;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct MerchantProfile {
+    pub display_name: String,
+    /// IPFS CID digest of the bio document.
+    pub bio_hash: BytesN<32>,
+    pub location: Symbol,
+    pub tags: Vec<Symbol>,
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 17: PROFILES ---
+
+    pub fn register_nickname(env: Env, user: Address, nickname: Symbol) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if nickname == Symbol::new(&env, DEFAULT_NICKNAME) { return Err(Error::InvalidNickname); }
+
+        let key = DataKey::Nickname(nickname.clone());
+        match storage::read::<Address>(&env, &key) {
+            Some(owner) if owner == user => return Ok(()),
+            Some(_) => return Err(Error::NicknameTaken),
+            None => {}
+        }
+
+        let mut merchant = load_merchant_for_update(&env, &user);
+        storage::remove(&env, &DataKey::Nickname(merchant.nickname.clone()));
+        merchant.nickname = nickname.clone();
+        write_merchant(&env, &user, &merchant);
+        storage::write(&env, &key, &user);
+        env.events().publish((events::NICKNAME_SET, user), nickname);
+        Ok(())
+    }
+
+    pub fn resolve_nickname(env: Env, nickname: Symbol) -> Option<Address> {
+        storage::read(&env, &DataKey::Nickname(nickname))
+    }
+
+    pub fn set_profile(env: Env, user: Address, profile: MerchantProfile) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if profile.display_name.is_empty() || profile.display_name.len() > MAX_DISPLAY_NAME_LEN { return Err(Error::InvalidProfile); }
+        if profile.tags.len() > MAX_CATEGORY_TAGS { return Err(Error::InvalidProfile); }
+
+        storage::write(&env, &DataKey::Profile(user.clone()), &profile);
+        env.events().publish((events::PROFILE_SET, user), profile.bio_hash);
+        Ok(())
+    }
+
+    pub fn get_profile(env: Env, user: Address) -> Option<MerchantProfile> {
+        storage::read(&env, &DataKey::Profile(user))
+    }
+}
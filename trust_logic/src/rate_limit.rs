@@ -0,0 +1,48 @@
+// 🏛️ PROJECT BAZAAR | RATE LIMITS
+// Trust-affecting actions are throttled so a single key can't spam vouches, emergencies
+// or panic rounds. Each limit keeps the timestamps of recent uses and refuses once the
+// window is full; old timestamps fall out as the window slides.
+
+use super::*;
+
+const VOUCH_COOLDOWN_SECONDS: u64 = 2_592_000;     // 30 Days
+const EMERGENCY_COOLDOWN_SECONDS: u64 = 7_776_000; // 90 Days
+const PANIC_ATTEMPTS_PER_YEAR: u32 = 3;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RateAction {
+    Vouch,
+    Emergency,
+    Panic,
+}
+
+/// (uses allowed, window) per action: one vouch per target per 30 days, one emergency
+/// declaration per declarer per 90 days, three panic attempts per circle per year.
+fn limit(action: RateAction) -> (u32, u64) {
+    match action {
+        RateAction::Vouch => (1, VOUCH_COOLDOWN_SECONDS),
+        RateAction::Emergency => (1, EMERGENCY_COOLDOWN_SECONDS),
+        RateAction::Panic => (PANIC_ATTEMPTS_PER_YEAR, YEAR_SECONDS),
+    }
+}
+
+/// Records one use of `action` by `actor` towards `scope`, or fails if the window is full.
+/// Per-address limits pass the actor as its own scope.
+pub(crate) fn consume(env: &Env, action: RateAction, actor: &Address, scope: &Address) -> Result<(), Error> {
+    let (max_uses, window) = limit(action);
+    let key = DataKey::RateLimit(action, actor.clone(), scope.clone());
+    let now = env.ledger().timestamp();
+
+    let mut recent: Vec<u64> = Vec::new(env);
+    for used_at in storage::read::<Vec<u64>>(env, &key).unwrap_or(Vec::new(env)).iter() {
+        if now < used_at + window { recent.push_back(used_at); }
+    }
+    if recent.len() >= max_uses {
+        return Err(if action == RateAction::Vouch { Error::VouchCooldown } else { Error::RateLimited });
+    }
+
+    recent.push_back(now);
+    storage::write(env, &key, &recent);
+    Ok(())
+}
@@ -0,0 +1,339 @@
+// 🏛️ PROJECT BAZAAR | SOCIAL RECOVERY
+// If an owner loses their key, witnesses holding three quarters of the circle's weight
+// can approve moving the whole account to a new address. The move only executes 14 days after approval, so
+// an owner who still has their key can spot and cancel a hostile recovery. Until a
+// candidate reaches the quorum witnesses may change or withdraw their votes, and a
+// request nobody agrees on lapses after 30 days.
+
+use super::*;
+
+const RECOVERY_QUORUM_BPS: u32 = 7_500;
+const MIN_RECOVERY_BACKERS: u32 = 3;
+const RECOVERY_DELAY_SECONDS: u64 = 1_209_600; // 14 Days
+const RECOVERY_VOTE_SECONDS: u64 = 2_592_000;  // 30 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RecoveryRequest {
+    /// Each witness's current candidate for the new owner.
+    pub votes: Map<Address, Address>,
+    pub opened_at: u64,
+    /// An unapproved request lapses at this time.
+    pub expires_at: u64,
+    /// The candidate that reached the quorum; fixed from then on.
+    pub new_owner: Option<Address>,
+    pub approved_at: Option<u64>,
+}
+
+/// Open request for `user`; an unapproved one past its expiry reads as none.
+fn live_request(env: &Env, user: &Address) -> Option<RecoveryRequest> {
+    let request: RecoveryRequest = storage::read(env, &DataKey::Recovery(user.clone()))?;
+    if request.approved_at.is_none() && env.ledger().timestamp() >= request.expires_at { return None; }
+    Some(request)
+}
+
+/// Weight a candidate needs, rounded up. It comes from at least `MIN_RECOVERY_BACKERS`
+/// witnesses as well, so one heavy witness can't hand the account over alone.
+fn recovery_quorum(env: &Env, user: &Address) -> u32 {
+    (circle_weight(env, user) * RECOVERY_QUORUM_BPS).div_ceil(FULL_SHARE_BPS)
+}
+
+/// Weight and head count behind `candidate`. Votes of witnesses who left the circle lapse.
+fn backing(env: &Env, user: &Address, request: &RecoveryRequest, candidate: &Address) -> (u32, u32) {
+    let (mut weight, mut backers) = (0, 0);
+    for member in load_circle(env, user).unwrap_or(Vec::new(env)).iter() {
+        if request.votes.get(member.address.clone()).as_ref() == Some(candidate) {
+            weight += member.weight;
+            backers += 1;
+        }
+    }
+    (weight, backers)
+}
+
+/// Points every other vault that names `old` as heir at `new` instead.
+fn migrate_heir_references(env: &Env, old: &Address, new: &Address) {
+    let owners: Vec<Address> = storage::read(env, &DataKey::NamedHeirIn(old.clone())).unwrap_or(Vec::new(env));
+    for owner in owners.iter() {
+        for id in vault_ids(env, &owner).iter() {
+            migrate_heir_in_vault(env, &owner, id, old, new);
+            routing::migrate_heir(env, &owner, id, old, new);
+        }
+    }
+}
+
+fn migrate_heir_in_vault(env: &Env, owner: &Address, vault_id: u32, old: &Address, new: &Address) {
+    let Ok(mut vault) = load_vault(env, owner, vault_id) else { return; };
+    let mut heirs = Vec::new(env);
+    for share in vault.heirs.iter() {
+        let address = if share.address == *old { new.clone() } else { share.address };
+        heirs.push_back(HeirShare { address, bps: share.bps });
+    }
+    vault.heirs = heirs;
+    let pending_key = DataKey::PendingHeirs(owner.clone(), vault_id);
+    if let Some(mut pending) = storage::read::<PendingHeirs>(env, &pending_key) {
+        let mut queued = Vec::new(env);
+        for share in pending.heirs.iter() {
+            let address = if share.address == *old { new.clone() } else { share.address };
+            queued.push_back(HeirShare { address, bps: share.bps });
+        }
+        pending.heirs = queued;
+        storage::write(env, &pending_key, &pending);
+    }
+    if let Some(i) = vault.accepted_heirs.first_index_of(old) { vault.accepted_heirs.set(i, new.clone()); }
+    if let Some(terms) = vault.minor_heirs.get(old.clone()) {
+        vault.minor_heirs.remove(old.clone());
+        vault.minor_heirs.set(new.clone(), terms);
+    }
+    if let Some(i) = vault.backup_heirs.first_index_of(old) { vault.backup_heirs.set(i, new.clone()); }
+    write_vault(env, owner, vault_id, &vault);
+}
+
+/// Vouch edges are stored from both ends, so the far end of each edge is rewritten too,
+/// along with the stakes behind them.
+fn migrate_vouch_edges(env: &Env, old: &Address, new: &Address) {
+    let given: Vec<Address> = storage::read(env, &DataKey::VouchesGiven(new.clone())).unwrap_or(Vec::new(env));
+    for target in given.iter() {
+        let key = DataKey::Vouchers(target.clone());
+        let mut vouchers: Map<Address, u32> = storage::read(env, &key).unwrap_or(Map::new(env));
+        if let Some(points) = vouchers.get(old.clone()) {
+            vouchers.remove(old.clone());
+            vouchers.set(new.clone(), points);
+            storage::write(env, &key, &vouchers);
+        }
+    }
+    vouch_stakes::migrate_voucher(env, &given, old, new);
+
+    let received: Map<Address, u32> = storage::read(env, &DataKey::Vouchers(new.clone())).unwrap_or(Map::new(env));
+    for voucher in received.keys().iter() {
+        let key = DataKey::VouchesGiven(voucher.clone());
+        let mut targets: Vec<Address> = storage::read(env, &key).unwrap_or(Vec::new(env));
+        if let Some(i) = targets.first_index_of(old) {
+            targets.set(i, new.clone());
+            storage::write(env, &key, &targets);
+        }
+    }
+}
+
+/// The circle moves with the owner, so its witnesses' reverse index must follow too.
+fn migrate_circle_index(env: &Env, old: &Address, new: &Address) {
+    let circle: Vec<Witness> = storage::read(env, &DataKey::Witnesses(new.clone())).unwrap_or(Vec::new(env));
+    for member in circle.iter() {
+        let key = DataKey::WitnessFor(member.address.clone());
+        let mut owners: Vec<Address> = storage::read(env, &key).unwrap_or(Vec::new(env));
+        if let Some(i) = owners.first_index_of(old) {
+            owners.set(i, new.clone());
+            storage::write(env, &key, &owners);
+        }
+    }
+}
+
+/// Listings, the nickname, the juror seat and an open loan carry the owner's address
+/// inside the value, not just the key.
+fn migrate_merchant_records(env: &Env, old: &Address, new: &Address) {
+    let nickname_key = DataKey::Nickname(load_merchant(env, new).nickname);
+    if storage::read::<Address>(env, &nickname_key).as_ref() == Some(old) {
+        storage::write(env, &nickname_key, new);
+    }
+
+    let ids: Vec<u64> = storage::read(env, &DataKey::MerchantListings(new.clone())).unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        if let Ok(mut listing) = listings::load_listing(env, id) {
+            listing.merchant = new.clone();
+            storage::write(env, &DataKey::Listing(id), &listing);
+        }
+    }
+
+    let mut pool: Vec<Address> = storage::read(env, &DataKey::JurorPool).unwrap_or(Vec::new(env));
+    if let Some(i) = pool.first_index_of(old) {
+        pool.set(i, new.clone());
+        storage::write(env, &DataKey::JurorPool, &pool);
+    }
+
+    if let Some(id) = storage::read::<u64>(env, &DataKey::ActiveLoan(new.clone())) {
+        if let Ok(mut loan) = loans::load_loan(env, id) {
+            loan.borrower = new.clone();
+            storage::write(env, &DataKey::Loan(id), &loan);
+        }
+    }
+}
+
+/// Every entry keyed by `old` that moves to `new`. Badge and spending-window entries
+/// move as well, but only exist beside the merchant record and the spending limits.
+fn account_moves(env: &Env, old: &Address, new: &Address) -> Vec<(DataKey, DataKey)> {
+    let mut moves = Vec::new(env);
+    for id in vault_ids(env, old).iter() {
+        for (from, to) in [
+            (DataKey::Vault(old.clone(), id), DataKey::Vault(new.clone(), id)),
+            (DataKey::VaultState(old.clone(), id), DataKey::VaultState(new.clone(), id)),
+            (DataKey::LastReminder(old.clone(), id), DataKey::LastReminder(new.clone(), id)),
+            (DataKey::StrategyPositions(old.clone(), id), DataKey::StrategyPositions(new.clone(), id)),
+            (DataKey::VaultVesting(old.clone(), id), DataKey::VaultVesting(new.clone(), id)),
+            (DataKey::PendingHeirs(old.clone(), id), DataKey::PendingHeirs(new.clone(), id)),
+            (DataKey::TokenRouting(old.clone(), id), DataKey::TokenRouting(new.clone(), id)),
+            (DataKey::ClaimRelayer(old.clone(), id), DataKey::ClaimRelayer(new.clone(), id)),
+        ] {
+            moves.push_back((from, to));
+        }
+    }
+    for (from, to) in [
+        (DataKey::VaultCount(old.clone()), DataKey::VaultCount(new.clone())),
+        (DataKey::Merchant(old.clone()), DataKey::Merchant(new.clone())),
+        (DataKey::TrustScore(old.clone()), DataKey::TrustScore(new.clone())),
+        (DataKey::Witnesses(old.clone()), DataKey::Witnesses(new.clone())),
+        (DataKey::CircleConfig(old.clone()), DataKey::CircleConfig(new.clone())),
+        (DataKey::UserConfig(old.clone()), DataKey::UserConfig(new.clone())),
+        (DataKey::Emergency(old.clone()), DataKey::Emergency(new.clone())),
+        (DataKey::MedicalYear(old.clone()), DataKey::MedicalYear(new.clone())),
+        (DataKey::PanicVotes(old.clone()), DataKey::PanicVotes(new.clone())),
+        (DataKey::AliveAttestations(old.clone()), DataKey::AliveAttestations(new.clone())),
+        (DataKey::Inbox(old.clone()), DataKey::Inbox(new.clone())),
+        (DataKey::HeartbeatDelegate(old.clone()), DataKey::HeartbeatDelegate(new.clone())),
+        (DataKey::NamedHeirIn(old.clone()), DataKey::NamedHeirIn(new.clone())),
+        (DataKey::Vesting(old.clone()), DataKey::Vesting(new.clone())),
+        (DataKey::GrantHeirs(old.clone()), DataKey::GrantHeirs(new.clone())),
+        (DataKey::Compensation(old.clone()), DataKey::Compensation(new.clone())),
+        (DataKey::OpenDisputes(old.clone()), DataKey::OpenDisputes(new.clone())),
+        (DataKey::MerchantListings(old.clone()), DataKey::MerchantListings(new.clone())),
+        (DataKey::Juror(old.clone()), DataKey::Juror(new.clone())),
+        (DataKey::Rating(old.clone()), DataKey::Rating(new.clone())),
+        (DataKey::TrustHistory(old.clone()), DataKey::TrustHistory(new.clone())),
+        (DataKey::Vouchers(old.clone()), DataKey::Vouchers(new.clone())),
+        (DataKey::VouchesGiven(old.clone()), DataKey::VouchesGiven(new.clone())),
+        (DataKey::ActiveLoan(old.clone()), DataKey::ActiveLoan(new.clone())),
+        (DataKey::PaluwaganMemberships(old.clone()), DataKey::PaluwaganMemberships(new.clone())),
+        (DataKey::GoalsOf(old.clone()), DataKey::GoalsOf(new.clone())),
+        (DataKey::SubscriptionsOf(old.clone()), DataKey::SubscriptionsOf(new.clone())),
+        (DataKey::PoolsOf(old.clone()), DataKey::PoolsOf(new.clone())),
+        (DataKey::GuardedHeirs(old.clone()), DataKey::GuardedHeirs(new.clone())),
+        (DataKey::GuardedFor(old.clone()), DataKey::GuardedFor(new.clone())),
+        (DataKey::WitnessStats(old.clone()), DataKey::WitnessStats(new.clone())),
+        (DataKey::SpendingLimits(old.clone()), DataKey::SpendingLimits(new.clone())),
+        (DataKey::LegacyNote(old.clone()), DataKey::LegacyNote(new.clone())),
+        (DataKey::Profile(old.clone()), DataKey::Profile(new.clone())),
+        (DataKey::BuyerProfile(old.clone()), DataKey::BuyerProfile(new.clone())),
+        (DataKey::CategoryTrust(old.clone()), DataKey::CategoryTrust(new.clone())),
+        (DataKey::VouchStakes(old.clone()), DataKey::VouchStakes(new.clone())),
+        (DataKey::Referral(old.clone()), DataKey::Referral(new.clone())),
+        (DataKey::Away(old.clone()), DataKey::Away(new.clone())),
+        (DataKey::WitnessContact(old.clone()), DataKey::WitnessContact(new.clone())),
+    ] {
+        moves.push_back((from, to));
+    }
+    moves
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 13: SOCIAL RECOVERY ---
+
+    /// Votes for `new_owner`, replacing the witness's earlier pick. Returns the circle weight
+    /// now behind that candidate.
+    pub fn vote_recovery(env: Env, witness: Address, target_user: Address, new_owner: Address) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        require_witness(&env, &witness, &target_user)?;
+        if load_circle(&env, &target_user)?.len() < MIN_RECOVERY_BACKERS { return Err(Error::NoCircle); }
+        if new_owner == target_user { return Err(Error::RecoveryMismatch); }
+        blacklist::require_not_blacklisted(&env, &target_user)?;
+        blacklist::require_not_blacklisted(&env, &new_owner)?;
+
+        let now = env.ledger().timestamp();
+        let mut request = live_request(&env, &target_user).unwrap_or(RecoveryRequest {
+            votes: Map::new(&env),
+            opened_at: now,
+            expires_at: now + RECOVERY_VOTE_SECONDS,
+            new_owner: None,
+            approved_at: None,
+        });
+        if request.approved_at.is_some() { return Err(Error::RecoveryAlreadyApproved); }
+        if request.votes.get(witness.clone()) == Some(new_owner.clone()) { return Err(Error::AlreadyVoted); }
+
+        request.votes.set(witness.clone(), new_owner.clone());
+        env.events().publish((events::RECOVERY_VOTE, target_user.clone()), (witness, new_owner.clone()));
+        let (weight, backers) = backing(&env, &target_user, &request, &new_owner);
+        if weight >= recovery_quorum(&env, &target_user) && backers >= MIN_RECOVERY_BACKERS {
+            request.new_owner = Some(new_owner.clone());
+            request.approved_at = Some(now);
+            env.events().publish((events::RECOVERY_APPROVED, target_user.clone()), new_owner);
+        }
+        storage::write(&env, &DataKey::Recovery(target_user), &request);
+        Ok(weight)
+    }
+
+    /// Takes back a vote that hasn't yet helped approve a candidate.
+    pub fn withdraw_recovery_vote(env: Env, witness: Address, target_user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        let mut request = live_request(&env, &target_user).ok_or(Error::RecoveryNotFound)?;
+        if request.approved_at.is_some() { return Err(Error::RecoveryAlreadyApproved); }
+        if !request.votes.contains_key(witness.clone()) { return Err(Error::NoRecoveryVote); }
+
+        request.votes.remove(witness.clone());
+        let key = DataKey::Recovery(target_user.clone());
+        if request.votes.is_empty() { storage::remove(&env, &key); } else { storage::write(&env, &key, &request); }
+        env.events().publish((events::RECOVERY_VOTE_WITHDRAWN, target_user), witness);
+        Ok(())
+    }
+
+    pub fn cancel_recovery(env: Env, user: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let key = DataKey::Recovery(user.clone());
+        if !storage::has(&env, &key) { return Err(Error::RecoveryNotFound); }
+        storage::remove(&env, &key);
+        env.events().publish((events::RECOVERY_CANCELLED, user), ());
+        Ok(())
+    }
+
+    /// Moves every entry keyed by the old address to the new owner in one transaction, and
+    /// rewrites the address inside its goals, subscriptions, pools, paluwagan groups and
+    /// guarded funds. Refused if the new address already holds any of those entries.
+    /// Anyone may trigger it once the delay has passed. Open orders, disputes, guardian
+    /// seats and the old address's seats in other people's circles are not rewritten.
+    pub fn recover_account(env: Env, target_user: Address) -> Result<Address, Error> {
+        require_not_paused(&env)?;
+        let key = DataKey::Recovery(target_user.clone());
+        let request = live_request(&env, &target_user).ok_or(Error::RecoveryNotFound)?;
+        let (Some(approved_at), Some(new)) = (request.approved_at, request.new_owner) else { return Err(Error::RecoveryNotApproved); };
+        if env.ledger().timestamp() < approved_at + RECOVERY_DELAY_SECONDS { return Err(Error::RecoveryDelayActive); }
+
+        let old = target_user;
+        // Either side may have been deny-listed during the delay; recovery must not launder a listing
+        blacklist::require_not_blacklisted(&env, &old)?;
+        blacklist::require_not_blacklisted(&env, &new)?;
+        // Nothing already under `new` may be overwritten. A BZR balance is merged instead
+        let moves = account_moves(&env, &old, &new);
+        if moves.iter().any(|(_, to)| storage::has(&env, &to)) { return Err(Error::RecoveryTargetInUse); }
+
+        storage::remove(&env, &key);
+        migrate_heir_references(&env, &old, &new);
+        for id in vault_ids(&env, &old).iter() {
+            claims::cancel_pending_claim(&env, &old, id);
+        }
+        badges::move_badges(&env, &old, &new);
+        spending::move_windows(&env, &old, &new);
+        for (from, to) in moves.iter() {
+            storage::rename(&env, &from, &to);
+        }
+        bzr_wallet::merge_balance(&env, &old, &new);
+        migrate_merchant_records(&env, &old, &new);
+        migrate_vouch_edges(&env, &old, &new);
+        migrate_circle_index(&env, &old, &new);
+        savings::migrate_owner(&env, &old, &new);
+        subscriptions::migrate_party(&env, &old, &new);
+        pools::migrate_party(&env, &old, &new);
+        paluwagan::migrate_member(&env, &old, &new);
+        guardianship::migrate_party(&env, &old, &new);
+
+        env.events().publish((events::ACCOUNT_RECOVERED, old), new.clone());
+        Ok(new)
+    }
+
+    pub fn get_recovery(env: Env, target_user: Address) -> Option<RecoveryRequest> {
+        live_request(&env, &target_user)
+    }
+}
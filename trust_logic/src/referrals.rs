@@ -0,0 +1,89 @@
+// 🏛️ PROJECT BAZAAR | REFERRALS
+// A new user can name the merchant who brought them in. When the newcomer first bonds
+// and first completes an order, both sides accrue the referral reward, claimable
+// through the BZR rewards pool. Only Silver-tier merchants and up may refer, and each
+// referrer is capped at ten new referrals per 30 days.
+
+use super::*;
+
+const REFERRER_MIN_TIER: TrustTier = TrustTier::Silver;
+const MAX_REFERRALS_PER_MONTH: u32 = 10;
+const REFERRAL_MONTH_SECONDS: u64 = 2_592_000; // 30 Days
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferralMilestone {
+    FirstStake,
+    FirstOrder,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Referral {
+    pub referrer: Address,
+    pub registered_at: u64,
+    pub milestones: Vec<ReferralMilestone>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReferralMonth {
+    pub started_at: u64,
+    pub count: u32,
+}
+
+fn referral_month(env: &Env, referrer: &Address) -> ReferralMonth {
+    let now = env.ledger().timestamp();
+    match storage::read::<ReferralMonth>(env, &DataKey::ReferralMonth(referrer.clone())) {
+        Some(month) if now < month.started_at + REFERRAL_MONTH_SECONDS => month,
+        _ => ReferralMonth { started_at: now, count: 0 },
+    }
+}
+
+/// Rewards both sides the first time `user` reaches `milestone`. No-op for users who
+/// were not referred.
+pub(crate) fn reach(env: &Env, user: &Address, milestone: ReferralMilestone) {
+    let key = DataKey::Referral(user.clone());
+    let Some(mut referral) = storage::read::<Referral>(env, &key) else { return; };
+    if referral.milestones.contains(milestone) { return; }
+    referral.milestones.push_back(milestone);
+    storage::write(env, &key, &referral);
+
+    for party in [user.clone(), referral.referrer.clone()] {
+        let mut merchant = load_merchant_for_update(env, &party);
+        rewards::accrue(env, &party, &mut merchant, RewardKind::Referral);
+        write_merchant(env, &party, &merchant);
+    }
+    env.events().publish((events::REFERRAL_MILESTONE, referral.referrer), (user.clone(), milestone));
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 44: REFERRALS ---
+
+    /// Must be the newcomer's first interaction: they may not have a merchant record yet.
+    pub fn register_with_referral(env: Env, new_user: Address, referrer: Address) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        new_user.require_auth();
+        touch_heartbeat(&env, &new_user);
+        if new_user == referrer { return Err(Error::InvalidReferral); }
+        if storage::has(&env, &DataKey::Merchant(new_user.clone())) || storage::has(&env, &DataKey::Referral(new_user.clone())) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if tier_of(current_trust(&env, &referrer)) < REFERRER_MIN_TIER { return Err(Error::TierTooLow); }
+        let mut month = referral_month(&env, &referrer);
+        if month.count >= MAX_REFERRALS_PER_MONTH { return Err(Error::ReferralLimitReached); }
+
+        month.count += 1;
+        storage::write(&env, &DataKey::ReferralMonth(referrer.clone()), &month);
+        let referral = Referral { referrer: referrer.clone(), registered_at: env.ledger().timestamp(), milestones: Vec::new(&env) };
+        storage::write(&env, &DataKey::Referral(new_user.clone()), &referral);
+        env.events().publish((events::REFERRED, referrer), new_user);
+        Ok(())
+    }
+
+    pub fn get_referral(env: Env, user: Address) -> Option<Referral> {
+        storage::read(&env, &DataKey::Referral(user))
+    }
+}
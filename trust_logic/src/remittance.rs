@@ -0,0 +1,90 @@
+// 🏛️ PROJECT BAZAAR | REMITTANCES
+// Direct transfers for overseas workers sending money home. Each send carries a memo,
+// pays a small protocol fee, lands in both parties' history, and regular senders
+// earn trust along the way.
+
+use super::*;
+
+const MAX_MEMO_LEN: u32 = 64;
+const MAX_HISTORY: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 20;
+const REMIT_MILESTONE: u32 = 10;
+const REMIT_TRUST_POINTS: u32 = 1;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Remittance {
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub memo: String,
+    pub timestamp: u64,
+}
+
+fn record(env: &Env, user: &Address, entry: &Remittance) {
+    let key = DataKey::RemitHistory(user.clone());
+    let mut history: Vec<Remittance> = storage::read(env, &key).unwrap_or(Vec::new(env));
+    if history.len() >= MAX_HISTORY { history.pop_front(); }
+    history.push_back(entry.clone());
+    storage::write(env, &key, &history);
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 14: REMITTANCES ---
+
+    /// Sends `amount` minus the protocol fee to `recipient`. Returns the amount delivered.
+    pub fn remit(env: Env, sender: Address, recipient: Address, token: Address, amount: i128, memo: String) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        sender.require_auth();
+        touch_heartbeat(&env, &sender);
+        if sender == recipient { return Err(Error::SelfRemit); }
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if memo.len() > MAX_MEMO_LEN { return Err(Error::InvalidMemo); }
+
+        let fee = treasury::fee_on(amount, treasury::fee_config(&env).remit_bps);
+        let delivered = amount - fee;
+        token::Client::new(&env, &token).transfer(&sender, &recipient, &delivered);
+        treasury::collect(&env, &token, &sender, fee);
+
+        let entry = Remittance {
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
+            amount: delivered,
+            fee,
+            memo,
+            timestamp: env.ledger().timestamp(),
+        };
+        record(&env, &sender, &entry);
+        record(&env, &recipient, &entry);
+
+        // Every 10th remittance is a volume milestone worth a trust point
+        let mut profile = load_merchant_for_update(&env, &sender);
+        profile.remittances_sent += 1;
+        profile.last_activity = entry.timestamp;
+        let milestone = profile.remittances_sent.is_multiple_of(REMIT_MILESTONE);
+        let before = profile.trust_score;
+        if milestone {
+            profile.trust_score = (profile.trust_score + REMIT_TRUST_POINTS).min(MAX_TRUST_SCORE);
+            badges::check_and_award_badges(&env, &sender, &mut profile);
+        }
+        write_merchant(&env, &sender, &profile);
+        history::record(&env, &sender, before, profile.trust_score, TrustReason::Remittance, &sender);
+
+        env.events().publish((events::REMITTED, recipient), (sender.clone(), token, delivered));
+        if milestone { env.events().publish((events::TRUST_CHANGED, sender), profile.trust_score); }
+        Ok(delivered)
+    }
+
+    /// Newest last, sent and received alike. `limit` is capped at 20 per page.
+    pub fn get_remittances(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Remittance> {
+        let history: Vec<Remittance> = storage::read(&env, &DataKey::RemitHistory(user)).unwrap_or(Vec::new(&env));
+        let start = offset.min(history.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(history.len());
+        history.slice(start..end)
+    }
+}
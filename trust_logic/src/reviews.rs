@@ -0,0 +1,100 @@
+// 🏛️ PROJECT BAZAAR | REVIEWS
+// Buyers rate a merchant once per released order. Ratings are kept as a running
+// (count, sum) aggregate, and the average moves the merchant's trust score by up to
+// four points either way.
+
+use super::*;
+use soroban_sdk::BytesN;
+
+const MIN_STARS: u32 = 1;
+const MAX_STARS: u32 = 5;
+/// Trust points per star above or below a neutral 3-star average.
+const TRUST_POINTS_PER_STAR: i64 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Review {
+    pub order_id: u64,
+    pub buyer: Address,
+    pub merchant: Address,
+    pub stars: u32,
+    pub comment_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RatingSummary {
+    pub count: u32,
+    pub sum: u32,
+    /// Trust adjustment currently applied for this average, so a new rating only adds the difference.
+    pub trust_bonus: i32,
+}
+
+fn rating_summary(env: &Env, merchant: &Address) -> RatingSummary {
+    storage::read(env, &DataKey::Rating(merchant.clone())).unwrap_or(RatingSummary { count: 0, sum: 0, trust_bonus: 0 })
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 18: REVIEWS ---
+
+    pub fn rate_merchant(env: Env, buyer: Address, order_id: u64, stars: u32, comment_hash: BytesN<32>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        buyer.require_auth();
+        touch_heartbeat(&env, &buyer);
+        if !(MIN_STARS..=MAX_STARS).contains(&stars) { return Err(Error::InvalidRating); }
+
+        let order = escrow::load_order(&env, order_id)?;
+        if order.buyer != buyer { return Err(Error::NotOrderParty); }
+        if order.state != OrderState::Released { return Err(Error::InvalidOrderState); }
+        let review_key = DataKey::Review(order_id);
+        if storage::has(&env, &review_key) { return Err(Error::AlreadyRated); }
+
+        let review = Review {
+            order_id,
+            buyer,
+            merchant: order.merchant.clone(),
+            stars,
+            comment_hash,
+            timestamp: env.ledger().timestamp(),
+        };
+        storage::write(&env, &review_key, &review);
+
+        let mut summary = rating_summary(&env, &order.merchant);
+        summary.count += 1;
+        summary.sum += stars;
+        // (average - 3) * 2, computed in tenths of a star to keep the integer rounding fair
+        let average_tenths = summary.sum as i64 * 10 / summary.count as i64;
+        let bonus = ((average_tenths - 30) * TRUST_POINTS_PER_STAR / 10) as i32;
+        let delta = bonus - summary.trust_bonus;
+        summary.trust_bonus = bonus;
+        storage::write(&env, &DataKey::Rating(order.merchant.clone()), &summary);
+
+        if delta != 0 {
+            let mut profile = load_merchant_for_update(&env, &order.merchant);
+            let before = profile.trust_score;
+            profile.trust_score = if delta > 0 {
+                (profile.trust_score + delta as u32).min(MAX_TRUST_SCORE)
+            } else {
+                profile.trust_score.saturating_sub(delta.unsigned_abs())
+            };
+            badges::check_and_award_badges(&env, &order.merchant, &mut profile);
+            write_merchant(&env, &order.merchant, &profile);
+            history::record(&env, &order.merchant, before, profile.trust_score, TrustReason::Rating, &review.buyer);
+            discount_if_collapsed(&env, &order.merchant, &profile);
+            env.events().publish((events::TRUST_CHANGED, order.merchant.clone()), profile.trust_score);
+        }
+        env.events().publish((events::MERCHANT_RATED, order.merchant), (order_id, stars));
+        Ok(())
+    }
+
+    pub fn get_review(env: Env, order_id: u64) -> Option<Review> {
+        storage::read(&env, &DataKey::Review(order_id))
+    }
+
+    pub fn get_rating(env: Env, merchant: Address) -> RatingSummary {
+        rating_summary(&env, &merchant)
+    }
+}
@@ -0,0 +1,108 @@
+// 🏛️ PROJECT BAZAAR | BZR REWARDS
+// Positive behaviour earns BZR: released escrows for the merchant, vouches received,
+// on-time paluwagan contributions and referral milestones. Rewards accrue into
+// `Merchant::pending_rewards` and are paid out of a pool anyone can fund, at per-action
+// rates the admin sets.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardRates {
+    pub per_order: i128,
+    pub per_vouch: i128,
+    pub per_contribution: i128,
+    /// Paid to both the referrer and the newcomer at each referral milestone.
+    pub per_referral: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RewardKind {
+    Order,
+    Vouch,
+    Contribution,
+    Referral,
+}
+
+pub(crate) fn reward_rates(env: &Env) -> RewardRates {
+    env.storage().instance().get(&DataKey::RewardRates).unwrap_or(RewardRates { per_order: 0, per_vouch: 0, per_contribution: 0, per_referral: 0 })
+}
+
+/// Credits the reward for `kind` to a merchant the caller is about to write back.
+pub(crate) fn accrue(env: &Env, user: &Address, merchant: &mut Merchant, kind: RewardKind) {
+    let rates = reward_rates(env);
+    let amount = match kind {
+        RewardKind::Order => rates.per_order,
+        RewardKind::Vouch => rates.per_vouch,
+        RewardKind::Contribution => rates.per_contribution,
+        RewardKind::Referral => rates.per_referral,
+    };
+    if amount <= 0 { return; }
+    merchant.pending_rewards += amount;
+    env.events().publish((events::REWARD_ACCRUED, user.clone()), (kind, amount));
+}
+
+fn reward_pool(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::RewardPool).unwrap_or(0)
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 21: REWARDS ---
+
+    pub fn set_reward_rates(env: Env, rates: RewardRates) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        if rates.per_order < 0 || rates.per_vouch < 0 || rates.per_contribution < 0 || rates.per_referral < 0 { return Err(Error::InvalidConfig); }
+        env.storage().instance().set(&DataKey::RewardRates, &rates);
+        env.events().publish((events::REWARD_RATES_SET, admin), (rates.per_order, rates.per_vouch, rates.per_contribution));
+        Ok(())
+    }
+
+    pub fn get_reward_rates(env: Env) -> RewardRates {
+        reward_rates(&env)
+    }
+
+    /// Tops up the BZR rewards pool. Anyone may fund it, typically the treasury or a sponsor.
+    pub fn fund_rewards(env: Env, funder: Address, amount: i128) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        funder.require_auth();
+        touch_heartbeat(&env, &funder);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&funder, &env.current_contract_address(), &amount);
+        let pool = reward_pool(&env) + amount;
+        env.storage().instance().set(&DataKey::RewardPool, &pool);
+        env.events().publish((events::REWARDS_FUNDED, funder), (amount, pool));
+        Ok(())
+    }
+
+    /// Pays out accrued rewards. A pool that can't cover everything pays what it holds and
+    /// the remainder stays accrued for a later claim.
+    pub fn claim_rewards(env: Env, user: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut merchant = load_merchant_for_update(&env, &user);
+        if merchant.pending_rewards <= 0 { return Err(Error::NothingToClaim); }
+        let pool = reward_pool(&env);
+        if pool <= 0 { return Err(Error::RewardPoolEmpty); }
+
+        let amount = merchant.pending_rewards.min(pool);
+        merchant.pending_rewards -= amount;
+        write_merchant(&env, &user, &merchant);
+        env.storage().instance().set(&DataKey::RewardPool, &(pool - amount));
+
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &user, &amount);
+        env.events().publish((events::REWARDS_CLAIMED, user), amount);
+        Ok(amount)
+    }
+
+    pub fn get_pending_rewards(env: Env, user: Address) -> i128 {
+        load_merchant(&env, &user).pending_rewards
+    }
+
+    pub fn get_reward_pool(env: Env) -> i128 {
+        reward_pool(&env)
+    }
+}
@@ -0,0 +1,63 @@
+// 🏛️ PROJECT BAZAAR | TOKEN ROUTING
+// Per-asset claim rules: an asset can go whole to one listed heir instead of being split
+// by shares, or be kept out of the inheritance entirely (e.g. a business operating
+// balance). Excluded assets stay in the vault when a claim finalizes.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenRoute {
+    Heir(Address),
+    Exclude,
+}
+
+pub(crate) fn token_routing(env: &Env, user: &Address, vault_id: u32) -> Map<Address, TokenRoute> {
+    storage::read(env, &DataKey::TokenRouting(user.clone(), vault_id)).unwrap_or(Map::new(env))
+}
+
+/// Recovery counterpart of the heir rewrite: routes that named `old` now name `new`.
+pub(crate) fn migrate_heir(env: &Env, owner: &Address, vault_id: u32, old: &Address, new: &Address) {
+    let mut routing = token_routing(env, owner, vault_id);
+    let mut changed = false;
+    for (token_id, route) in routing.iter() {
+        if route == TokenRoute::Heir(old.clone()) {
+            routing.set(token_id, TokenRoute::Heir(new.clone()));
+            changed = true;
+        }
+    }
+    if changed { storage::write(env, &DataKey::TokenRouting(owner.clone(), vault_id), &routing); }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 35: TOKEN ROUTING ---
+
+    /// Rules are per vault. `None` returns the asset to the normal split. A routed heir must
+    /// already be listed in that vault, so routing can't be used to slip a new beneficiary
+    /// past the heir-change delay; if they are later removed, the asset falls back to the split.
+    pub fn set_token_routing(env: Env, user: Address, vault_id: u32, token: Address, route: Option<TokenRoute>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        let vault = load_vault(&env, &user, vault_id)?;
+        if let Some(TokenRoute::Heir(heir)) = &route {
+            if !vault.heirs.iter().any(|share| share.address == *heir) { return Err(Error::NotHeir); }
+        }
+
+        let mut routing = token_routing(&env, &user, vault_id);
+        match &route {
+            Some(rule) => routing.set(token.clone(), rule.clone()),
+            None => { routing.remove(token.clone()); }
+        }
+        storage::write(&env, &DataKey::TokenRouting(user.clone(), vault_id), &routing);
+        env.events().publish((events::TOKEN_ROUTED, user, vault_id), (token, route));
+        Ok(())
+    }
+
+    pub fn get_token_routing(env: Env, user: Address, vault_id: u32) -> Map<Address, TokenRoute> {
+        token_routing(&env, &user, vault_id)
+    }
+}
@@ -0,0 +1,131 @@
+// 🏛️ PROJECT BAZAAR | SAVINGS GOALS
+// Personal savings kept apart from the Legacy Vault: deposits go in freely, but nothing
+// comes out until the target or the unlock date is reached. Breaking a goal early is
+// allowed for emergencies, at a fee paid to the treasury.
+
+use super::*;
+
+const EMERGENCY_BREAK_FEE_BPS: u32 = 500; // 5%
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SavingsGoal {
+    pub id: u64,
+    pub owner: Address,
+    pub token: Address,
+    pub target_amount: i128,
+    pub unlock_time: u64,
+    pub balance: i128,
+    pub is_closed: bool,
+}
+
+fn load_goal(env: &Env, id: u64) -> Result<SavingsGoal, Error> {
+    storage::read(env, &DataKey::SavingsGoal(id)).ok_or(Error::GoalNotFound)
+}
+
+fn load_owned_goal(env: &Env, owner: &Address, id: u64) -> Result<SavingsGoal, Error> {
+    let goal = load_goal(env, id)?;
+    if goal.owner != *owner { return Err(Error::NotGoalOwner); }
+    if goal.is_closed { return Err(Error::GoalClosed); }
+    Ok(goal)
+}
+
+/// Closes the goal and pays out its balance, less `fee` booked into the treasury.
+fn close_goal(env: &Env, goal: &mut SavingsGoal, fee: i128) -> i128 {
+    let payout = goal.balance - fee;
+    goal.balance = 0;
+    goal.is_closed = true;
+    storage::write(env, &DataKey::SavingsGoal(goal.id), goal);
+    treasury::credit(env, &goal.token, &goal.owner, fee);
+    if payout > 0 {
+        token::Client::new(env, &goal.token).transfer(&env.current_contract_address(), &goal.owner, &payout);
+    }
+    payout
+}
+
+/// Points the recovered owner's goals at the new address.
+pub(crate) fn migrate_owner(env: &Env, old: &Address, new: &Address) {
+    let ids: Vec<u64> = storage::read(env, &DataKey::GoalsOf(new.clone())).unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        let Ok(mut goal) = load_goal(env, id) else { continue; };
+        if goal.owner != *old { continue; }
+        goal.owner = new.clone();
+        storage::write(env, &DataKey::SavingsGoal(id), &goal);
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 27: SAVINGS GOALS ---
+
+    pub fn create_goal(env: Env, user: Address, token: Address, target_amount: i128, unlock_time: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        if target_amount <= 0 { return Err(Error::InvalidAmount); }
+        if unlock_time <= env.ledger().timestamp() { return Err(Error::InvalidGoal); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::GoalCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::GoalCount, &id);
+
+        let goal = SavingsGoal { id, owner: user.clone(), token, target_amount, unlock_time, balance: 0, is_closed: false };
+        storage::write(&env, &DataKey::SavingsGoal(id), &goal);
+        storage::push_unique(&env, &DataKey::GoalsOf(user.clone()), id);
+        env.events().publish((events::GOAL_CREATED, user), (id, target_amount, unlock_time));
+        Ok(id)
+    }
+
+    /// Reaching the target for the first time earns the savings badge.
+    pub fn deposit_goal(env: Env, user: Address, id: u64, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut goal = load_owned_goal(&env, &user, id)?;
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        token::Client::new(&env, &goal.token).transfer(&user, &env.current_contract_address(), &amount);
+        let was_short = goal.balance < goal.target_amount;
+        goal.balance += amount;
+        storage::write(&env, &DataKey::SavingsGoal(id), &goal);
+        env.events().publish((events::GOAL_DEPOSIT, user.clone()), (id, amount, goal.balance));
+
+        if was_short && goal.balance >= goal.target_amount {
+            let mut merchant = load_merchant_for_update(&env, &user);
+            badges::award(&env, &user, &mut merchant, BADGE_GOAL_MET);
+            write_merchant(&env, &user, &merchant);
+            env.events().publish((events::GOAL_REACHED, user), id);
+        }
+        Ok(goal.balance)
+    }
+
+    /// Pays out the whole goal once the target is met or the unlock date has passed.
+    pub fn withdraw_goal(env: Env, user: Address, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut goal = load_owned_goal(&env, &user, id)?;
+        if goal.balance < goal.target_amount && env.ledger().timestamp() < goal.unlock_time { return Err(Error::GoalLocked); }
+
+        let payout = close_goal(&env, &mut goal, 0);
+        env.events().publish((events::GOAL_WITHDRAWN, user), (id, payout));
+        Ok(payout)
+    }
+
+    /// Emergency exit before the goal unlocks; 5% of the balance goes to the treasury.
+    pub fn break_goal(env: Env, user: Address, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut goal = load_owned_goal(&env, &user, id)?;
+
+        let fee = treasury::fee_on(goal.balance, EMERGENCY_BREAK_FEE_BPS);
+        let payout = close_goal(&env, &mut goal, fee);
+        env.events().publish((events::GOAL_BROKEN, user), (id, payout, fee));
+        Ok(payout)
+    }
+
+    pub fn get_goal(env: Env, id: u64) -> Result<SavingsGoal, Error> {
+        load_goal(&env, id)
+    }
+}
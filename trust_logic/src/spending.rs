@@ -0,0 +1,175 @@
+// 🏛️ PROJECT BAZAAR | SPENDING POLICY
+// Large withdrawals need the circle too, so even a live owner's stolen key can't empty
+// the vault in one go. Above the owner's per-asset threshold a withdrawal becomes a
+// request that two witnesses must approve before it expires. Direct withdrawals add up
+// over a rolling 24-hour window, so splitting a large amount into small ones doesn't help.
+
+use super::*;
+
+const REQUIRED_APPROVALS: u32 = 2;
+const WITHDRAWAL_REQUEST_TTL_SECONDS: u64 = 604_800; // 7 Days
+const SPENDING_WINDOW_SECONDS: u64 = 86_400; // 24 Hours
+
+#[contracttype]
+#[derive(Clone)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    pub owner: Address,
+    pub vault_id: u32,
+    pub token: Address,
+    pub amount: i128,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+fn spending_limits(env: &Env, user: &Address) -> Map<Address, i128> {
+    storage::read(env, &DataKey::SpendingLimits(user.clone())).unwrap_or(Map::new(env))
+}
+
+fn load_request(env: &Env, id: u64) -> Result<WithdrawalRequest, Error> {
+    storage::read(env, &DataKey::WithdrawalRequest(id)).ok_or(Error::WithdrawalNotFound)
+}
+
+/// Approvals from witnesses still active in the owner's circle; one removed since
+/// approving no longer counts toward `REQUIRED_APPROVALS`.
+fn live_approvals(env: &Env, request: &WithdrawalRequest) -> u32 {
+    let active = active_witnesses(env, &request.owner);
+    request.approvals.iter().filter(|approver| active.contains(approver)).count() as u32
+}
+
+/// `(window_start, spent)` for the current window; a lapsed window counts as empty.
+fn spent_in_window(env: &Env, user: &Address, token: &Address) -> (u64, i128) {
+    let now = env.ledger().timestamp();
+    match storage::read::<(u64, i128)>(env, &DataKey::SpendingUsed(user.clone(), token.clone())) {
+        Some((started_at, spent)) if now < started_at + SPENDING_WINDOW_SECONDS => (started_at, spent),
+        _ => (now, 0),
+    }
+}
+
+/// Direct withdrawals in the current window, this one included, must stay at or below the
+/// owner's threshold for that asset.
+pub(crate) fn require_within_limit(env: &Env, user: &Address, token: &Address, amount: i128) -> Result<(), Error> {
+    let Some(threshold) = spending_limits(env, user).get(token.clone()) else { return Ok(()) };
+    let (_, spent) = spent_in_window(env, user, token);
+    if spent.saturating_add(amount) > threshold { return Err(Error::ApprovalRequired); }
+    Ok(())
+}
+
+/// Counts a direct withdrawal against the window; assets without a limit aren't tracked.
+pub(crate) fn record_spend(env: &Env, user: &Address, token: &Address, amount: i128) {
+    if !spending_limits(env, user).contains_key(token.clone()) { return; }
+    let (started_at, spent) = spent_in_window(env, user, token);
+    storage::write(env, &DataKey::SpendingUsed(user.clone(), token.clone()), &(started_at, spent + amount));
+}
+
+pub(crate) fn extend_windows(env: &Env, user: &Address) {
+    for token in spending_limits(env, user).keys().iter() {
+        storage::extend(env, &DataKey::SpendingUsed(user.clone(), token));
+    }
+}
+
+/// Runs before `SpendingLimits` itself is renamed, while the old limits still list the assets.
+pub(crate) fn move_windows(env: &Env, old: &Address, new: &Address) {
+    for token in spending_limits(env, old).keys().iter() {
+        storage::rename(env, &DataKey::SpendingUsed(old.clone(), token.clone()), &DataKey::SpendingUsed(new.clone(), token));
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 33: SPENDING POLICY ---
+
+    /// `None` removes the limit. Tightening is free; raising or removing a limit needs an
+    /// active witness to co-sign, or a stolen key could simply switch the policy off.
+    pub fn set_spending_limit(env: Env, user: Address, token: Address, threshold: Option<i128>, cosigner: Option<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if threshold.is_some_and(|limit| limit < 0) { return Err(Error::InvalidAmount); }
+        if threshold.is_some() && active_witnesses(&env, &user).len() < REQUIRED_APPROVALS { return Err(Error::NoCircle); }
+
+        let mut limits = spending_limits(&env, &user);
+        let loosened = match (limits.get(token.clone()), threshold) {
+            (Some(current), Some(limit)) => limit > current,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if loosened {
+            let cosigner = cosigner.ok_or(Error::CosignRequired)?;
+            cosigner.require_auth();
+            require_witness(&env, &cosigner, &user)?;
+        }
+
+        match threshold {
+            Some(limit) => limits.set(token.clone(), limit),
+            None => {
+                limits.remove(token.clone());
+                storage::remove(&env, &DataKey::SpendingUsed(user.clone(), token.clone()));
+            }
+        }
+        storage::write(&env, &DataKey::SpendingLimits(user.clone()), &limits);
+        env.events().publish((events::SPENDING_LIMIT_SET, user), (token, threshold));
+        Ok(())
+    }
+
+    pub fn get_spending_limit(env: Env, user: Address, token: Address) -> Option<i128> {
+        spending_limits(&env, &user).get(token)
+    }
+
+    pub fn request_withdrawal(env: Env, user: Address, vault_id: u32, token: Address, amount: i128) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        load_vault(&env, &user, vault_id)?;
+
+        let id: u64 = env.storage().instance().get(&DataKey::WithdrawalCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::WithdrawalCount, &id);
+
+        let expires_at = env.ledger().timestamp() + WITHDRAWAL_REQUEST_TTL_SECONDS;
+        let request = WithdrawalRequest { id, owner: user.clone(), vault_id, token: token.clone(), amount, approvals: Vec::new(&env), expires_at, executed: false };
+        storage::write(&env, &DataKey::WithdrawalRequest(id), &request);
+        env.events().publish((events::WITHDRAWAL_REQUESTED, user, vault_id), (id, token, amount, expires_at));
+        Ok(id)
+    }
+
+    pub fn approve_withdrawal(env: Env, witness: Address, request_id: u64) -> Result<u32, Error> {
+        require_not_paused(&env)?;
+        witness.require_auth();
+        touch_heartbeat(&env, &witness);
+        let mut request = load_request(&env, request_id)?;
+        require_witness(&env, &witness, &request.owner)?;
+        if request.executed { return Err(Error::WithdrawalExecuted); }
+        if env.ledger().timestamp() >= request.expires_at { return Err(Error::WithdrawalExpired); }
+        if request.approvals.contains(&witness) { return Err(Error::AlreadyVoted); }
+
+        request.approvals.push_back(witness.clone());
+        storage::write(&env, &DataKey::WithdrawalRequest(request_id), &request);
+        let approvals = live_approvals(&env, &request);
+        env.events().publish((events::WITHDRAWAL_APPROVED, request.owner), (request_id, witness, approvals));
+        Ok(approvals)
+    }
+
+    pub fn execute_withdrawal(env: Env, user: Address, request_id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        let mut request = load_request(&env, request_id)?;
+        if request.owner != user { return Err(Error::WithdrawalNotFound); }
+        if request.executed { return Err(Error::WithdrawalExecuted); }
+        if env.ledger().timestamp() >= request.expires_at { return Err(Error::WithdrawalExpired); }
+        if live_approvals(&env, &request) < REQUIRED_APPROVALS { return Err(Error::ApprovalRequired); }
+
+        request.executed = true;
+        storage::write(&env, &DataKey::WithdrawalRequest(request_id), &request);
+        withdraw_from_vault(&env, &user, request.vault_id, &request.token, request.amount)
+    }
+
+    pub fn get_withdrawal_request(env: Env, request_id: u64) -> Result<WithdrawalRequest, Error> {
+        load_request(&env, request_id)
+    }
+}
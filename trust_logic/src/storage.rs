@@ -0,0 +1,82 @@
+// 🏛️ PROJECT BAZAAR | STORAGE & TTL
+// Persistent entries are archived once their TTL runs out, which on mainnet would make a
+// vault silently disappear. Every read and write goes through these helpers so live
+// entries keep getting extended, and bump_storage lets anyone top them up.
+// Layout: protocol-wide config and counters sit in instance storage; per-user records are
+// persistent and kept small on hot paths (the inbox and the trust score have keys of
+// their own, apart from the merchant record).
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+use crate::DataKey;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17_280; // ~5s per ledger
+pub(crate) const PERSISTENT_TTL_THRESHOLD: u32 = 60 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_TTL_EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_TTL_EXTEND_TO: u32 = 60 * DAY_IN_LEDGERS;
+
+pub(crate) fn read<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() { extend_live(env, key); }
+    value
+}
+
+pub(crate) fn write<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_live(env, key);
+    extend_instance(env);
+}
+
+pub(crate) fn has(env: &Env, key: &DataKey) -> bool {
+    env.storage().persistent().has(key)
+}
+
+pub(crate) fn remove(env: &Env, key: &DataKey) {
+    env.storage().persistent().remove(key);
+}
+
+/// Extends `key` if it exists; missing keys are left alone rather than trapping.
+pub(crate) fn extend(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) { extend_live(env, key); }
+}
+
+fn extend_live(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage().instance().extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+}
+
+/// Re-keys an entry without decoding it. Returns false if `from` did not exist.
+pub(crate) fn rename(env: &Env, from: &DataKey, to: &DataKey) -> bool {
+    let Some(value) = env.storage().persistent().get::<DataKey, Val>(from) else { return false; };
+    env.storage().persistent().remove(from);
+    write(env, to, &value);
+    true
+}
+
+/// Adds `item` to the list under `key` once. Backs the per-user indexes of id-keyed
+/// records, which recovery follows to rewrite the address stored inside them.
+pub(crate) fn push_unique<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, key: &DataKey, item: V) {
+    let mut items: Vec<V> = read(env, key).unwrap_or(Vec::new(env));
+    if items.contains(&item) { return; }
+    items.push_back(item);
+    write(env, key, &items);
+}
+
+/// Drops `item` from the list under `key`, and the list itself once empty.
+pub(crate) fn remove_item<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, key: &DataKey, item: &V) {
+    let Some(mut items) = read::<Vec<V>>(env, key) else { return; };
+    let Some(i) = items.first_index_of(item) else { return; };
+    items.remove(i);
+    if items.is_empty() { remove(env, key); } else { write(env, key, &items); }
+}
+
+pub(crate) fn replace_item<V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, key: &DataKey, old: &V, new: V) {
+    let Some(mut items) = read::<Vec<V>>(env, key) else { return; };
+    let Some(i) = items.first_index_of(old) else { return; };
+    items.set(i, new);
+    write(env, key, &items);
+}
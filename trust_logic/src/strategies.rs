@@ -0,0 +1,136 @@
+// 🏛️ PROJECT BAZAAR | YIELD STRATEGIES
+// Idle vault balances can be parked in an admin-approved yield protocol. The vault only
+// remembers the shares it holds; before a claim or a medical release touches an asset,
+// its position is pulled back so heirs and hospitals are paid on principal + yield.
+
+use super::*;
+use soroban_sdk::contractclient;
+
+/// What an external protocol must implement to be approved as a strategy.
+#[contractclient(name = "YieldStrategyClient")]
+pub trait YieldStrategy {
+    /// Called after `amount` of `token` was sent to the strategy. Returns the shares minted to `from`.
+    fn deposit(env: Env, from: Address, token: Address, amount: i128) -> i128;
+    /// Burns `shares` and sends the underlying to `to`. Returns the amount sent.
+    fn withdraw(env: Env, to: Address, token: Address, shares: i128) -> i128;
+    fn value_of(env: Env, token: Address, shares: i128) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct StrategyPosition {
+    pub strategy: Address,
+    pub shares: i128,
+    pub principal: i128,
+}
+
+fn load_positions(env: &Env, user: &Address, vault_id: u32) -> Map<Address, StrategyPosition> {
+    storage::read(env, &DataKey::StrategyPositions(user.clone(), vault_id)).unwrap_or(Map::new(env))
+}
+
+fn write_positions(env: &Env, user: &Address, vault_id: u32, positions: &Map<Address, StrategyPosition>) {
+    let key = DataKey::StrategyPositions(user.clone(), vault_id);
+    if positions.is_empty() { storage::remove(env, &key); } else { storage::write(env, &key, positions); }
+}
+
+/// Principal parked per asset, the best on-chain estimate of a position short of asking the strategy.
+pub(crate) fn parked_principal(env: &Env, user: &Address, vault_id: u32) -> Map<Address, i128> {
+    let mut parked = Map::new(env);
+    for (token, position) in load_positions(env, user, vault_id).iter() {
+        parked.set(token, position.principal);
+    }
+    parked
+}
+
+/// Pulls the `token` position back into the in-memory vault, yield included, and returns
+/// what came back. The caller writes the vault.
+pub(crate) fn unwind(env: &Env, user: &Address, vault_id: u32, vault: &mut LegacyVault, token: &Address) -> i128 {
+    let mut positions = load_positions(env, user, vault_id);
+    let Some(position) = positions.get(token.clone()) else { return 0; };
+    positions.remove(token.clone());
+    write_positions(env, user, vault_id, &positions);
+
+    let returned = YieldStrategyClient::new(env, &position.strategy).withdraw(&env.current_contract_address(), token, &position.shares);
+    vault.balances.set(token.clone(), vault.balances.get(token.clone()).unwrap_or(0) + returned);
+    env.events().publish((events::STRATEGY_WITHDRAW, user.clone(), vault_id), (position.strategy, token.clone(), returned));
+    returned
+}
+
+pub(crate) fn unwind_all(env: &Env, user: &Address, vault_id: u32, vault: &mut LegacyVault) {
+    for token in load_positions(env, user, vault_id).keys() {
+        unwind(env, user, vault_id, vault, &token);
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 30: YIELD STRATEGIES ---
+
+    pub fn set_strategy(env: Env, strategy: Address, approved: bool) -> Result<(), Error> {
+        admin::require_admin(&env)?;
+        let key = DataKey::Strategy(strategy.clone());
+        if approved { storage::write(&env, &key, &true); } else { storage::remove(&env, &key); }
+        env.events().publish((events::STRATEGY_SET, strategy), approved);
+        Ok(())
+    }
+
+    pub fn is_strategy_approved(env: Env, strategy: Address) -> bool {
+        storage::has(&env, &DataKey::Strategy(strategy))
+    }
+
+    /// Each asset sits in at most one strategy; top-ups must use the same one.
+    pub fn deposit_to_strategy(env: Env, user: Address, vault_id: u32, token: Address, strategy: Address, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if !storage::has(&env, &DataKey::Strategy(strategy.clone())) { return Err(Error::StrategyNotApproved); }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        let balance = vault.balances.get(token.clone()).unwrap_or(0);
+        if amount > balance { return Err(Error::InsufficientBalance); }
+        let mut positions = load_positions(&env, &user, vault_id);
+        let mut position = positions.get(token.clone()).unwrap_or(StrategyPosition { strategy: strategy.clone(), shares: 0, principal: 0 });
+        if position.strategy != strategy { return Err(Error::StrategyMismatch); }
+
+        if balance == amount { vault.balances.remove(token.clone()); } else { vault.balances.set(token.clone(), balance - amount); }
+        write_vault(&env, &user, vault_id, &vault);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &strategy, &amount);
+        let shares = YieldStrategyClient::new(&env, &strategy).deposit(&env.current_contract_address(), &token, &amount);
+
+        position.shares += shares;
+        position.principal += amount;
+        positions.set(token.clone(), position);
+        write_positions(&env, &user, vault_id, &positions);
+        env.events().publish((events::STRATEGY_DEPOSIT, user, vault_id), (strategy, token, amount));
+        Ok(shares)
+    }
+
+    /// Brings the whole position home, even from a strategy the admin has since removed.
+    pub fn withdraw_from_strategy(env: Env, user: Address, vault_id: u32, token: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if !load_positions(&env, &user, vault_id).contains_key(token.clone()) { return Err(Error::NoStrategyPosition); }
+
+        let mut vault = load_vault(&env, &user, vault_id)?;
+        let returned = unwind(&env, &user, vault_id, &mut vault, &token);
+        write_vault(&env, &user, vault_id, &vault);
+        Ok(returned)
+    }
+
+    pub fn get_strategy_position(env: Env, user: Address, vault_id: u32, token: Address) -> Option<StrategyPosition> {
+        load_positions(&env, &user, vault_id).get(token)
+    }
+
+    /// Current worth of the position as quoted by the strategy.
+    pub fn get_strategy_value(env: Env, user: Address, vault_id: u32, token: Address) -> i128 {
+        match load_positions(&env, &user, vault_id).get(token.clone()) {
+            Some(position) => YieldStrategyClient::new(&env, &position.strategy).value_of(&token, &position.shares),
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// 🏛️ PROJECT BAZAAR | SUBSCRIPTIONS
+// Recurring payments for rent, tuition support and vendor retainers. The payer tops up
+// a balance held by the contract and anyone can collect one installment per interval;
+// cancelling stops the schedule and returns whatever is left.
+
+use super::*;
+
+const MIN_INTERVAL_SECONDS: u64 = 86_400; // 1 Day
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Subscription {
+    pub id: u64,
+    pub payer: Address,
+    pub payee: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub interval_seconds: u64,
+    /// The next installment may be collected from this time on.
+    pub next_due: u64,
+    pub balance: i128,
+    pub is_active: bool,
+}
+
+fn load_subscription(env: &Env, id: u64) -> Result<Subscription, Error> {
+    storage::read(env, &DataKey::Subscription(id)).ok_or(Error::SubscriptionNotFound)
+}
+
+/// Rewrites the recovered address on whichever side of each subscription it is.
+pub(crate) fn migrate_party(env: &Env, old: &Address, new: &Address) {
+    let ids: Vec<u64> = storage::read(env, &DataKey::SubscriptionsOf(new.clone())).unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        let Ok(mut subscription) = load_subscription(env, id) else { continue; };
+        if subscription.payer == *old { subscription.payer = new.clone(); }
+        if subscription.payee == *old { subscription.payee = new.clone(); }
+        storage::write(env, &DataKey::Subscription(id), &subscription);
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 26: SUBSCRIPTIONS ---
+
+    /// The first installment is due right away, so rent-style schedules are paid in advance.
+    pub fn create_subscription(env: Env, payer: Address, payee: Address, token: Address, amount: i128, interval_seconds: u64) -> Result<u64, Error> {
+        require_not_paused(&env)?;
+        payer.require_auth();
+        touch_heartbeat(&env, &payer);
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        if payer == payee || interval_seconds < MIN_INTERVAL_SECONDS { return Err(Error::InvalidSubscription); }
+
+        let id: u64 = env.storage().instance().get(&DataKey::SubscriptionCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::SubscriptionCount, &id);
+
+        let subscription = Subscription {
+            id,
+            payer: payer.clone(),
+            payee: payee.clone(),
+            token,
+            amount,
+            interval_seconds,
+            next_due: env.ledger().timestamp(),
+            balance: 0,
+            is_active: true,
+        };
+        storage::write(&env, &DataKey::Subscription(id), &subscription);
+        storage::push_unique(&env, &DataKey::SubscriptionsOf(payer.clone()), id);
+        storage::push_unique(&env, &DataKey::SubscriptionsOf(payee.clone()), id);
+        env.events().publish((events::SUBSCRIPTION_CREATED, payer), (id, payee, amount, interval_seconds));
+        Ok(id)
+    }
+
+    pub fn fund_subscription(env: Env, payer: Address, id: u64, amount: i128) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        payer.require_auth();
+        touch_heartbeat(&env, &payer);
+        let mut subscription = load_subscription(&env, id)?;
+        if subscription.payer != payer { return Err(Error::NotSubscriptionPayer); }
+        if !subscription.is_active { return Err(Error::SubscriptionInactive); }
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+
+        token::Client::new(&env, &subscription.token).transfer(&payer, &env.current_contract_address(), &amount);
+        subscription.balance += amount;
+        storage::write(&env, &DataKey::Subscription(id), &subscription);
+        env.events().publish((events::SUBSCRIPTION_FUNDED, payer), (id, amount));
+        Ok(subscription.balance)
+    }
+
+    /// Permissionless: pays one installment to the payee once it is due. Installments
+    /// missed while the balance was short stay owed and can be collected back to back.
+    pub fn collect(env: Env, id: u64) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        let mut subscription = load_subscription(&env, id)?;
+        if !subscription.is_active { return Err(Error::SubscriptionInactive); }
+        if env.ledger().timestamp() < subscription.next_due { return Err(Error::PaymentNotDue); }
+        if subscription.balance < subscription.amount { return Err(Error::InsufficientBalance); }
+
+        subscription.balance -= subscription.amount;
+        subscription.next_due = subscription.next_due.saturating_add(subscription.interval_seconds);
+        storage::write(&env, &DataKey::Subscription(id), &subscription);
+        token::Client::new(&env, &subscription.token).transfer(&env.current_contract_address(), &subscription.payee, &subscription.amount);
+        env.events().publish((events::SUBSCRIPTION_COLLECTED, subscription.payee), (id, subscription.amount, subscription.next_due));
+        Ok(())
+    }
+
+    /// Stops the schedule and refunds the unspent balance to the payer.
+    pub fn cancel_subscription(env: Env, payer: Address, id: u64) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        payer.require_auth();
+        touch_heartbeat(&env, &payer);
+        let mut subscription = load_subscription(&env, id)?;
+        if subscription.payer != payer { return Err(Error::NotSubscriptionPayer); }
+        if !subscription.is_active { return Err(Error::SubscriptionInactive); }
+
+        let refund = subscription.balance;
+        subscription.balance = 0;
+        subscription.is_active = false;
+        storage::write(&env, &DataKey::Subscription(id), &subscription);
+        if refund > 0 {
+            token::Client::new(&env, &subscription.token).transfer(&env.current_contract_address(), &payer, &refund);
+        }
+        env.events().publish((events::SUBSCRIPTION_CANCELLED, payer), (id, refund));
+        Ok(refund)
+    }
+
+    pub fn get_subscription(env: Env, id: u64) -> Result<Subscription, Error> {
+        load_subscription(&env, id)
+    }
+}
@@ -0,0 +1,58 @@
+// 🏛️ PROJECT BAZAAR | ACCOUNT SUMMARY
+// Everything the dashboard shows, in one simulated call instead of five: merchant stats,
+// vault state and balances, an open emergency, and the circles the user sits on.
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AccountSummary {
+    pub merchant: Merchant,
+    pub tier: TrustTier,
+    /// One view per vault in id order; empty when the user has no vault.
+    pub vaults: Vec<VaultView>,
+    pub vault_ids: Vec<u32>,
+    /// Same order as `vaults`.
+    pub vault_states: Vec<VaultState>,
+    /// Balances of the first vault.
+    pub balances: Map<Address, i128>,
+    /// The open emergency, if any. A list rather than an `Option`, which the SDK can't nest
+    /// inside a contract type.
+    pub emergency: Vec<EmergencyStatus>,
+    pub witnesses: Vec<Witness>,
+    pub circles_i_protect: Vec<Address>,
+    pub vaults_naming_me: Vec<Address>,
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 31: ACCOUNT SUMMARY ---
+
+    pub fn get_account_summary(env: Env, user: Address) -> AccountSummary {
+        let merchant = load_merchant(&env, &user);
+        let ids = vault_ids(&env, &user);
+        let mut vaults = Vec::new(&env);
+        let mut vault_states = Vec::new(&env);
+        for id in ids.iter() {
+            let Ok(view) = Self::get_vault_view(env.clone(), user.clone(), id) else { continue; };
+            vault_states.push_back(vault_state(&env, &view.vault));
+            vaults.push_back(view);
+        }
+        let balances = vaults.first().map(|view| view.vault.balances).unwrap_or(Map::new(&env));
+        let mut emergency = Vec::new(&env);
+        if let Ok(status) = Self::get_emergency_status(env.clone(), user.clone()) { emergency.push_back(status); }
+        AccountSummary {
+            tier: tier_of(merchant.trust_score),
+            merchant,
+            vaults,
+            vault_ids: ids,
+            vault_states,
+            balances,
+            emergency,
+            witnesses: load_circle(&env, &user).unwrap_or(Vec::new(&env)),
+            circles_i_protect: storage::read(&env, &DataKey::WitnessFor(user.clone())).unwrap_or(Vec::new(&env)),
+            vaults_naming_me: storage::read(&env, &DataKey::NamedHeirIn(user)).unwrap_or(Vec::new(&env)),
+        }
+    }
+}
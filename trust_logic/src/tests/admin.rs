@@ -0,0 +1,101 @@
+// 🏛️ PROJECT BAZAAR | ADMIN TESTS
+// One-time initialization, the admin key, the circuit breaker and contract upgrades.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+use soroban_sdk::{Bytes, BytesN, IntoVal};
+
+fn config(bond_amount: i128, slash_bps: u32) -> ProtocolConfig {
+    ProtocolConfig { bond_amount, slash_bps, juror_stake: 100, juror_reward_bps: 500, listing_value_cap: 1_000_000 }
+}
+
+#[test]
+fn the_admin_signs_config_changes_and_hands_over_the_key() {
+    let t = TestBazaar::default();
+    assert_eq!(t.client.get_admin(), t.admin);
+    assert_eq!(t.client.get_bzr_token(), t.bzr);
+
+    t.client.set_config(&config(250, 2_000));
+    assert_eq!(t.env.auths().first().unwrap().0, t.admin);
+    assert_eq!(t.client.get_config().bond_amount, 250);
+
+    let successor = t.user();
+    t.client.transfer_admin(&successor);
+    assert_eq!(t.client.get_admin(), successor);
+    t.client.set_config(&config(100, 1_000));
+    assert_eq!(t.env.auths().first().unwrap().0, successor);
+}
+
+#[test]
+fn initialization_happens_once_with_a_sane_config() {
+    let t = TestBazaar::default();
+    assert_eq!(t.client.try_initialize(&t.admin, &t.bzr, &config(100, 1_000)), Err(Ok(Error::AlreadyInitialized)));
+    assert_eq!(t.client.try_set_config(&config(-1, 1_000)), Err(Ok(Error::InvalidConfig)));
+    assert_eq!(t.client.try_set_config(&config(100, 10_001)), Err(Ok(Error::InvalidConfig)));
+
+    let fresh = TrustContractClient::new(&t.env, &t.env.register(TrustContract, (t.admin.clone(),)));
+    assert_eq!(fresh.try_get_admin().err(), Some(Ok(Error::NotInitialized)));
+    assert_eq!(fresh.try_pause(), Err(Ok(Error::NotInitialized)));
+    let admin = t.user();
+    assert_eq!(fresh.try_initialize(&admin, &t.bzr, &config(100, 10_001)), Err(Ok(Error::InvalidConfig)));
+}
+
+#[test]
+fn only_the_deployer_can_initialize() {
+    let t = TestBazaar::default();
+    let (deployer, stranger) = (t.user(), t.user());
+    let fresh = TrustContractClient::new(&t.env, &t.env.register(TrustContract, (deployer.clone(),)));
+
+    // A stranger racing the deployer to `initialize` can't pick the admin or the BZR token
+    let args = (stranger.clone(), t.bzr.clone(), config(100, 1_000)).into_val(&t.env);
+    let invoke = MockAuthInvoke { contract: &fresh.address, fn_name: "initialize", args, sub_invokes: &[] };
+    let front_run = fresh.mock_auths(&[MockAuth { address: &stranger, invoke: &invoke }]).try_initialize(&stranger, &t.bzr, &config(100, 1_000));
+    assert!(front_run.is_err());
+    assert_eq!(fresh.try_get_admin().err(), Some(Ok(Error::NotInitialized)));
+
+    let admin = t.user();
+    fresh.initialize(&admin, &t.bzr, &config(100, 1_000));
+    assert_eq!(fresh.get_admin(), admin);
+}
+
+/// Smallest module the host accepts: just the env-meta section naming protocol 22.
+const EMPTY_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x11, b'c', b'o', b'n', b't', b'r', b'a', b'c', b't',
+    b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v', b'0', 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0,
+];
+
+#[test]
+fn the_admin_swaps_in_uploaded_code() {
+    let t = TestBazaar::default();
+    let wasm = t.env.deployer().upload_contract_wasm(Bytes::from_slice(&t.env, EMPTY_WASM));
+    t.client.upgrade(&wasm);
+    assert_eq!(t.env.auths().first().unwrap().0, t.admin);
+}
+
+#[test]
+fn upgrades_refuse_code_that_was_never_uploaded() {
+    let t = TestBazaar::default();
+    assert!(t.client.try_upgrade(&BytesN::from_array(&t.env, &[7; 32])).is_err());
+    assert_eq!(t.client.version(), String::from_str(&t.env, "3.1.0"));
+}
+
+#[test]
+fn the_circuit_breaker_halts_writes_until_unpaused() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.client.pause();
+    assert_eq!(t.env.auths().first().unwrap().0, t.admin);
+    assert!(t.client.is_paused());
+
+    assert_eq!(t.client.try_ping_heartbeat(&owner), Err(Ok(Error::Paused)));
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &100), Err(Ok(Error::Paused)));
+    assert_eq!(t.client.try_create_vault(&t.user(), &t.user()), Err(Ok(Error::Paused)));
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 1_000);
+
+    t.client.unpause();
+    assert!(!t.client.is_paused());
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &100);
+    assert_eq!(t.balance(&t.token, &owner), 100);
+}
@@ -0,0 +1,61 @@
+// 🏛️ PROJECT BAZAAR | ARBITER REGISTRY TESTS
+// Staked arbiters drawn for a dispute, their ruling statistics and the cooldown on leaving.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+fn registered_arbiter(t: &TestBazaar) -> Address {
+    let arbiter = t.user();
+    t.mint(&t.bzr, &arbiter, 100);
+    t.client.register_arbiter(&arbiter, &100);
+    arbiter
+}
+
+#[test]
+fn an_assigned_arbiter_rules_and_gets_the_stake_back_after_the_cooldown() {
+    let t = TestBazaar::default();
+    let arbiter = registered_arbiter(&t);
+    let (merchant, buyer) = (t.bonded_user(0), t.user());
+    let order = t.funded_order(&merchant, &buyer, 500);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+
+    assert_eq!(t.client.assign_arbiter(&merchant, &dispute), arbiter);
+    assert_eq!(t.client.get_arbiter(&arbiter).active_cases, 1);
+    t.client.resolve_dispute(&arbiter, &dispute, &true);
+    t.client.mark_overturned(&dispute);
+    let record = t.client.get_arbiter(&arbiter);
+    assert_eq!((record.active_cases, record.cases, record.overturned), (0, 1, 1));
+
+    t.client.deregister_arbiter(&arbiter);
+    assert!(t.client.get_arbiter_registry().is_empty());
+    t.advance_days(14);
+    assert_eq!(t.client.withdraw_arbiter_stake(&arbiter), 100);
+    assert_eq!(t.balance(&t.bzr, &arbiter), 100);
+}
+
+#[test]
+fn arbiters_stake_a_bond_and_only_the_assigned_one_rules() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 500);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+    assert_eq!(t.client.try_assign_arbiter(&buyer, &dispute), Err(Ok(Error::NoArbiters)));
+
+    let cheap = t.user();
+    t.mint(&t.bzr, &cheap, 100);
+    assert_eq!(t.client.try_register_arbiter(&cheap, &99), Err(Ok(Error::ArbiterStakeTooLow)));
+    let arbiter = registered_arbiter(&t);
+    assert_eq!(t.client.try_register_arbiter(&arbiter, &100), Err(Ok(Error::ArbiterAlreadyRegistered)));
+
+    t.client.assign_arbiter(&buyer, &dispute);
+    assert_eq!(t.client.try_assign_arbiter(&merchant, &dispute), Err(Ok(Error::ArbiterAlreadyAssigned)));
+    let appointed = t.user();
+    t.client.add_arbiter(&appointed);
+    assert_eq!(t.client.try_resolve_dispute(&appointed, &dispute, &true), Err(Ok(Error::NotAssignedArbiter)));
+
+    // Leaving does not free the stake while a case is still open
+    t.client.deregister_arbiter(&arbiter);
+    assert_eq!(t.client.try_withdraw_arbiter_stake(&arbiter), Err(Ok(Error::ArbiterCooldown)));
+    t.advance_days(14);
+    assert_eq!(t.client.try_withdraw_arbiter_stake(&arbiter), Err(Ok(Error::ArbiterBusy)));
+}
@@ -0,0 +1,74 @@
+// 🏛️ PROJECT BAZAAR | ARBITRATION TESTS
+// Juror registration, panel draws and majority rulings on escalated disputes.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn register_jurors(t: &TestBazaar, count: u32) -> Vec<Address> {
+    let mut jurors = Vec::new(&t.env);
+    for _ in 0..count {
+        let juror = t.user();
+        t.set_trust(&juror, 60);
+        t.mint(&t.bzr, &juror, 100);
+        t.client.register_juror(&juror);
+        jurors.push_back(juror);
+    }
+    jurors
+}
+
+#[test]
+fn a_panel_majority_settles_the_dispute_and_pays_its_jurors() {
+    let t = TestBazaar::default();
+    register_jurors(&t, 3);
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+
+    let panel = t.client.escalate_dispute(&buyer, &dispute);
+    let (first, second, third) = (panel.get_unchecked(0), panel.get_unchecked(1), panel.get_unchecked(2));
+    t.client.juror_vote(&first, &dispute, &true);
+    assert_eq!(t.client.try_finalize_panel(&dispute), Err(Ok(Error::VotingOpen)));
+    t.client.juror_vote(&second, &dispute, &true);
+    t.client.juror_vote(&third, &dispute, &false);
+
+    // 5% of the order goes to the two jurors in the majority
+    assert_eq!(t.client.finalize_panel(&dispute), 50);
+    assert_eq!((t.balance(&t.token, &first), t.balance(&t.token, &second), t.balance(&t.token, &third)), (25, 25, 0));
+    assert_eq!(t.balance(&t.token, &buyer), 950);
+    assert_eq!(t.client.get_juror(&first).active_cases, 0);
+}
+
+#[test]
+fn jurors_need_gold_trust_and_a_full_pool() {
+    let t = TestBazaar::default();
+    let newcomer = t.user();
+    t.mint(&t.bzr, &newcomer, 100);
+    assert_eq!(t.client.try_register_juror(&newcomer), Err(Ok(Error::JurorTrustTooLow)));
+
+    let jurors = register_jurors(&t, 2);
+    assert_eq!(t.client.try_register_juror(&jurors.get_unchecked(0)), Err(Ok(Error::AlreadyJuror)));
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+    assert_eq!(t.client.try_escalate_dispute(&buyer, &dispute), Err(Ok(Error::NotEnoughJurors)));
+}
+
+#[test]
+fn a_seated_juror_votes_once_and_stays_until_the_panel_closes() {
+    let t = TestBazaar::default();
+    register_jurors(&t, 3);
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+    let panel = t.client.escalate_dispute(&merchant, &dispute);
+    let juror = panel.get_unchecked(0);
+
+    t.client.juror_vote(&juror, &dispute, &false);
+    assert_eq!(t.client.try_juror_vote(&juror, &dispute, &false), Err(Ok(Error::AlreadyVoted)));
+    assert_eq!(t.client.try_unregister_juror(&juror), Err(Ok(Error::JurorBusy)));
+
+    // No majority by the deadline: the panel dissolves and frees its jurors
+    t.advance_seconds(7 * DAY_SECONDS + 1);
+    assert_eq!(t.client.finalize_panel(&dispute), 0);
+    assert_eq!(t.client.unregister_juror(&juror), 100);
+}
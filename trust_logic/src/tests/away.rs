@@ -0,0 +1,47 @@
+// 🏛️ PROJECT BAZAAR | AWAY MODE TESTS
+// Away windows that pause new orders and trust decay, and the limits on taking one.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::BytesN;
+
+#[test]
+fn an_away_merchant_takes_no_orders_and_keeps_their_trust() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.bonded_user(40), t.user());
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &100, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    t.client.set_away(&merchant, &(t.env.ledger().timestamp() + 60 * DAY_SECONDS));
+    assert!(t.client.is_away(&merchant));
+    assert_eq!(t.client.try_create_order(&buyer, &listing), Err(Ok(Error::MerchantAway)));
+
+    // Both resume on their own once the window runs out
+    t.advance_days(60);
+    assert!(!t.client.is_away(&merchant));
+    assert_eq!(t.client.get_trust(&merchant), 40);
+    t.client.create_order(&buyer, &listing);
+    t.advance_days(30);
+    assert_eq!(t.client.get_trust(&merchant), 39);
+}
+
+#[test]
+fn away_windows_are_capped_at_60_days_with_a_30_day_cooldown() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    let now = t.env.ledger().timestamp();
+    assert_eq!(t.client.try_set_away(&merchant, &now), Err(Ok(Error::NotAway)));
+    assert_eq!(t.client.try_set_away(&merchant, &(now + 61 * DAY_SECONDS)), Err(Ok(Error::InvalidAwayPeriod)));
+
+    t.client.set_away(&merchant, &(now + 10 * DAY_SECONDS));
+    assert_eq!(t.client.try_set_away(&merchant, &(now + 61 * DAY_SECONDS)), Err(Ok(Error::InvalidAwayPeriod)));
+    // An `until` in the past ends the running window early
+    t.advance_days(5);
+    t.client.set_away(&merchant, &0);
+    assert!(!t.client.is_away(&merchant));
+
+    t.advance_days(29);
+    let now = t.env.ledger().timestamp();
+    assert_eq!(t.client.try_set_away(&merchant, &(now + DAY_SECONDS)), Err(Ok(Error::AwayCooldown)));
+    t.advance_days(1);
+    t.client.set_away(&merchant, &(now + 2 * DAY_SECONDS));
+}
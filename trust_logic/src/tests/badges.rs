@@ -0,0 +1,89 @@
+// 🏛️ PROJECT BAZAAR | BADGE TESTS
+// Milestone badges awarded as profiles change, the admin's grants and revocations, and
+// their soulbound mirror.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::{contract, contractimpl};
+
+/// Records every badge it holds for a user, keyed by `(user, badge)`.
+#[contract]
+struct MockMinter;
+
+#[contractimpl]
+impl BadgeMinter for MockMinter {
+    fn mint_badge(env: Env, to: Address, badge: Symbol) {
+        env.storage().persistent().set(&(to, badge), &true);
+    }
+
+    fn burn_badge(env: Env, from: Address, badge: Symbol) {
+        env.storage().persistent().remove(&(from, badge));
+    }
+}
+
+fn minted(t: &TestBazaar, minter: &Address, user: &Address, badge: Symbol) -> bool {
+    t.env.as_contract(minter, || t.env.storage().persistent().has(&(user.clone(), badge)))
+}
+
+#[test]
+fn milestones_award_their_badges_with_the_issue_time() {
+    let t = TestBazaar::default();
+    let merchant = t.bonded_user(49);
+    assert_eq!(t.client.get_badges(&merchant), vec![&t.env, BADGE_BONDED]);
+
+    let voucher = t.bonded_user(10);
+    t.mint(&t.bzr, &voucher, 5);
+    t.advance_days(1);
+    t.client.vouch(&voucher, &merchant);
+    assert!(t.client.has_badge(&merchant, &BADGE_TRUSTED));
+    let trusted = t.client.get_badge_info(&merchant).get_unchecked(1);
+    assert_eq!((trusted.badge, trusted.issued_at), (BADGE_TRUSTED, Some(t.env.ledger().timestamp())));
+}
+
+#[test]
+fn revoked_badges_stay_off_until_granted_by_hand() {
+    let t = TestBazaar::default();
+    let merchant = t.bonded_user(0);
+    t.client.revoke_badge(&merchant, &BADGE_BONDED);
+    assert_eq!(t.client.try_revoke_badge(&merchant, &BADGE_BONDED), Err(Ok(Error::BadgeNotHeld)));
+
+    // Restaking would normally earn it again
+    t.advance_days(30);
+    t.client.unstake(&merchant);
+    t.client.stake(&merchant);
+    assert!(!t.client.has_badge(&merchant, &BADGE_BONDED));
+
+    t.client.grant_badge(&merchant, &BADGE_BONDED);
+    assert_eq!(t.client.try_grant_badge(&merchant, &BADGE_BONDED), Err(Ok(Error::BadgeAlreadyHeld)));
+    assert!(t.client.has_badge(&merchant, &BADGE_BONDED));
+}
+
+#[test]
+fn a_named_minter_mirrors_every_issue_and_revocation() {
+    let t = TestBazaar::default();
+    let minter = t.env.register(MockMinter, ());
+    t.client.set_badge_minter(&Some(minter.clone()));
+    assert_eq!(t.client.get_badge_minter(), Some(minter.clone()));
+
+    let merchant = t.bonded_user(0);
+    assert!(minted(&t, &minter, &merchant, BADGE_BONDED));
+    t.client.revoke_badge(&merchant, &BADGE_BONDED);
+    assert!(!minted(&t, &minter, &merchant, BADGE_BONDED));
+
+    // Badges held before a minter was named are not backfilled
+    t.client.set_badge_minter(&None);
+    t.client.grant_badge(&merchant, &BADGE_BONDED);
+    t.client.set_badge_minter(&Some(minter.clone()));
+    assert!(!minted(&t, &minter, &merchant, BADGE_BONDED));
+}
+
+#[test]
+fn a_failing_minter_never_blocks_the_badge() {
+    let t = TestBazaar::default();
+    // An address with no contract behind it fails every call
+    t.client.set_badge_minter(&Some(t.user()));
+    let merchant = t.bonded_user(0);
+    assert!(t.client.has_badge(&merchant, &BADGE_BONDED));
+    t.client.revoke_badge(&merchant, &BADGE_BONDED);
+    assert!(!t.client.has_badge(&merchant, &BADGE_BONDED));
+}
@@ -0,0 +1,45 @@
+// 🏛️ PROJECT BAZAAR | DENY LIST TESTS
+// Listed addresses kept out of orders and vouches, listed by the admin or by proposal.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+#[test]
+fn listed_addresses_cannot_trade_or_vouch() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, voucher) = (t.user(), t.user(), t.bonded_user(60));
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &100, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+
+    t.client.blacklist(&merchant, &7);
+    assert_eq!(t.client.get_blacklist_status(&merchant).unwrap().reason_code, 7);
+    assert_eq!(t.client.try_create_order(&buyer, &listing), Err(Ok(Error::Blacklisted)));
+    t.client.blacklist(&voucher, &7);
+    t.mint(&t.bzr, &voucher, 5);
+    assert_eq!(t.client.try_vouch(&voucher, &buyer), Err(Ok(Error::Blacklisted)));
+
+    // Governance lists through the same entry
+    let voters = [t.bonded_user(100), t.bonded_user(100), t.bonded_user(100)];
+    let id = t.client.create_proposal(&voters[0], &ProposalAction::Blacklist(buyer.clone(), 9));
+    for voter in &voters {
+        t.client.vote(voter, &id, &true);
+    }
+    t.advance_days(7);
+    t.client.execute_proposal(&id);
+    assert_eq!(t.client.get_blacklist_status(&buyer).unwrap().reason_code, 9);
+}
+
+#[test]
+fn only_a_listed_address_can_be_cleared() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    assert_eq!(t.client.try_unblacklist(&buyer), Err(Ok(Error::NotBlacklisted)));
+    let proposer = t.bonded_user(100);
+    assert_eq!(t.client.try_create_proposal(&proposer, &ProposalAction::Unblacklist(buyer.clone())), Err(Ok(Error::NotBlacklisted)));
+
+    t.client.blacklist(&buyer, &1);
+    t.client.unblacklist(&buyer);
+    assert!(t.client.get_blacklist_status(&buyer).is_none());
+    t.funded_order(&merchant, &buyer, 100);
+}
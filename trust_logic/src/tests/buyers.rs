@@ -0,0 +1,35 @@
+// 🏛️ PROJECT BAZAAR | BUYER TRUST TESTS
+// The buyer's own score from prompt funding and completed orders, and what a lost dispute costs.
+
+use crate::testutils::TestBazaar;
+
+#[test]
+fn buyers_earn_trust_for_prompt_funding_and_completed_orders() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    assert_eq!(t.client.get_buyer_trust(&buyer), 1);
+
+    t.client.release_escrow(&buyer, &order);
+    let profile = t.client.get_buyer_profile(&buyer);
+    assert_eq!((profile.trust_score, profile.completed_orders, profile.on_time_fundings), (3, 1, 1));
+    // The buyer score is kept apart from the merchant one
+    assert_eq!(t.client.get_trust(&buyer), 0);
+    assert_eq!(t.client.get_buyer_trust(&merchant), 0);
+}
+
+#[test]
+fn a_lost_dispute_costs_the_buyer_trust_and_earns_no_completion() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, arbiter) = (t.bonded_user(0), t.user(), t.user());
+    t.client.add_arbiter(&arbiter);
+    let first = t.funded_order(&merchant, &buyer, 500);
+    t.client.release_escrow(&buyer, &first);
+    let order = t.funded_order(&merchant, &buyer, 500);
+    assert_eq!(t.client.get_buyer_trust(&buyer), 4);
+
+    let id = t.client.open_dispute(&buyer, &merchant, &order);
+    t.client.resolve_dispute(&arbiter, &id, &false);
+    let profile = t.client.get_buyer_profile(&buyer);
+    assert_eq!((profile.trust_score, profile.completed_orders, profile.disputes_lost), (0, 1, 1));
+}
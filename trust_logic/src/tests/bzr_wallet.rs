@@ -0,0 +1,42 @@
+// 🏛️ PROJECT BAZAAR | BZR WALLET TESTS
+// The internal BZR wallet: deposits, transfers and withdrawals.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn bzr_round_trips_through_the_wallet_without_fees() {
+    let t = TestBazaar::default();
+    let (alice, bob) = (t.user(), t.user());
+    t.mint(&t.bzr, &alice, 300);
+
+    assert_eq!(t.client.deposit_bzr(&alice, &300), 300);
+    assert_eq!(t.balance(&t.bzr, &alice), 0);
+    assert_eq!(t.client.try_transfer_bzr_internal(&alice, &alice, &10), Err(Ok(Error::BzrTransferToSelf)));
+    assert_eq!(t.client.try_transfer_bzr_internal(&alice, &bob, &301), Err(Ok(Error::InsufficientBalance)));
+    t.client.transfer_bzr_internal(&alice, &bob, &120);
+    assert_eq!(t.client.get_bzr_balance(&alice), 180);
+    assert_eq!(t.client.get_bzr_balance(&bob), 120);
+
+    t.client.withdraw_bzr(&bob, &120);
+    assert_eq!(t.balance(&t.bzr, &bob), 120);
+    assert_eq!(t.client.get_bzr_balance(&bob), 0);
+    // What stays booked is exactly what the contract still holds
+    assert_eq!(t.balance(&t.bzr, &t.client.address), 180);
+}
+
+#[test]
+fn the_wallet_refuses_empty_amounts_overdrafts_and_listed_parties() {
+    let t = TestBazaar::default();
+    let (alice, bob) = (t.user(), t.user());
+    t.mint(&t.bzr, &alice, 100);
+    assert_eq!(t.client.try_deposit_bzr(&alice, &0), Err(Ok(Error::InvalidAmount)));
+    t.client.deposit_bzr(&alice, &100);
+    assert_eq!(t.client.try_transfer_bzr_internal(&alice, &bob, &-1), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_withdraw_bzr(&alice, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_withdraw_bzr(&alice, &101), Err(Ok(Error::InsufficientBalance)));
+
+    t.client.blacklist(&bob, &1);
+    assert_eq!(t.client.try_transfer_bzr_internal(&alice, &bob, &10), Err(Ok(Error::Blacklisted)));
+    assert_eq!(t.client.get_bzr_balance(&alice), 100);
+}
@@ -0,0 +1,49 @@
+// 🏛️ PROJECT BAZAAR | CATEGORY TRUST TESTS
+// Sub-scores fed by categorised listings alongside the global merchant score.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::{symbol_short, BytesN};
+
+fn sell(t: &TestBazaar, merchant: &Address, buyer: &Address, category: Option<Symbol>) -> u64 {
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(merchant, &title, &100, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    t.client.set_listing_category(merchant, &listing, &category);
+    let order = t.client.create_order(buyer, &listing);
+    t.mint(&t.token, buyer, 100);
+    t.client.fund_order(buyer, &order);
+    order
+}
+
+#[test]
+fn released_orders_credit_their_listing_category() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    for category in [Some(symbol_short!("food")), Some(symbol_short!("food")), None] {
+        let order = sell(&t, &merchant, &buyer, category);
+        t.client.release_escrow(&buyer, &order);
+    }
+
+    assert_eq!(t.client.get_trust_by_category(&merchant, &symbol_short!("food")), 2);
+    assert_eq!(t.client.get_trust_by_category(&merchant, &symbol_short!("tech")), 0);
+    assert_eq!(t.client.get_category_scores(&merchant).len(), 1);
+    // The global score still counts the uncategorised order
+    assert_eq!(t.client.get_trust(&merchant), 3);
+}
+
+#[test]
+fn only_the_owner_sets_a_category_and_open_orders_keep_theirs() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = sell(&t, &merchant, &buyer, Some(symbol_short!("food")));
+    let listing = t.client.get_order(&order).listing_id;
+    assert_eq!(
+        t.client.try_set_listing_category(&buyer, &listing, &Some(symbol_short!("tech"))),
+        Err(Ok(Error::NotListingOwner))
+    );
+
+    t.client.set_listing_category(&merchant, &listing, &Some(symbol_short!("tech")));
+    t.client.release_escrow(&buyer, &order);
+    assert_eq!(t.client.get_trust_by_category(&merchant, &symbol_short!("food")), 1);
+    assert_eq!(t.client.get_trust_by_category(&merchant, &symbol_short!("tech")), 0);
+}
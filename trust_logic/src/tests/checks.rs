@@ -0,0 +1,47 @@
+// 🏛️ PROJECT BAZAAR | DRY RUN TESTS
+// The can_* previews track what the real calls would do at each step.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn can_claim_walks_through_every_step_of_a_claim() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let stranger = t.user();
+    assert_eq!(t.client.can_claim(&stranger, &owner, &PRIMARY_VAULT), ClaimCheck::NoVault);
+    let heir = t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.can_claim(&stranger, &owner, &PRIMARY_VAULT), ClaimCheck::NoHeir);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::TimerRemaining(180 * DAY_SECONDS));
+
+    t.advance_days(180);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::Ready);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::ChallengeRemaining(14 * DAY_SECONDS));
+    t.advance_days(14);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::Ready);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::AlreadyClaimed);
+}
+
+#[test]
+fn dry_runs_report_the_refusal_the_real_call_would_give() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.user();
+    t.client.create_vault(&owner, &heir);
+    t.advance_days(180);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::HeirNotAccepted);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::HeirNotAccepted)));
+
+    t.mint(&t.token, &owner, 500);
+    t.client.deposit_vault(&owner, &PRIMARY_VAULT, &t.token, &500);
+    assert_eq!(t.client.can_withdraw(&owner, &PRIMARY_VAULT, &t.token, &600), WithdrawCheck::InsufficientBalance);
+    assert_eq!(t.client.can_withdraw(&owner, &PRIMARY_VAULT, &t.token, &500), WithdrawCheck::Ready);
+    assert_eq!(t.client.can_withdraw(&owner, &1, &t.token, &500), WithdrawCheck::NoVault);
+
+    t.client.pause();
+    assert_eq!(t.client.can_withdraw(&owner, &PRIMARY_VAULT, &t.token, &500), WithdrawCheck::Paused);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::Paused);
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &500), Err(Ok(Error::Paused)));
+}
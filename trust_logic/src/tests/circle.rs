@@ -0,0 +1,172 @@
+// 🏛️ PROJECT BAZAAR | CIRCLE TESTS
+// Witness circle changes and the quorums that depend on them.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::testutils::Events;
+use soroban_sdk::IntoVal;
+
+#[test]
+fn circle_changes_keep_both_quorums_within_reach() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let (spouse, friend) = (circle.get_unchecked(0), circle.get_unchecked(1));
+
+    assert_eq!(t.client.try_remove_witness(&owner, &friend), Err(Ok(Error::InvalidQuorum)));
+    let too_heavy = vec![&t.env, (spouse.clone(), 4u32), (friend.clone(), 1u32)];
+    assert_eq!(t.client.try_assign_witnesses(&owner, &too_heavy), Err(Ok(Error::InvalidWitnessWeight)));
+    let too_light = vec![&t.env, (spouse.clone(), 1u32), (friend.clone(), 1u32)];
+    assert_eq!(t.client.try_assign_witnesses(&owner, &too_light), Err(Ok(Error::InvalidQuorum)));
+
+    // A spouse of weight 2 plus one friend still carries a quorum of 3
+    t.client.assign_witnesses(&owner, &vec![&t.env, (spouse.clone(), 2u32), (friend.clone(), 1u32)]);
+    t.client.panic_button(&spouse, &owner);
+    t.client.panic_button(&friend, &owner);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Frozen);
+}
+
+#[test]
+fn a_replacement_witness_votes_once_the_cooldown_passes() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 4);
+    let (leaving, newcomer) = (circle.get_unchecked(3), t.user());
+
+    t.client.remove_witness(&owner, &leaving);
+    assert!(t.client.get_circles_i_protect(&leaving).is_empty());
+    assert_eq!(t.client.try_panic_button(&leaving, &owner), Err(Ok(Error::NotWitness)));
+
+    let active_from = t.client.add_witness(&owner, &newcomer, &1);
+    assert_eq!(active_from, t.env.ledger().timestamp() + 7 * DAY_SECONDS);
+    assert_eq!(t.client.try_panic_button(&newcomer, &owner), Err(Ok(Error::WitnessNotAccepted)));
+    t.client.accept_witness_role(&newcomer, &owner);
+    assert_eq!(t.client.try_panic_button(&newcomer, &owner), Err(Ok(Error::WitnessPending)));
+    t.advance_days(7);
+    t.client.panic_button(&newcomer, &owner);
+    assert_eq!(t.client.get_circles_i_protect(&newcomer), vec![&t.env, owner]);
+}
+
+#[test]
+fn witnesses_are_added_once_and_only_up_to_the_circle_size() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 4);
+    assert_eq!(t.client.try_add_witness(&owner, &circle.get_unchecked(0), &1), Err(Ok(Error::AlreadyWitness)));
+    assert_eq!(t.client.try_add_witness(&owner, &t.user(), &0), Err(Ok(Error::InvalidWitnessWeight)));
+    assert_eq!(t.client.try_remove_witness(&owner, &t.user()), Err(Ok(Error::NotWitness)));
+
+    t.client.add_witness(&owner, &t.user(), &1);
+    assert_eq!(t.client.try_add_witness(&owner, &t.user(), &1), Err(Ok(Error::TooManyWitnesses)));
+    assert_eq!(t.client.try_accept_witness_role(&circle.get_unchecked(0), &owner), Err(Ok(Error::WitnessAlreadyAccepted)));
+}
+
+#[test]
+fn owners_set_their_own_medical_and_panic_quorums() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let config = CircleConfig { max_witnesses: 5, medical_quorum: 2, panic_quorum: 4 };
+    assert_eq!(t.client.try_set_circle_config(&owner, &config, &None), Err(Ok(Error::CosignRequired)));
+    t.client.set_circle_config(&owner, &config, &Some(circle.get_unchecked(0)));
+
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    for i in 0..2 {
+        t.client.witness_vote_medical(&circle.get_unchecked(i), &owner);
+    }
+    let status = t.client.get_emergency_status(&owner);
+    assert!(status.is_unlocked);
+    assert_eq!(status.unlocked_bps, 1_500);
+
+    for i in 0..3 {
+        t.client.panic_button(&circle.get_unchecked(i), &owner);
+    }
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+    t.client.panic_button(&circle.get_unchecked(3), &owner);
+    assert!(t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+}
+
+#[test]
+fn quorums_must_be_reachable_by_the_circle() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let witness = Some(t.make_circle(&owner, 3).get_unchecked(0));
+    for (medical_quorum, panic_quorum) in [(0, 3), (3, 4)] {
+        let config = CircleConfig { max_witnesses: 5, medical_quorum, panic_quorum };
+        assert_eq!(t.client.try_set_circle_config(&owner, &config, &witness), Err(Ok(Error::InvalidQuorum)));
+    }
+    for max_witnesses in [2, 6] {
+        let config = CircleConfig { max_witnesses, medical_quorum: 3, panic_quorum: 3 };
+        assert_eq!(t.client.try_set_circle_config(&owner, &config, &None), Err(Ok(Error::TooManyWitnesses)));
+    }
+    assert_eq!(t.client.get_circle_config(&owner).panic_quorum, 3);
+}
+
+#[test]
+fn the_circle_config_can_be_set_before_the_first_circle() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let config = CircleConfig { max_witnesses: 2, medical_quorum: 7, panic_quorum: 2 };
+    assert_eq!(t.client.try_set_circle_config(&owner, &config, &None), Err(Ok(Error::InvalidQuorum)));
+    t.client.set_circle_config(&owner, &CircleConfig { medical_quorum: 2, ..config }, &None);
+    assert_eq!(t.client.get_circle_config(&owner).max_witnesses, 2);
+
+    let three = vec![&t.env, (t.user(), 1u32), (t.user(), 1u32), (t.user(), 1u32)];
+    assert_eq!(t.client.try_assign_witnesses(&owner, &three), Err(Ok(Error::TooManyWitnesses)));
+    let one = vec![&t.env, (t.user(), 1u32)];
+    assert_eq!(t.client.try_assign_witnesses(&owner, &one), Err(Ok(Error::InvalidQuorum)));
+}
+
+#[test]
+fn a_circle_of_two_runs_on_a_quorum_of_two() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let (spouse, sibling) = (t.user(), t.user());
+    let pair = vec![&t.env, (spouse.clone(), 1u32), (sibling.clone(), 1u32)];
+    assert_eq!(t.client.try_assign_witnesses(&owner, &pair), Err(Ok(Error::InvalidQuorum)));
+
+    let mut config = t.client.get_user_config(&owner);
+    (config.medical_quorum, config.panic_quorum) = (2, 2);
+    t.client.set_user_config(&owner, &config, &None);
+    t.client.assign_witnesses(&owner, &pair);
+    assert_eq!(t.client.try_remove_witness(&owner, &sibling), Err(Ok(Error::InvalidQuorum)));
+
+    for witness in [&spouse, &sibling] {
+        t.client.accept_witness_role(witness, &owner);
+        t.client.panic_button(witness, &owner);
+    }
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Frozen);
+}
+
+#[test]
+fn assigned_witnesses_are_notified_and_see_every_circle_they_guard() {
+    let t = TestBazaar::default();
+    let (mother, father, friend) = (t.user(), t.user(), t.user());
+    let circle = vec![&t.env, (friend.clone(), 3), (t.user(), 1), (t.user(), 1)];
+    t.client.assign_witnesses(&mother, &circle);
+    let notified = t.env.events().all().iter().filter(|(_, topics, _)| {
+        *topics == (events::WITNESS_ADDED, mother.clone()).into_val(&t.env)
+    }).count();
+    assert_eq!(notified, 3);
+
+    t.client.assign_witnesses(&father, &circle);
+    assert_eq!(t.client.get_circles_i_protect(&friend), vec![&t.env, mother.clone(), father]);
+    t.client.accept_witness_role(&friend, &mother);
+    assert!(t.client.get_witnesses(&mother).get_unchecked(0).accepted);
+}
+
+#[test]
+fn only_an_assigned_witness_accepts_the_role() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    assert_eq!(t.client.try_accept_witness_role(&t.user(), &owner), Err(Ok(Error::NoCircle)));
+    t.make_circle(&owner, 3);
+    assert_eq!(t.client.try_accept_witness_role(&t.user(), &owner), Err(Ok(Error::NotWitness)));
+}
@@ -0,0 +1,206 @@
+// 🏛️ PROJECT BAZAAR | CLAIM TESTS
+// The deadman switch from timeout to payout: the challenge window, witness cosigns,
+// deny-listed heirs, the relayer share and the legacy note.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{BytesN, IntoVal, TryFromVal};
+
+#[test]
+fn deadman_switch_pays_the_heir_after_timeout_and_challenge() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    t.advance_days(179);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Warning);
+
+    t.advance_days(1);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::Ready);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_finalize_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ChallengeActive)));
+
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Claimed);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::VaultAlreadyClaimed)));
+}
+
+#[test]
+fn claim_cosigns_hold_the_payout_until_witnesses_approve() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let mut config = t.client.get_user_config(&owner);
+    config.claim_cosigns = 2;
+    t.client.set_user_config(&owner, &config, &None);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.approve_claim(&circle.get_unchecked(0), &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::ApprovalsNeeded(1));
+    assert_eq!(t.client.try_finalize_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ClaimCosignRequired)));
+
+    t.client.approve_claim(&circle.get_unchecked(1), &owner, &PRIMARY_VAULT);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+}
+
+#[test]
+fn only_approvals_from_current_witnesses_count_and_only_for_their_vault() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let second_heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let mut config = t.client.get_user_config(&owner);
+    config.claim_cosigns = 2;
+    t.client.set_user_config(&owner, &config, &None);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.client.initiate_claim(&second_heir, &owner, &1);
+    t.advance_days(14);
+    t.client.approve_claim(&circle.get_unchecked(0), &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.approve_claim(&circle.get_unchecked(1), &owner, &PRIMARY_VAULT), 2);
+    assert_eq!(t.client.can_claim(&second_heir, &owner, &1), ClaimCheck::ApprovalsNeeded(2));
+
+    // The owner drops a witness who had approved; their approval goes with them
+    t.client.remove_witness(&owner, &circle.get_unchecked(1));
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::ApprovalsNeeded(1));
+    assert_eq!(t.client.try_finalize_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ClaimCosignRequired)));
+
+    assert_eq!(t.client.approve_claim(&circle.get_unchecked(2), &owner, &PRIMARY_VAULT), 2);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+}
+
+#[test]
+fn a_deny_listed_heir_cannot_claim_until_cleared() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    t.advance_days(180);
+    t.client.blacklist(&heir, &1);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::Blacklisted);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::Blacklisted)));
+
+    t.client.unblacklist(&heir);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+}
+
+#[test]
+fn the_claim_relayer_takes_its_share_from_its_own_vault_only() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let relayer = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let second_heir = t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_set_claim_relayer(&owner, &PRIMARY_VAULT, &Some(relayer.clone()), &201), Err(Ok(Error::InvalidRelayer)));
+    t.client.set_claim_relayer(&owner, &PRIMARY_VAULT, &Some(relayer.clone()), &200);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_set_claim_relayer(&owner, &PRIMARY_VAULT, &None, &0), Err(Ok(Error::ClaimPending)));
+    t.client.initiate_claim(&second_heir, &owner, &1);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.client.finalize_claim(&second_heir, &owner, &1);
+
+    // 2% of the first vault goes to the relayer; the second vault named none
+    assert_eq!(t.balance(&t.token, &relayer), 20);
+    assert_eq!(t.balance(&t.token, &heir), 980);
+    assert_eq!(t.balance(&t.token, &second_heir), 1_000);
+}
+
+#[test]
+fn three_witness_vetoes_or_the_owner_stop_a_claim() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 4);
+    t.advance_days(180);
+
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    for i in 0..2 {
+        t.client.veto_claim(&circle.get_unchecked(i), &owner, &PRIMARY_VAULT);
+    }
+    assert_eq!(t.client.get_pending_claim(&owner, &PRIMARY_VAULT).unwrap().vetoes.len(), 2);
+    t.client.veto_claim(&circle.get_unchecked(2), &owner, &PRIMARY_VAULT);
+    assert!(t.client.get_pending_claim(&owner, &PRIMARY_VAULT).is_none());
+
+    // The vetoed heir can't reopen the claim straight away
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ClaimVetoed)));
+    t.advance_days(13);
+    assert_eq!(t.client.can_claim(&heir, &owner, &PRIMARY_VAULT), ClaimCheck::VetoRemaining(DAY_SECONDS));
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ClaimVetoed)));
+    t.advance_days(1);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.client.veto_claim(&owner, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    assert_eq!(t.client.try_finalize_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::NoPendingClaim)));
+    assert_eq!(t.balance(&t.token, &heir), 0);
+}
+
+#[test]
+fn vetoes_need_an_open_claim_and_count_once_per_witness() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let first = circle.get_unchecked(0);
+    assert_eq!(t.client.try_veto_claim(&first, &owner, &PRIMARY_VAULT), Err(Ok(Error::NoPendingClaim)));
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::ClaimPending)));
+    t.client.veto_claim(&first, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_veto_claim(&first, &owner, &PRIMARY_VAULT), Err(Ok(Error::AlreadyVoted)));
+    assert_eq!(t.client.try_veto_claim(&t.user(), &owner, &PRIMARY_VAULT), Err(Ok(Error::NotWitness)));
+    assert_eq!(t.client.try_finalize_claim(&first, &owner, &PRIMARY_VAULT), Err(Ok(Error::NotClaimant)));
+}
+
+#[test]
+fn the_legacy_note_is_released_to_the_heir_with_the_claim() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let doc_hash = BytesN::from_array(&t.env, &[4; 32]);
+    t.client.set_legacy_note(&owner, &doc_hash);
+    assert!(t.client.get_legacy_note(&owner).is_none());
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    let released = t.env.events().all().iter().find(|(_, topics, _)| *topics == (events::LEGACY_NOTE_RELEASED, owner.clone()).into_val(&t.env));
+    let (_, _, data) = released.unwrap();
+    assert_eq!(<(Address, BytesN<32>)>::try_from_val(&t.env, &data).unwrap(), (heir, doc_hash.clone()));
+    assert_eq!(t.client.get_legacy_note(&owner), Some(doc_hash));
+}
+
+#[test]
+fn legacy_notes_need_a_vault_and_a_cleared_note_stays_sealed() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let doc_hash = BytesN::from_array(&t.env, &[4; 32]);
+    assert_eq!(t.client.try_set_legacy_note(&owner, &doc_hash), Err(Ok(Error::VaultNotFound)));
+
+    let heir = t.fund_vault(&owner, 1_000);
+    t.client.set_legacy_note(&owner, &doc_hash);
+    t.client.clear_legacy_note(&owner);
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert!(t.client.get_legacy_note(&owner).is_none());
+}
@@ -0,0 +1,47 @@
+// 🏛️ PROJECT BAZAAR | WITNESS CONTACT TESTS
+// Encrypted contact pointers and the circle alert that lists them.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{BytesN, IntoVal, TryFromVal, Val};
+
+fn circle_alert(t: &TestBazaar, owner: &Address) -> Option<(Symbol, Map<Address, BytesN<32>>)> {
+    let topics: Vec<Val> = (events::CIRCLE_ALERT, owner.clone()).into_val(&t.env);
+    t.env.events().all().iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .map(|(_, _, data)| TryFromVal::try_from_val(&t.env, &data).unwrap())
+}
+
+#[test]
+fn an_emergency_alerts_every_witness_who_left_a_contact() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let (first, second) = (circle.get_unchecked(0), circle.get_unchecked(1));
+    t.client.set_witness_contact(&first, &Some(BytesN::from_array(&t.env, &[1; 32])));
+    t.client.set_witness_contact(&second, &Some(BytesN::from_array(&t.env, &[2; 32])));
+    t.client.set_witness_contact(&second, &None);
+    assert!(t.client.get_witness_contact(&second).is_none());
+
+    t.client.declare_emergency(&first, &owner);
+    let (kind, contacts) = circle_alert(&t, &owner).unwrap();
+    assert_eq!(kind, events::EMERGENCY_DECLARED);
+    assert_eq!(contacts.len(), 1);
+    assert_eq!(contacts.get(first), Some(BytesN::from_array(&t.env, &[1; 32])));
+}
+
+#[test]
+fn a_circle_without_contacts_sends_no_alert() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    assert!(circle_alert(&t, &owner).is_none());
+
+    t.client.pause();
+    let hash = Some(BytesN::from_array(&t.env, &[1; 32]));
+    assert_eq!(t.client.try_set_witness_contact(&circle.get_unchecked(0), &hash), Err(Ok(Error::Paused)));
+}
@@ -0,0 +1,57 @@
+// 🏛️ PROJECT BAZAAR | DISPUTE TESTS
+// Arbiter rulings on escrowed orders and the bond a losing merchant forfeits.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn a_buyer_win_refunds_the_order_and_slashes_the_bond() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, arbiter) = (t.bonded_user(0), t.user(), t.user());
+    t.client.add_arbiter(&arbiter);
+    let order = t.funded_order(&merchant, &buyer, 500);
+    let id = t.client.open_dispute(&buyer, &merchant, &order);
+    assert!(t.client.is_disputed(&merchant));
+
+    // 10% of the 100 BZR bond goes to the buyer's compensation pool
+    assert_eq!(t.client.resolve_dispute(&arbiter, &id, &true), 10);
+    assert_eq!(t.client.get_dispute(&id).status, DisputeStatus::BuyerWon);
+    assert!(!t.client.is_disputed(&merchant));
+    assert_eq!(t.balance(&t.token, &buyer), 500);
+    assert_eq!(t.client.claim_compensation(&buyer), 10);
+    assert_eq!(t.balance(&t.bzr, &buyer), 10);
+
+    t.advance_days(30);
+    assert_eq!(t.client.unstake(&merchant), 90);
+}
+
+#[test]
+fn a_merchant_cannot_unstake_out_of_an_open_dispute() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, arbiter) = (t.bonded_user(0), t.user(), t.user());
+    t.client.add_arbiter(&arbiter);
+    let order = t.funded_order(&merchant, &buyer, 500);
+    t.advance_days(30);
+    let id = t.client.open_dispute(&buyer, &merchant, &order);
+    assert_eq!(t.client.try_unstake(&merchant), Err(Ok(Error::DisputeOutstanding)));
+
+    assert_eq!(t.client.resolve_dispute(&arbiter, &id, &true), 10);
+    assert_eq!(t.client.claim_compensation(&buyer), 10);
+    assert_eq!(t.client.unstake(&merchant), 90);
+}
+
+#[test]
+fn only_arbiters_rule_once_and_a_merchant_win_keeps_the_bond() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, arbiter) = (t.bonded_user(0), t.user(), t.user());
+    t.client.add_arbiter(&arbiter);
+    let order = t.funded_order(&merchant, &buyer, 500);
+    assert_eq!(t.client.try_open_dispute(&t.user(), &merchant, &order), Err(Ok(Error::NotOrderParty)));
+    let id = t.client.open_dispute(&buyer, &merchant, &order);
+
+    assert_eq!(t.client.try_resolve_dispute(&buyer, &id, &true), Err(Ok(Error::NotArbiter)));
+    assert_eq!(t.client.resolve_dispute(&arbiter, &id, &false), 0);
+    assert_eq!(t.client.try_resolve_dispute(&arbiter, &id, &true), Err(Ok(Error::DisputeClosed)));
+    assert_eq!(t.balance(&t.token, &merchant), 500);
+    assert_eq!(t.client.try_claim_compensation(&buyer), Err(Ok(Error::InsufficientBalance)));
+}
@@ -0,0 +1,29 @@
+// 🏛️ PROJECT BAZAAR | ERROR TESTS
+// Refusals reach clients as typed contract errors with their stable codes, never as traps.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::Error as HostError;
+
+#[test]
+fn refusals_come_back_as_typed_errors() {
+    let t = TestBazaar::default();
+    let (owner, stranger) = (t.user(), t.user());
+    assert_eq!(t.client.try_get_vault(&owner, &PRIMARY_VAULT).err(), Some(Ok(Error::VaultNotFound)));
+    assert_eq!(t.client.try_panic_button(&stranger, &owner), Err(Ok(Error::NoCircle)));
+    assert_eq!(t.client.try_vouch(&owner, &owner), Err(Ok(Error::SelfVouch)));
+
+    let heir = t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    t.mint(&t.bzr, &owner, 100);
+    t.client.stake(&owner);
+    assert_eq!(t.client.try_stake(&owner), Err(Ok(Error::AlreadyBonded)));
+}
+
+#[test]
+fn error_codes_keep_their_published_values() {
+    for (error, code) in [(Error::VaultNotFound, 1), (Error::TimerNotExpired, 6), (Error::NotWitness, 21), (Error::AlreadyBonded, 40)] {
+        assert_eq!(error as u32, code);
+        assert_eq!(HostError::from(error), HostError::from_contract_error(code));
+    }
+}
@@ -0,0 +1,172 @@
+// 🏛️ PROJECT BAZAAR | ESCROW TESTS
+// Orders from funding through shipping to release, full refund, a negotiated partial refund or expiry.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::BytesN;
+
+#[test]
+fn an_accepted_partial_refund_splits_the_escrow_less_the_fee() {
+    let t = TestBazaar::default();
+    t.client.set_fee_config(&FeeConfig { escrow_bps: 100, remit_bps: 0, claim_bps: 0 });
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+
+    t.client.propose_partial_refund(&buyer, &order, &500);
+    // A counter-offer replaces the standing one
+    t.client.propose_partial_refund(&merchant, &order, &300);
+    t.client.accept_partial_refund(&buyer, &order);
+
+    assert_eq!(t.balance(&t.token, &buyer), 300);
+    assert_eq!(t.balance(&t.token, &merchant), 693);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 7);
+    assert_eq!(t.client.get_order(&order).state, OrderState::Settled);
+    assert!(t.client.get_refund_proposal(&order).is_none());
+}
+
+#[test]
+fn partial_refunds_need_the_other_party_and_a_live_order() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, outsider) = (t.user(), t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+
+    assert_eq!(t.client.try_accept_partial_refund(&merchant, &order), Err(Ok(Error::NoRefundProposal)));
+    assert_eq!(t.client.try_propose_partial_refund(&buyer, &order, &1_000), Err(Ok(Error::InvalidRefund)));
+    assert_eq!(t.client.try_propose_partial_refund(&outsider, &order, &100), Err(Ok(Error::NotOrderParty)));
+    t.client.propose_partial_refund(&buyer, &order, &400);
+    assert_eq!(t.client.try_accept_partial_refund(&buyer, &order), Err(Ok(Error::NotOrderParty)));
+
+    // A full refund from the merchant drops the offer and closes the order
+    t.client.refund_order(&merchant, &order);
+    assert_eq!(t.balance(&t.token, &buyer), 1_000);
+    assert_eq!(t.client.try_accept_partial_refund(&merchant, &order), Err(Ok(Error::InvalidOrderState)));
+}
+
+#[test]
+fn a_shipped_order_releases_to_the_merchant_on_delivery() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    assert_eq!(t.balance(&t.token, &t.client.address), 1_000);
+
+    t.client.mark_shipped(&merchant, &order, &BytesN::from_array(&t.env, &[1; 32]));
+    t.client.confirm_delivery(&buyer, &order);
+    assert_eq!(t.client.get_order(&order).state, OrderState::Released);
+    assert_eq!(t.balance(&t.token, &merchant), 1_000);
+    assert_eq!(t.client.get_trust(&merchant), 1);
+}
+
+#[test]
+fn orders_move_only_by_their_parties_and_in_order() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &1_000, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    assert_eq!(t.client.try_create_order(&merchant, &listing), Err(Ok(Error::SelfTrade)));
+    let order = t.client.create_order(&buyer, &listing);
+    assert_eq!(t.client.try_fund_order(&merchant, &order), Err(Ok(Error::NotOrderParty)));
+
+    t.mint(&t.token, &buyer, 1_000);
+    t.client.fund_order(&buyer, &order);
+    assert_eq!(t.client.try_fund_order(&buyer, &order), Err(Ok(Error::InvalidOrderState)));
+    assert_eq!(t.client.try_confirm_delivery(&buyer, &order), Err(Ok(Error::InvalidOrderState)));
+    assert_eq!(t.client.try_refund_order(&buyer, &order), Err(Ok(Error::NotOrderParty)));
+
+    t.client.refund_order(&merchant, &order);
+    assert_eq!(t.balance(&t.token, &buyer), 1_000);
+    assert_eq!(t.client.get_trust(&merchant), 0);
+}
+
+#[test]
+fn an_oracle_confirmed_delivery_auto_releases_after_seven_days() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, carrier) = (t.user(), t.user(), t.user());
+    t.client.set_shipping_oracle(&carrier, &true);
+    assert!(t.client.is_shipping_oracle(&carrier));
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    t.client.mark_shipped(&merchant, &order, &BytesN::from_array(&t.env, &[1; 32]));
+
+    t.client.confirm_delivery_oracle(&carrier, &order);
+    assert_eq!(t.client.get_order(&order).state, OrderState::Delivered);
+    t.advance_seconds(7 * DAY_SECONDS - 1);
+    assert_eq!(t.client.try_auto_release(&order), Err(Ok(Error::AutoReleaseNotDue)));
+    t.advance_seconds(1);
+    t.client.auto_release(&order);
+    assert_eq!(t.client.get_order(&order).state, OrderState::Released);
+    assert_eq!(t.balance(&t.token, &merchant), 1_000);
+}
+
+#[test]
+fn only_a_registered_oracle_confirms_a_shipped_order() {
+    let t = TestBazaar::default();
+    let (merchant, buyer, carrier) = (t.user(), t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    assert_eq!(t.client.try_confirm_delivery_oracle(&carrier, &order), Err(Ok(Error::NotShippingOracle)));
+
+    t.client.set_shipping_oracle(&carrier, &true);
+    assert_eq!(t.client.try_confirm_delivery_oracle(&carrier, &order), Err(Ok(Error::InvalidOrderState)));
+    assert_eq!(t.client.try_auto_release(&order), Err(Ok(Error::InvalidOrderState)));
+    t.client.set_shipping_oracle(&carrier, &false);
+    t.client.mark_shipped(&merchant, &order, &BytesN::from_array(&t.env, &[1; 32]));
+    assert_eq!(t.client.try_confirm_delivery_oracle(&carrier, &order), Err(Ok(Error::NotShippingOracle)));
+}
+
+#[test]
+fn a_released_order_pays_out_once() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+
+    t.client.release_escrow(&buyer, &order);
+    assert_eq!(t.client.get_order(&order).state, OrderState::Released);
+    assert_eq!(t.client.try_release_escrow(&buyer, &order), Err(Ok(Error::InvalidOrderState)));
+    assert_eq!(t.client.try_refund_order(&merchant, &order), Err(Ok(Error::InvalidOrderState)));
+    assert_eq!((t.balance(&t.token, &merchant), t.balance(&t.token, &t.client.address)), (1_000, 0));
+}
+
+#[test]
+fn lapsed_orders_expire_against_whoever_let_them_lapse() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    t.set_trust(&merchant, 10);
+    let unshipped = t.funded_order(&merchant, &buyer, 1_000);
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &1_000, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    let unfunded = t.client.create_order(&buyer, &listing);
+
+    // An unfunded order lapses after a day and costs the buyer
+    t.advance_days(1);
+    t.client.cancel_expired_order(&unfunded);
+    assert_eq!(t.client.get_order(&unfunded).state, OrderState::Refunded);
+    assert_eq!(t.client.get_buyer_profile(&buyer).lapsed_orders, 1);
+
+    // An unshipped one lapses after a week, goes back to the buyer and costs the merchant
+    t.advance_days(6);
+    t.client.cancel_expired_order(&unshipped);
+    assert_eq!(t.client.get_order(&unshipped).state, OrderState::Refunded);
+    assert_eq!(t.balance(&t.token, &buyer), 1_000);
+    assert_eq!(t.client.get_trust(&merchant), 5);
+}
+
+#[test]
+fn orders_expire_only_once_their_window_has_passed() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &1_000, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    let unfunded = t.client.create_order(&buyer, &listing);
+    assert_eq!(t.client.try_cancel_expired_order(&order), Err(Ok(Error::OrderNotExpired)));
+
+    t.advance_days(1);
+    t.mint(&t.token, &buyer, 1_000);
+    assert_eq!(t.client.try_fund_order(&buyer, &unfunded), Err(Ok(Error::OrderExpired)));
+    t.advance_days(6);
+    assert_eq!(t.client.try_mark_shipped(&merchant, &order, &BytesN::from_array(&t.env, &[1; 32])), Err(Ok(Error::OrderExpired)));
+
+    // Shipped orders are past the point of lapsing
+    let shipped = t.funded_order(&merchant, &buyer, 1_000);
+    t.client.mark_shipped(&merchant, &shipped, &BytesN::from_array(&t.env, &[1; 32]));
+    t.advance_days(30);
+    assert_eq!(t.client.try_cancel_expired_order(&shipped), Err(Ok(Error::InvalidOrderState)));
+}
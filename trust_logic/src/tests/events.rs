@@ -0,0 +1,48 @@
+// 🏛️ PROJECT BAZAAR | EVENT TESTS
+// State transitions announce themselves under their topic; refused calls announce nothing.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{IntoVal, Symbol, TryFromVal, Val};
+
+/// First topics of the events published by the last contract call, in order.
+fn last_topics(t: &TestBazaar) -> Vec<Symbol> {
+    let mut topics = Vec::new(&t.env);
+    for (_, event_topics, _) in t.env.events().all().iter() {
+        topics.push_back(Symbol::try_from_val(&t.env, &event_topics.get_unchecked(0)).unwrap());
+    }
+    topics
+}
+
+#[test]
+fn vault_heartbeat_and_panic_transitions_are_published() {
+    let t = TestBazaar::default();
+    let (owner, heir) = (t.user(), t.user());
+    t.client.create_vault(&owner, &heir);
+    let (_, topics, data) = t.env.events().all().last().unwrap();
+    let expected: Vec<Val> = (events::VAULT_CREATED, owner.clone(), PRIMARY_VAULT).into_val(&t.env);
+    assert_eq!(topics, expected);
+    assert_eq!(Address::try_from_val(&t.env, &data).unwrap(), heir);
+
+    t.client.ping_heartbeat(&owner);
+    assert!(last_topics(&t).contains(events::HEARTBEAT));
+
+    let circle = t.make_circle(&owner, 3);
+    t.client.panic_button(&circle.get_unchecked(0), &owner);
+    assert!(last_topics(&t).contains(events::PANIC_VOTE));
+    t.client.panic_button(&circle.get_unchecked(1), &owner);
+    t.client.panic_button(&circle.get_unchecked(2), &owner);
+    assert!(last_topics(&t).contains(events::PANIC_FREEZE));
+}
+
+#[test]
+fn refused_calls_publish_nothing() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    assert!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT).is_err());
+    assert!(last_topics(&t).is_empty());
+    assert!(t.client.try_ping_heartbeat_as_delegate(&heir, &owner).is_err());
+    assert!(!last_topics(&t).contains(events::HEARTBEAT));
+}
@@ -0,0 +1,55 @@
+// 🏛️ PROJECT BAZAAR | DISPUTE EVIDENCE TESTS
+// Evidence filed by the two parties and the acknowledgement jurors give before voting.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+fn open_dispute(t: &TestBazaar) -> (Address, Address, u64) {
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+    (merchant, buyer, dispute)
+}
+
+#[test]
+fn jurors_vote_only_after_acknowledging_the_evidence_on_file() {
+    let t = TestBazaar::default();
+    for _ in 0..3 {
+        let juror = t.user();
+        t.set_trust(&juror, 60);
+        t.mint(&t.bzr, &juror, 100);
+        t.client.register_juror(&juror);
+    }
+    let (merchant, buyer, dispute) = open_dispute(&t);
+    assert_eq!(t.client.submit_evidence(&buyer, &dispute, &BytesN::from_array(&t.env, &[1; 32])), 1);
+    let juror = t.client.escalate_dispute(&buyer, &dispute).get_unchecked(0);
+
+    assert_eq!(t.client.try_juror_vote(&juror, &dispute, &true), Err(Ok(Error::EvidenceNotAcknowledged)));
+    assert_eq!(t.client.acknowledge_evidence(&juror, &dispute), 1);
+    // A late filing has to be acknowledged again
+    t.client.submit_evidence(&merchant, &dispute, &BytesN::from_array(&t.env, &[2; 32]));
+    assert_eq!(t.client.try_juror_vote(&juror, &dispute, &true), Err(Ok(Error::EvidenceNotAcknowledged)));
+    assert_eq!(t.client.acknowledge_evidence(&juror, &dispute), 2);
+    t.client.juror_vote(&juror, &dispute, &true);
+
+    let evidence = t.client.get_evidence(&dispute);
+    assert_eq!((evidence.get_unchecked(0).submitter, evidence.get_unchecked(1).submitter), (buyer, merchant));
+}
+
+#[test]
+fn evidence_comes_from_the_parties_within_five_days_and_five_items() {
+    let t = TestBazaar::default();
+    let (_, buyer, dispute) = open_dispute(&t);
+    let hash = BytesN::from_array(&t.env, &[1; 32]);
+    assert_eq!(t.client.try_submit_evidence(&t.user(), &dispute, &hash), Err(Ok(Error::NotOrderParty)));
+    for _ in 0..5 {
+        t.client.submit_evidence(&buyer, &dispute, &hash);
+    }
+    assert_eq!(t.client.try_submit_evidence(&buyer, &dispute, &hash), Err(Ok(Error::TooMuchEvidence)));
+    assert_eq!(t.client.try_acknowledge_evidence(&buyer, &dispute), Err(Ok(Error::PanelNotFound)));
+
+    let (merchant, _, late) = open_dispute(&t);
+    t.advance_days(5);
+    assert_eq!(t.client.try_submit_evidence(&merchant, &late, &hash), Err(Ok(Error::EvidenceWindowClosed)));
+}
@@ -0,0 +1,48 @@
+// 🏛️ PROJECT BAZAAR | GOVERNANCE TESTS
+// Trust-weighted proposals from bonded merchants, from opening to execution.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn faster_voting() -> ProposalAction {
+    ProposalAction::SetGovernance(GovernanceParams { voting_seconds: 2 * DAY_SECONDS, quorum_power: 200, pass_bps: 6_000 })
+}
+
+#[test]
+fn a_proposal_that_reaches_quorum_and_passes_is_applied() {
+    let t = TestBazaar::default();
+    let voters = [t.bonded_user(100), t.bonded_user(100), t.bonded_user(100), t.bonded_user(60)];
+    let id = t.client.create_proposal(&voters[0], &faster_voting());
+    for voter in &voters[..3] {
+        assert_eq!(t.client.vote(voter, &id, &true), 100);
+    }
+    t.client.vote(&voters[3], &id, &false);
+    assert_eq!(t.client.try_execute_proposal(&id), Err(Ok(Error::VotingOpen)));
+
+    t.advance_days(7);
+    t.client.execute_proposal(&id);
+    assert_eq!(t.client.get_governance_params().quorum_power, 200);
+    assert_eq!(t.client.try_execute_proposal(&id), Err(Ok(Error::ProposalExecuted)));
+}
+
+#[test]
+fn proposals_need_gold_bonded_proposers_and_enough_votes() {
+    let t = TestBazaar::default();
+    let unbonded = t.user();
+    t.set_trust(&unbonded, 100);
+    assert_eq!(t.client.try_create_proposal(&unbonded, &faster_voting()), Err(Ok(Error::NoVotingPower)));
+    let silver = t.bonded_user(30);
+    assert_eq!(t.client.try_create_proposal(&silver, &faster_voting()), Err(Ok(Error::NoVotingPower)));
+
+    let proposer = t.bonded_user(100);
+    let lax = ProposalAction::SetGovernance(GovernanceParams { voting_seconds: 2 * DAY_SECONDS, quorum_power: 200, pass_bps: 4_000 });
+    assert_eq!(t.client.try_create_proposal(&proposer, &lax), Err(Ok(Error::InvalidConfig)));
+
+    // 130 points take part against a quorum of 300
+    let id = t.client.create_proposal(&proposer, &faster_voting());
+    t.client.vote(&proposer, &id, &true);
+    assert_eq!(t.client.try_vote(&proposer, &id, &true), Err(Ok(Error::AlreadyVoted)));
+    t.client.vote(&silver, &id, &true);
+    t.advance_days(7);
+    assert_eq!(t.client.try_execute_proposal(&id), Err(Ok(Error::ProposalRejected)));
+}
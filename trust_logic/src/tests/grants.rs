@@ -0,0 +1,69 @@
+// 🏛️ PROJECT BAZAAR | GRANT TESTS
+// Treasury grants approved by governance and paid out milestone by milestone.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::BytesN;
+
+/// Books `fees` into the treasury through a remittance at the 5% fee cap.
+fn fill_treasury(t: &TestBazaar, fees: i128) {
+    t.client.set_fee_config(&FeeConfig { escrow_bps: 0, remit_bps: 500, claim_bps: 0 });
+    let (sender, recipient) = (t.user(), t.user());
+    t.mint(&t.token, &sender, fees * 20);
+    t.client.remit(&sender, &recipient, &t.token, &(fees * 20), &String::from_str(&t.env, "fees"));
+}
+
+/// Three 100-point votes clear the default quorum.
+fn pass(t: &TestBazaar, voters: &[Address; 3], proposal_id: u64) {
+    for voter in voters {
+        t.client.vote(voter, &proposal_id, &true);
+    }
+    t.advance_days(7);
+    t.client.execute_proposal(&proposal_id);
+}
+
+#[test]
+fn an_approved_grant_pays_one_milestone_per_interval() {
+    let t = TestBazaar::default();
+    fill_treasury(&t, 300);
+    let voters = [t.bonded_user(100), t.bonded_user(100), t.bonded_user(100)];
+    let recipient = t.user();
+    let id = t.client.propose_grant(&voters[0], &recipient, &t.token, &300, &3, &(30 * DAY_SECONDS), &BytesN::from_array(&t.env, &[7; 32]));
+    assert_eq!(t.client.try_release_milestone(&id), Err(Ok(Error::GrantNotApproved)));
+
+    pass(&t, &voters, t.client.get_grant(&id).proposal_id);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 0);
+    assert_eq!(t.client.try_release_milestone(&id), Err(Ok(Error::MilestoneLocked)));
+    for _ in 0..3 {
+        t.advance_days(30);
+        assert_eq!(t.client.release_milestone(&id), 100);
+    }
+    assert_eq!(t.balance(&t.token, &recipient), 300);
+    assert_eq!(t.client.try_release_milestone(&id), Err(Ok(Error::GrantComplete)));
+}
+
+#[test]
+fn grants_need_treasury_cover_and_cancelling_returns_the_unpaid_rest() {
+    let t = TestBazaar::default();
+    fill_treasury(&t, 300);
+    let voters = [t.bonded_user(100), t.bonded_user(100), t.bonded_user(100)];
+    let (recipient, hash) = (t.user(), BytesN::from_array(&t.env, &[7; 32]));
+    assert_eq!(t.client.try_propose_grant(&voters[0], &recipient, &t.token, &300, &13, &DAY_SECONDS, &hash), Err(Ok(Error::InvalidGrant)));
+
+    let too_big = t.client.propose_grant(&voters[0], &recipient, &t.token, &301, &1, &DAY_SECONDS, &hash);
+    let proposal = t.client.get_grant(&too_big).proposal_id;
+    for voter in &voters {
+        t.client.vote(voter, &proposal, &true);
+    }
+    t.advance_days(7);
+    assert_eq!(t.client.try_execute_proposal(&proposal), Err(Ok(Error::InsufficientBalance)));
+
+    let id = t.client.propose_grant(&voters[0], &recipient, &t.token, &300, &3, &DAY_SECONDS, &hash);
+    pass(&t, &voters, t.client.get_grant(&id).proposal_id);
+    t.advance_days(1);
+    t.client.release_milestone(&id);
+    let cancel = t.client.create_proposal(&voters[0], &ProposalAction::CancelGrant(id));
+    pass(&t, &voters, cancel);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 200);
+    assert_eq!(t.client.try_release_milestone(&id), Err(Ok(Error::InvalidGrant)));
+}
@@ -0,0 +1,55 @@
+// 🏛️ PROJECT BAZAAR | GUARDIANSHIP TESTS
+// A minor heir's inheritance held until they come of age, with the guardian's allowance.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn claim(t: &TestBazaar, owner: &Address, heir: &Address) {
+    t.advance_days(180);
+    t.client.initiate_claim(heir, owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(heir, owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn a_minor_inheritance_waits_for_the_release_age() {
+    let t = TestBazaar::default();
+    let (owner, guardian) = (t.user(), t.user());
+    let heir = t.fund_vault(&owner, 1_000);
+    let unlock_at = t.env.ledger().timestamp() + 400 * DAY_SECONDS;
+    t.client.set_minor_heir(&owner, &PRIMARY_VAULT, &heir, &MinorTerms { guardian: guardian.clone(), unlock_at, allowance_bps: 1_000 });
+    claim(&t, &owner, &heir);
+    assert_eq!(t.balance(&t.token, &heir), 0);
+    assert_eq!(t.client.get_guarded_funds(&owner, &heir).balances.get_unchecked(t.token.clone()), 1_000);
+
+    t.client.guardian_withdraw(&guardian, &owner, &heir, &t.token, &100);
+    assert_eq!(t.client.try_guardian_withdraw(&guardian, &owner, &heir, &t.token, &10), Err(Ok(Error::AllowanceCooldown)));
+    assert_eq!(t.client.try_release_guarded_funds(&heir, &owner), Err(Ok(Error::FundsLocked)));
+
+    t.advance_days(206);
+    assert_eq!(t.client.release_guarded_funds(&heir, &owner).get_unchecked(t.token.clone()), 900);
+    assert_eq!((t.balance(&t.token, &heir), t.balance(&t.token, &guardian)), (900, 100));
+    assert_eq!(t.client.try_get_guarded_funds(&owner, &heir).err(), Some(Ok(Error::NoGuardedFunds)));
+}
+
+#[test]
+fn guardians_keep_to_their_terms() {
+    let t = TestBazaar::default();
+    let (owner, guardian) = (t.user(), t.user());
+    let heir = t.fund_vault(&owner, 1_000);
+    let now = t.env.ledger().timestamp();
+    let terms = |guardian: &Address, unlock_at: u64, allowance_bps: u32| MinorTerms { guardian: guardian.clone(), unlock_at, allowance_bps };
+    let invalid = [
+        terms(&guardian, now, 1_000),
+        terms(&guardian, now + 400 * DAY_SECONDS, 1_001),
+        terms(&heir, now + 400 * DAY_SECONDS, 1_000),
+    ];
+    for bad in invalid {
+        assert_eq!(t.client.try_set_minor_heir(&owner, &PRIMARY_VAULT, &heir, &bad), Err(Ok(Error::InvalidGuardianship)));
+    }
+
+    t.client.set_minor_heir(&owner, &PRIMARY_VAULT, &heir, &terms(&guardian, now + 400 * DAY_SECONDS, 1_000));
+    claim(&t, &owner, &heir);
+    assert_eq!(t.client.try_guardian_withdraw(&heir, &owner, &heir, &t.token, &100), Err(Ok(Error::NotGuardian)));
+    assert_eq!(t.client.try_guardian_withdraw(&guardian, &owner, &heir, &t.token, &101), Err(Ok(Error::AllowanceExceeded)));
+}
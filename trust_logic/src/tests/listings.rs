@@ -0,0 +1,49 @@
+// 🏛️ PROJECT BAZAAR | LISTING TESTS
+// Merchant listings: publishing, repricing, paging and closing them.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+fn list(t: &TestBazaar, merchant: &Address, title: &str, price: i128) -> Result<u64, Error> {
+    let title = String::from_str(&t.env, title);
+    match t.client.try_create_listing(merchant, &title, &price, &t.token, &BytesN::from_array(&t.env, &[0; 32])) {
+        Ok(id) => Ok(id.unwrap()),
+        Err(error) => Err(error.unwrap()),
+    }
+}
+
+#[test]
+fn merchants_publish_reprice_and_close_listings() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let rice = list(&t, &merchant, "Rice sack", 1_000).unwrap();
+    let mangoes = list(&t, &merchant, "Mangoes", 300).unwrap();
+
+    let new_title = String::from_str(&t.env, "Dried mangoes");
+    t.client.update_listing(&merchant, &mangoes, &new_title, &350, &BytesN::from_array(&t.env, &[2; 32]));
+    let page = t.client.get_listings(&merchant, &0, &20);
+    assert_eq!(page.len(), 2);
+    assert_eq!((page.get_unchecked(1).title.clone(), page.get_unchecked(1).price), (new_title, 350));
+
+    t.client.deactivate_listing(&merchant, &rice);
+    assert!(!t.client.get_listing(&rice).is_active);
+    assert_eq!(t.client.try_create_order(&buyer, &rice), Err(Ok(Error::ListingInactive)));
+    t.client.create_order(&buyer, &mangoes);
+}
+
+#[test]
+fn listings_need_a_title_a_price_and_their_owner() {
+    let t = TestBazaar::default();
+    let (merchant, stranger) = (t.user(), t.user());
+    assert_eq!(list(&t, &merchant, "", 1_000), Err(Error::InvalidListing));
+    assert_eq!(list(&t, &merchant, "Rice sack", 0), Err(Error::InvalidAmount));
+    // Above the value cap is for Silver tier and up
+    assert_eq!(list(&t, &merchant, "Tricycle", 1_000_001), Err(Error::TierTooLow));
+
+    let rice = list(&t, &merchant, "Rice sack", 1_000).unwrap();
+    assert_eq!(t.client.try_deactivate_listing(&stranger, &rice), Err(Ok(Error::NotListingOwner)));
+    assert_eq!(t.client.try_get_listing(&99).err(), Some(Ok(Error::ListingNotFound)));
+    t.set_trust(&merchant, 25);
+    list(&t, &merchant, "Tricycle", 1_000_001).unwrap();
+}
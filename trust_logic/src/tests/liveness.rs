@@ -0,0 +1,216 @@
+// 🏛️ PROJECT BAZAAR | LIVENESS TESTS
+// Heartbeats, passive activity and the per-vault timers they keep alive.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn heartbeat_resets_the_deadman_timer() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    t.advance_days(170);
+    t.client.ping_heartbeat(&owner);
+    t.advance_days(170);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+}
+
+#[test]
+fn separate_vaults_claim_apart_but_share_heartbeat_and_panic() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let family_heir = t.fund_vault(&owner, 1_000);
+    let business_heir = t.fund_vault(&owner, 500);
+    assert_eq!(t.client.list_vaults(&owner), vec![&t.env, 0, 1]);
+    let circle = t.make_circle(&owner, 3);
+    t.client.set_vault_timeout(&owner, &1, &(90 * DAY_SECONDS), &Some(circle.get_unchecked(0)));
+
+    // One heartbeat keeps both vaults alive
+    t.advance_days(80);
+    t.client.ping_heartbeat(&owner);
+    t.advance_days(90);
+    assert_eq!(t.client.try_initiate_claim(&family_heir, &owner, &0), Err(Ok(Error::TimerNotExpired)));
+    assert_eq!(t.client.try_initiate_claim(&family_heir, &owner, &1), Err(Ok(Error::NotHeir)));
+    t.client.initiate_claim(&business_heir, &owner, &1);
+    t.advance_days(14);
+    t.client.finalize_claim(&business_heir, &owner, &1);
+    assert_eq!(t.balance(&t.token, &business_heir), 500);
+    assert_eq!(t.client.get_vault_balance(&owner, &0, &t.token), 1_000);
+
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+    assert!(t.client.get_vault(&owner, &0).is_frozen && t.client.get_vault(&owner, &1).is_frozen);
+}
+
+#[test]
+fn owner_activity_counts_as_a_heartbeat_unless_turned_off() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    t.advance_days(170);
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &100);
+    t.advance_days(170);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+
+    t.client.set_passive_heartbeat(&owner, &PRIMARY_VAULT, &false);
+    t.advance_days(170);
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &100);
+    t.advance_days(10);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn a_heartbeat_delegate_keeps_the_vault_alive() {
+    let t = TestBazaar::default();
+    let (owner, daughter) = (t.user(), t.user());
+    let heir = t.fund_vault(&owner, 1_000);
+    t.client.set_heartbeat_delegate(&owner, &daughter);
+    assert_eq!(t.client.get_heartbeat_delegate(&owner), Some(daughter.clone()));
+
+    t.advance_days(170);
+    t.client.ping_heartbeat_as_delegate(&daughter, &owner);
+    t.advance_days(170);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+}
+
+#[test]
+fn only_the_current_delegate_may_ping() {
+    let t = TestBazaar::default();
+    let (owner, daughter) = (t.user(), t.user());
+    assert_eq!(t.client.try_set_heartbeat_delegate(&owner, &daughter), Err(Ok(Error::VaultNotFound)));
+    t.fund_vault(&owner, 1_000);
+    t.client.set_heartbeat_delegate(&owner, &daughter);
+    assert_eq!(t.client.try_ping_heartbeat_as_delegate(&t.user(), &owner), Err(Ok(Error::NotDelegate)));
+
+    t.client.revoke_heartbeat_delegate(&owner);
+    assert_eq!(t.client.get_heartbeat_delegate(&owner), None);
+    assert_eq!(t.client.try_ping_heartbeat_as_delegate(&daughter, &owner), Err(Ok(Error::NotDelegate)));
+}
+
+#[test]
+fn reminders_fire_inside_the_warning_window_once_per_cooldown() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+
+    t.advance_days(160);
+    assert_eq!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT), 20 * DAY_SECONDS);
+    assert_eq!(t.client.emit_reminder(&owner, &PRIMARY_VAULT), 20 * DAY_SECONDS);
+    t.advance_days(3);
+    assert_eq!(t.client.emit_reminder(&owner, &PRIMARY_VAULT), 17 * DAY_SECONDS);
+}
+
+#[test]
+fn reminders_refuse_early_or_repeated_nudges() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+
+    t.advance_days(150);
+    assert_eq!(t.client.try_emit_reminder(&owner, &PRIMARY_VAULT), Err(Ok(Error::ReminderNotDue)));
+    t.advance_days(10);
+    t.client.emit_reminder(&owner, &PRIMARY_VAULT);
+    t.advance_days(2);
+    assert_eq!(t.client.try_emit_reminder(&owner, &PRIMARY_VAULT), Err(Ok(Error::ReminderCooldown)));
+    assert_eq!(t.client.try_emit_reminder(&t.user(), &PRIMARY_VAULT), Err(Ok(Error::VaultNotFound)));
+}
+
+#[test]
+fn a_delegate_keeps_several_family_vaults_alive_in_one_call() {
+    let t = TestBazaar::default();
+    let (mother, father, helper) = (t.user(), t.user(), t.user());
+    let heirs = [t.fund_vault(&mother, 1_000), t.fund_vault(&father, 1_000)];
+    t.client.set_heartbeat_delegate(&mother, &helper);
+    t.client.set_heartbeat_delegate(&father, &helper);
+
+    t.advance_days(170);
+    t.client.ping_heartbeat_many(&helper, &vec![&t.env, mother.clone(), father.clone()]);
+    t.advance_days(170);
+    assert_eq!(t.client.try_initiate_claim(&heirs[0], &mother, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    assert_eq!(t.client.try_initiate_claim(&heirs[1], &father, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+}
+
+#[test]
+fn batch_heartbeats_refuse_strangers_and_oversized_batches() {
+    let t = TestBazaar::default();
+    let (owner, stranger, helper) = (t.user(), t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    t.fund_vault(&stranger, 1_000);
+    t.client.set_heartbeat_delegate(&owner, &helper);
+
+    assert_eq!(t.client.try_ping_heartbeat_many(&helper, &vec![&t.env, owner.clone(), stranger]), Err(Ok(Error::NotDelegate)));
+    let mut users = Vec::new(&t.env);
+    for _ in 0..11 {
+        users.push_back(owner.clone());
+    }
+    assert_eq!(t.client.try_ping_heartbeat_many(&helper, &users), Err(Ok(Error::BatchTooLarge)));
+}
+
+#[test]
+fn three_witness_attestations_within_two_days_count_as_a_heartbeat() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    // Votes further apart than 48 hours don't combine
+    t.advance_days(100);
+    t.client.attest_alive(&circle.get_unchecked(0), &owner);
+    t.advance_days(3);
+    assert_eq!(t.client.attest_alive(&circle.get_unchecked(1), &owner), 1);
+
+    t.advance_days(1);
+    assert_eq!(t.client.attest_alive(&circle.get_unchecked(2), &owner), 2);
+    assert_eq!(t.client.attest_alive(&circle.get_unchecked(3), &owner), 3);
+    assert_eq!(t.client.get_alive_attestations(&owner).streak, 1);
+    t.advance_days(170);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+}
+
+#[test]
+fn attestations_count_by_weight_and_only_from_current_witnesses() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    t.advance_days(100);
+    t.client.attest_alive(&circle.get_unchecked(0), &owner);
+    t.client.remove_witness(&owner, &circle.get_unchecked(0));
+    assert_eq!(t.client.attest_alive(&circle.get_unchecked(1), &owner), 1);
+
+    // A spouse of weight 2 completes the quorum of 3 on their own
+    t.client.set_witness_weight(&owner, &circle.get_unchecked(2), &2);
+    assert_eq!(t.client.attest_alive(&circle.get_unchecked(2), &owner), 3);
+    assert_eq!(t.client.get_alive_attestations(&owner).streak, 1);
+}
+
+#[test]
+fn attestations_are_rate_limited_and_need_the_owner_back_after_two() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let attest = || {
+        for witness in circle.iter() {
+            t.client.attest_alive(&witness, &owner);
+        }
+    };
+
+    t.client.attest_alive(&circle.get_unchecked(0), &owner);
+    assert_eq!(t.client.try_attest_alive(&circle.get_unchecked(0), &owner), Err(Ok(Error::AlreadyVoted)));
+    assert_eq!(t.client.try_attest_alive(&t.user(), &owner), Err(Ok(Error::NotWitness)));
+    t.advance_days(3);
+    attest();
+    assert_eq!(t.client.try_attest_alive(&circle.get_unchecked(0), &owner), Err(Ok(Error::AttestationCooldown)));
+
+    t.advance_days(90);
+    attest();
+    t.advance_days(90);
+    assert_eq!(t.client.try_attest_alive(&circle.get_unchecked(0), &owner), Err(Ok(Error::AttestationLimit)));
+    t.client.ping_heartbeat(&owner);
+    t.client.attest_alive(&circle.get_unchecked(0), &owner);
+}
@@ -0,0 +1,63 @@
+// 🏛️ PROJECT BAZAAR | LOAN TESTS
+// Bond-backed BZR loans: the tier ceiling, repayment and default.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn a_funded_loan_is_repaid_with_interest_to_the_lender() {
+    let t = TestBazaar::default();
+    let borrower = t.bonded_user(60);
+    let lender = t.user();
+    // Gold borrows up to twice the bond
+    assert_eq!(t.client.get_max_loan(&borrower), 200);
+
+    let id = t.client.request_loan(&borrower, &150, &1_000, &(30 * DAY_SECONDS));
+    t.mint(&t.bzr, &lender, 150);
+    t.client.fund_loan(&lender, &id);
+    assert_eq!(t.balance(&t.bzr, &borrower), 150);
+    t.advance_days(30);
+    assert_eq!(t.client.try_unstake(&borrower), Err(Ok(Error::LoanOutstanding)));
+
+    t.mint(&t.bzr, &borrower, 15);
+    assert_eq!(t.client.repay(&borrower, &id), 165);
+    assert_eq!(t.balance(&t.bzr, &lender), 165);
+    assert_eq!(t.client.get_loan(&id).state, LoanState::Repaid);
+    assert_eq!(t.client.unstake(&borrower), 100);
+}
+
+#[test]
+fn loans_stay_within_the_tier_ceiling_and_one_at_a_time() {
+    let t = TestBazaar::default();
+    let bronze = t.bonded_user(10);
+    assert_eq!(t.client.try_request_loan(&bronze, &1, &0, &DAY_SECONDS), Err(Ok(Error::LoanLimitExceeded)));
+
+    let borrower = t.bonded_user(60);
+    assert_eq!(t.client.try_request_loan(&borrower, &201, &0, &DAY_SECONDS), Err(Ok(Error::LoanLimitExceeded)));
+    assert_eq!(t.client.try_request_loan(&borrower, &100, &3_001, &DAY_SECONDS), Err(Ok(Error::InvalidLoan)));
+    let id = t.client.request_loan(&borrower, &100, &0, &DAY_SECONDS);
+    assert_eq!(t.client.try_request_loan(&borrower, &100, &0, &DAY_SECONDS), Err(Ok(Error::LoanOutstanding)));
+    assert_eq!(t.client.try_fund_loan(&borrower, &id), Err(Ok(Error::InvalidLoan)));
+    t.client.cancel_loan(&borrower, &id);
+    assert_eq!(t.client.get_loan(&id).state, LoanState::Cancelled);
+}
+
+#[test]
+fn an_unpaid_loan_is_slashed_from_the_bond_after_its_term() {
+    let t = TestBazaar::default();
+    let borrower = t.bonded_user(60);
+    let lender = t.user();
+    let id = t.client.request_loan(&borrower, &150, &1_000, &(30 * DAY_SECONDS));
+    t.mint(&t.bzr, &lender, 150);
+    t.client.fund_loan(&lender, &id);
+
+    t.advance_days(30);
+    assert_eq!(t.client.try_mark_default(&id), Err(Ok(Error::LoanNotDue)));
+    t.advance_seconds(1);
+    // The whole bond goes to the lender, short of the 165 owed
+    assert_eq!(t.client.mark_default(&id), 100);
+    assert_eq!(t.balance(&t.bzr, &lender), 100);
+    // 60, less a point of decay for the idle month, less the default penalty
+    assert_eq!(t.client.get_trust(&borrower), 34);
+    assert_eq!(t.client.try_repay(&borrower, &id), Err(Ok(Error::InvalidLoanState)));
+}
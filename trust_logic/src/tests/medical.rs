@@ -0,0 +1,259 @@
+// 🏛️ PROJECT BAZAAR | MEDICAL TESTS
+// Medical emergencies: witness votes, tiered releases to the payee and the vote deadline.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn medical_emergency_releases_by_tier_to_the_payee() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    t.client.set_medical_payee(&owner, &PRIMARY_VAULT, &hospital);
+
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    for i in 0..2 {
+        t.client.witness_vote_medical(&circle.get_unchecked(i), &owner);
+    }
+    assert_eq!(t.client.can_unlock_medical(&owner, &PRIMARY_VAULT), EmergencyCheck::VotesNeeded(1));
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::EmergencyLocked)));
+
+    // Three votes unlock the first tier: 15%
+    t.client.witness_vote_medical(&circle.get_unchecked(2), &owner);
+    assert_eq!(t.client.can_unlock_medical(&owner, &PRIMARY_VAULT), EmergencyCheck::Ready(1_500));
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &150);
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::MedicalTierExceeded)));
+
+    // A fourth vote lifts it to 30%, counted against the balance before any release
+    t.client.witness_vote_medical(&circle.get_unchecked(3), &owner);
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &150);
+    assert_eq!(t.balance(&t.token, &hospital), 300);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 700);
+}
+
+#[test]
+fn medical_emergency_lapses_without_quorum_by_the_vote_deadline() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    t.client.witness_vote_medical(&circle.get_unchecked(0), &owner);
+    t.advance_days(14);
+    assert_eq!(t.client.try_witness_vote_medical(&circle.get_unchecked(1), &owner), Err(Ok(Error::EmergencyExpired)));
+    assert_eq!(t.client.can_unlock_medical(&owner, &PRIMARY_VAULT), EmergencyCheck::Expired);
+
+    // The lapsed emergency no longer blocks a fresh declaration
+    t.client.declare_emergency(&circle.get_unchecked(1), &owner);
+    t.client.witness_vote_medical(&circle.get_unchecked(1), &owner);
+    assert_eq!(t.client.can_unlock_medical(&owner, &PRIMARY_VAULT), EmergencyCheck::VotesNeeded(2));
+}
+
+#[test]
+fn a_quorum_reached_before_the_deadline_outlives_it() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.client.set_medical_payee(&owner, &PRIMARY_VAULT, &hospital);
+    let circle = t.make_circle(&owner, 3);
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    t.client.witness_vote_medical(&first, &owner);
+
+    t.advance_days(13);
+    t.client.witness_vote_medical(&circle.get_unchecked(1), &owner);
+    t.client.witness_vote_medical(&circle.get_unchecked(2), &owner);
+    t.advance_days(1);
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &50);
+    assert_eq!(t.balance(&t.token, &hospital), 50);
+}
+
+#[test]
+fn medical_shares_span_every_vault_whatever_the_draw_order() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 100);
+    t.fund_vault(&owner, 100);
+    let circle = t.make_circle(&owner, 5);
+    for vault_id in [0, 1] {
+        t.client.set_medical_payee(&owner, &vault_id, &hospital);
+    }
+
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    for witness in circle.iter() {
+        t.client.witness_vote_medical(&witness, &owner);
+    }
+    // All five votes unlock 50% of the 200 the owner holds
+    t.client.withdraw_medical(&circle.get_unchecked(0), &owner, &0, &t.token, &50);
+    assert_eq!(t.client.can_unlock_medical(&owner, &1), EmergencyCheck::Ready(5_000));
+    t.client.withdraw_medical(&circle.get_unchecked(0), &owner, &1, &t.token, &50);
+    assert_eq!(t.balance(&t.token, &hospital), 100);
+    assert_eq!(t.client.can_unlock_medical(&owner, &0), EmergencyCheck::Consumed);
+}
+
+#[test]
+fn each_witness_counts_once_towards_the_medical_quorum() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let outsider = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let first = circle.get_unchecked(0);
+
+    t.client.declare_emergency(&first, &owner);
+    t.client.witness_vote_medical(&first, &owner);
+    assert_eq!(t.client.try_witness_vote_medical(&first, &owner), Err(Ok(Error::AlreadyVoted)));
+    assert_eq!(t.client.try_witness_vote_medical(&outsider, &owner), Err(Ok(Error::NotWitness)));
+
+    t.client.witness_vote_medical(&circle.get_unchecked(1), &owner);
+    let status = t.client.get_emergency_status(&owner);
+    assert_eq!(status.votes_collected, 2);
+    assert_eq!(status.voters, vec![&t.env, first, circle.get_unchecked(1)]);
+    assert_eq!(status.pending_witnesses.len(), 3);
+    assert!(!status.is_unlocked);
+}
+
+#[test]
+fn an_unlocked_emergency_expires_after_thirty_days() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.client.set_medical_payee(&owner, &PRIMARY_VAULT, &hospital);
+    let circle = t.make_circle(&owner, 3);
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    for witness in circle.iter() {
+        t.client.witness_vote_medical(&witness, &owner);
+    }
+
+    t.advance_days(29);
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &50);
+    t.advance_days(1);
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &50), Err(Ok(Error::EmergencyExpired)));
+    assert_eq!(t.balance(&t.token, &hospital), 50);
+}
+
+#[test]
+fn the_owner_cancels_an_emergency_and_only_one_runs_at_a_time() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    assert_eq!(t.client.try_cancel_emergency(&owner), Err(Ok(Error::EmergencyNotFound)));
+
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    assert_eq!(t.client.try_declare_emergency(&circle.get_unchecked(1), &owner), Err(Ok(Error::EmergencyActive)));
+    t.client.cancel_emergency(&owner);
+    assert_eq!(t.client.try_witness_vote_medical(&circle.get_unchecked(0), &owner), Err(Ok(Error::EmergencyNotFound)));
+    assert_eq!(t.client.try_cancel_emergency(&owner), Err(Ok(Error::EmergencyNotFound)));
+}
+
+#[test]
+fn only_the_owner_or_an_active_witness_may_declare() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    assert_eq!(t.client.try_declare_emergency(&t.user(), &owner), Err(Ok(Error::NotWitness)));
+    let newcomer = t.user();
+    t.client.add_witness(&owner, &newcomer, &1);
+    t.client.accept_witness_role(&newcomer, &owner);
+    assert_eq!(t.client.try_declare_emergency(&newcomer, &owner), Err(Ok(Error::WitnessPending)));
+
+    t.client.declare_emergency(&owner, &owner);
+    t.client.witness_vote_medical(&circle.get_unchecked(0), &owner);
+    assert_eq!(t.client.get_emergency_status(&owner).votes_collected, 1);
+}
+
+#[test]
+fn a_full_circle_unlocks_half_and_the_year_cap_holds_across_emergencies() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    t.client.set_medical_payee(&owner, &PRIMARY_VAULT, &hospital);
+
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    for witness in circle.iter() {
+        t.client.witness_vote_medical(&witness, &owner);
+    }
+    assert_eq!(t.client.can_unlock_medical(&owner, &PRIMARY_VAULT), EmergencyCheck::Ready(5_000));
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &500);
+    assert_eq!(t.balance(&t.token, &hospital), 500);
+
+    // A fresh emergency inside the same year finds the 50% already spent
+    t.advance_days(31);
+    let second = circle.get_unchecked(1);
+    t.client.declare_emergency(&second, &owner);
+    for i in 1..4 {
+        t.client.witness_vote_medical(&circle.get_unchecked(i), &owner);
+    }
+    assert_eq!(t.client.try_withdraw_medical(&second, &owner, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::MedicalYearCapReached)));
+}
+
+#[test]
+fn without_a_payee_the_witnesses_name_the_recipient() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let (clinic, stray) = (t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    for i in 0..3 {
+        t.client.witness_vote_medical(&circle.get_unchecked(i), &owner);
+    }
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &100), Err(Ok(Error::NoMedicalPayee)));
+
+    t.client.approve_medical_recipient(&circle.get_unchecked(0), &owner, &clinic);
+    t.client.approve_medical_recipient(&circle.get_unchecked(1), &owner, &stray);
+    t.client.approve_medical_recipient(&circle.get_unchecked(2), &owner, &clinic);
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &100), Err(Ok(Error::NoMedicalPayee)));
+    t.client.approve_medical_recipient(&circle.get_unchecked(1), &owner, &clinic);
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &100);
+    assert_eq!(t.balance(&t.token, &clinic), 100);
+    assert_eq!(t.balance(&t.token, &owner), 0);
+}
+
+#[test]
+fn only_voting_witnesses_name_the_medical_recipient() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    t.client.witness_vote_medical(&circle.get_unchecked(0), &owner);
+
+    let clinic = t.user();
+    assert_eq!(t.client.try_approve_medical_recipient(&circle.get_unchecked(1), &owner, &clinic), Err(Ok(Error::NotWitness)));
+    assert_eq!(t.client.try_approve_medical_recipient(&t.user(), &owner, &clinic), Err(Ok(Error::NotWitness)));
+}
+
+#[test]
+fn a_fully_drawn_emergency_is_marked_consumed_and_pays_nothing_more() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let hospital = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    t.client.set_medical_payee(&owner, &PRIMARY_VAULT, &hospital);
+    let first = circle.get_unchecked(0);
+    t.client.declare_emergency(&first, &owner);
+    for witness in circle.iter() {
+        t.client.witness_vote_medical(&witness, &owner);
+    }
+
+    t.client.withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &500);
+    assert_eq!(t.client.try_withdraw_medical(&first, &owner, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::EmergencyConsumed)));
+    assert_eq!(t.balance(&t.token, &hospital), 500);
+}
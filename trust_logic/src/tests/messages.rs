@@ -0,0 +1,38 @@
+// 🏛️ PROJECT BAZAAR | MESSAGING TESTS
+// Bounded inboxes read a page at a time.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn inboxes_page_oldest_first_and_evict_past_fifty() {
+    let t = TestBazaar::default();
+    let (buyer, merchant) = (t.user(), t.user());
+    for _ in 0..55 {
+        t.advance_seconds(1);
+        t.client.send_message(&buyer, &merchant, &String::from_str(&t.env, "May stock pa po?"));
+    }
+    assert_eq!(t.client.get_message_count(&merchant), 50);
+
+    let first_page = t.client.get_messages(&merchant, &0, &100);
+    assert_eq!(first_page.len(), 20);
+    let last_page = t.client.get_messages(&merchant, &40, &20);
+    assert_eq!(last_page.len(), 10);
+    assert_eq!(last_page.last().unwrap().timestamp - first_page.first().unwrap().timestamp, 49);
+    assert!(t.client.get_messages(&merchant, &60, &20).is_empty());
+
+    t.client.clear_inbox(&merchant);
+    assert_eq!(t.client.get_message_count(&merchant), 0);
+}
+
+#[test]
+fn messages_must_hold_between_one_and_280_characters() {
+    let t = TestBazaar::default();
+    let (buyer, merchant) = (t.user(), t.user());
+    let too_long = [b'a'; 281];
+    for text in [String::from_str(&t.env, ""), String::from_bytes(&t.env, &too_long)] {
+        assert_eq!(t.client.try_send_message(&buyer, &merchant, &text), Err(Ok(Error::InvalidMessage)));
+    }
+    t.client.send_message(&buyer, &merchant, &String::from_bytes(&t.env, &too_long[..280]));
+    assert_eq!(t.client.get_message_count(&merchant), 1);
+}
@@ -0,0 +1,50 @@
+// 🏛️ PROJECT BAZAAR | MIGRATION TESTS
+// Carrying v2 users over, from the old contract's getters or an admin snapshot, exactly once.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::{contract, contractimpl};
+
+/// Stands in for the deployed v2 contract, which only ever exposed `get_trust`.
+#[contract]
+struct V2Bazaar;
+
+#[contractimpl]
+impl V2Bazaar {
+    pub fn get_trust(_env: Env, _user: Address) -> u32 {
+        42
+    }
+}
+
+#[test]
+fn users_migrate_their_v2_trust_or_an_admin_snapshot() {
+    let t = TestBazaar::default();
+    let old_contract = t.env.register(V2Bazaar, ());
+    t.client.set_legacy_contract(&old_contract);
+
+    let user = t.user();
+    assert_eq!(t.client.migrate_merchant(&user, &old_contract), 42);
+    assert_eq!(t.client.get_trust(&user), 42);
+    assert!(t.client.is_migrated(&user));
+
+    let (owner, heir) = (t.user(), t.user());
+    let snapshot = V2Snapshot { trust_score: 30, badges: Vec::new(&t.env), heir: Some(heir.clone()) };
+    assert_eq!(t.client.migrate_from_snapshot(&owner, &snapshot), 30);
+    assert_eq!(t.client.get_vault(&owner, &PRIMARY_VAULT).heirs.get_unchecked(0).address, heir);
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).accepted_heirs.contains(&heir));
+}
+
+#[test]
+fn migrations_run_once_from_the_registered_contract_and_into_fresh_accounts() {
+    let t = TestBazaar::default();
+    let old_contract = t.env.register(V2Bazaar, ());
+    let user = t.user();
+    assert_eq!(t.client.try_migrate_merchant(&user, &old_contract), Err(Ok(Error::NotLegacyContract)));
+
+    t.client.set_legacy_contract(&old_contract);
+    t.client.migrate_merchant(&user, &old_contract);
+    assert_eq!(t.client.try_migrate_merchant(&user, &old_contract), Err(Ok(Error::AlreadyMigrated)));
+
+    let active = t.bonded_user(10);
+    assert_eq!(t.client.try_migrate_merchant(&active, &old_contract), Err(Ok(Error::MigrationTargetInUse)));
+}
@@ -0,0 +1,57 @@
+// 🏛️ PROJECT BAZAAR | CONTRACT TESTS
+// End-to-end runs through `TestBazaar`, one module per feature area.
+
+#![cfg(test)]
+
+mod admin;
+mod arbiters;
+mod arbitration;
+mod away;
+mod badges;
+mod blacklist;
+mod buyers;
+mod bzr_wallet;
+mod checks;
+mod circle;
+mod categories;
+mod claims;
+mod contacts;
+mod disputes;
+mod errors;
+mod escrow;
+mod events;
+mod evidence;
+mod governance;
+mod grants;
+mod guardianship;
+mod listings;
+mod liveness;
+mod loans;
+mod medical;
+mod messages;
+mod migration;
+mod oracle;
+mod paluwagan;
+mod panic;
+mod pools;
+mod profile;
+mod rate_limits;
+mod recovery;
+mod referrals;
+mod remittance;
+mod reviews;
+mod rewards;
+mod savings;
+mod spending;
+mod storage;
+mod strategies;
+mod subscriptions;
+mod summary;
+mod treasury;
+mod trust;
+mod user_config;
+mod vault;
+mod verification;
+mod vesting;
+mod vouch_stakes;
+mod witness_stats;
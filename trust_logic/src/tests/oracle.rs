@@ -0,0 +1,37 @@
+// 🏛️ PROJECT BAZAAR | ORACLE TESTS
+// The frozen read interface for allowlisted consumer contracts and its query log.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{symbol_short, IntoVal, Symbol, TryFromVal};
+
+#[test]
+fn allowlisted_consumers_read_trust_tier_and_bond_and_are_logged() {
+    let t = TestBazaar::default();
+    let oracle = TrustOracleClient::new(&t.env, &t.client.address);
+    let (lender, merchant) = (t.user(), t.bonded_user(60));
+    t.client.allowlist_consumer(&lender);
+    assert!(t.client.is_consumer_allowed(&lender));
+
+    assert_eq!(oracle.oracle_get_trust(&lender, &merchant), 60);
+    assert_eq!(oracle.oracle_get_tier(&lender, &merchant), TrustTier::Gold);
+    assert!(oracle.oracle_is_bonded(&lender, &merchant));
+
+    let (_, topics, data) = t.env.events().all().last().unwrap();
+    assert_eq!(topics, (events::ORACLE_QUERY, lender).into_val(&t.env));
+    assert_eq!(<(Address, Symbol)>::try_from_val(&t.env, &data).unwrap(), (merchant, symbol_short!("bonded")));
+}
+
+#[test]
+fn unlisted_or_removed_consumers_are_refused() {
+    let t = TestBazaar::default();
+    let oracle = TrustOracleClient::new(&t.env, &t.client.address);
+    let (lender, merchant) = (t.user(), t.user());
+    assert_eq!(oracle.try_oracle_get_trust(&lender, &merchant), Err(Ok(Error::ConsumerNotAllowed)));
+
+    t.client.allowlist_consumer(&lender);
+    t.client.remove_consumer(&lender);
+    assert_eq!(oracle.try_oracle_get_tier(&lender, &merchant), Err(Ok(Error::ConsumerNotAllowed)));
+    assert_eq!(oracle.try_oracle_is_bonded(&lender, &merchant), Err(Ok(Error::ConsumerNotAllowed)));
+}
@@ -0,0 +1,81 @@
+// 🏛️ PROJECT BAZAAR | PALUWAGAN TESTS
+// Rotating savings: payouts in join order, missed rounds and the bond lock.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn bonded_group(t: &TestBazaar, size: u32) -> (u64, Vec<Address>) {
+    let mut members = Vec::new(&t.env);
+    for _ in 0..size {
+        let member = t.user();
+        t.mint(&t.bzr, &member, 100);
+        t.client.stake(&member);
+        t.mint(&t.token, &member, 1_000);
+        members.push_back(member);
+    }
+    let id = t.client.create_paluwagan(&members.get_unchecked(0), &members, &t.token, &100, &(7 * DAY_SECONDS));
+    (id, members)
+}
+
+#[test]
+fn each_member_takes_the_pot_in_join_order() {
+    let t = TestBazaar::default();
+    let (id, members) = bonded_group(&t, 2);
+    let (first, second) = (members.get_unchecked(0), members.get_unchecked(1));
+    t.client.join_paluwagan(&first, &id);
+    assert_eq!(t.client.try_contribute(&first, &id), Err(Ok(Error::PaluwaganNotActive)));
+    t.client.join_paluwagan(&second, &id);
+
+    t.client.contribute(&first, &id);
+    assert_eq!(t.client.try_contribute(&first, &id), Err(Ok(Error::AlreadyContributed)));
+    t.client.contribute(&second, &id);
+    assert_eq!(t.balance(&t.token, &first), 1_100);
+
+    t.client.contribute(&first, &id);
+    t.client.contribute(&second, &id);
+    assert_eq!(t.balance(&t.token, &first), 1_000);
+    assert_eq!(t.balance(&t.token, &second), 1_000);
+    assert!(t.client.get_paluwagan(&id).is_complete);
+}
+
+#[test]
+fn a_member_cannot_unstake_out_of_an_unfinished_paluwagan() {
+    let t = TestBazaar::default();
+    let (id, members) = bonded_group(&t, 2);
+    let (first, second) = (members.get_unchecked(0), members.get_unchecked(1));
+    t.advance_days(30);
+    t.client.join_paluwagan(&first, &id);
+    assert_eq!(t.client.try_unstake(&first), Err(Ok(Error::PaluwaganOutstanding)));
+    t.client.join_paluwagan(&second, &id);
+    t.client.contribute(&first, &id);
+    t.client.contribute(&second, &id);
+
+    // Paid out already, `first` skips the second round and is slashed for it
+    assert_eq!(t.client.try_unstake(&first), Err(Ok(Error::PaluwaganOutstanding)));
+    t.client.contribute(&second, &id);
+    t.advance_days(8);
+    t.client.settle_round(&id);
+    assert_eq!(t.balance(&t.bzr, &second), 10);
+
+    assert_eq!(t.client.unstake(&first), 90);
+    assert_eq!(t.client.unstake(&second), 100);
+}
+
+#[test]
+fn members_may_leave_only_before_the_group_starts() {
+    let t = TestBazaar::default();
+    let (id, members) = bonded_group(&t, 3);
+    let (first, second) = (members.get_unchecked(0), members.get_unchecked(1));
+    t.client.join_paluwagan(&first, &id);
+    t.client.leave_paluwagan(&first, &id);
+    t.advance_days(30);
+    assert_eq!(t.client.unstake(&first), 100);
+    assert_eq!(t.client.try_leave_paluwagan(&first, &id), Err(Ok(Error::NotMember)));
+
+    t.client.join_paluwagan(&second, &id);
+    t.mint(&t.bzr, &first, 100);
+    t.client.stake(&first);
+    t.client.join_paluwagan(&first, &id);
+    t.client.join_paluwagan(&members.get_unchecked(2), &id);
+    assert_eq!(t.client.try_leave_paluwagan(&second, &id), Err(Ok(Error::PaluwaganStarted)));
+}
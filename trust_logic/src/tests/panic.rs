@@ -0,0 +1,228 @@
+// 🏛️ PROJECT BAZAAR | PANIC TESTS
+// The panic freeze: witness quorum, the published countdown and the rewound deadline.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn panic_quorum_freezes_the_vault_until_the_owner_cancels() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    t.client.panic_button(&circle.get_unchecked(0), &owner);
+    t.client.panic_button(&circle.get_unchecked(1), &owner);
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+    t.client.panic_button(&circle.get_unchecked(2), &owner);
+
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Frozen);
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &100), Err(Ok(Error::VaultFrozen)));
+
+    t.client.cancel_panic(&owner);
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &100);
+    assert_eq!(t.balance(&t.token, &owner), 100);
+}
+
+#[test]
+fn panic_status_counts_down_the_response_window_and_claim() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    assert!(t.client.poke_panic_status(&owner).is_empty());
+
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+    let opened = t.client.poke_panic_status(&owner).get_unchecked(0);
+    t.advance_days(1);
+    let status = t.client.poke_panic_status(&owner).get_unchecked(0);
+    assert_eq!((status.frozen_at, status.response_ends_at, status.claimable_at), (opened.frozen_at, opened.response_ends_at, opened.claimable_at));
+    assert_eq!(status.claimable_in, opened.claimable_in - DAY_SECONDS);
+    assert_eq!(status.response_remaining, 2 * DAY_SECONDS);
+
+    // The published deadline is the one the claim path honours
+    t.advance_seconds(status.claimable_in - 1);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    t.advance_seconds(1);
+    assert_eq!(t.client.poke_panic_status(&owner).get_unchecked(0).claimable_in, 0);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+    assert!(t.client.poke_panic_status(&owner).is_empty());
+}
+
+#[test]
+fn only_frozen_vaults_get_a_status_checkpoint() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let second = t.client.create_vault(&owner, &t.user());
+    let circle = t.make_circle(&owner, 3);
+    // A stranger with no vaults at all reads as nothing frozen
+    assert!(t.client.poke_panic_status(&t.user()).is_empty());
+
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+    t.client.cancel_panic(&owner);
+    assert!(t.client.poke_panic_status(&owner).is_empty());
+    assert!(!t.client.get_vault(&owner, &second).is_frozen);
+}
+
+#[test]
+fn weighted_witnesses_reach_the_panic_quorum_sooner() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let spouse = circle.get_unchecked(0);
+    assert_eq!(t.client.try_set_witness_weight(&owner, &spouse, &4), Err(Ok(Error::InvalidWitnessWeight)));
+    t.client.set_witness_weight(&owner, &spouse, &2);
+
+    t.client.panic_button(&spouse, &owner);
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+    t.client.panic_button(&circle.get_unchecked(1), &owner);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Frozen);
+}
+
+#[test]
+fn panic_freeze_lets_the_heirs_claim_once_the_rewound_deadline_passes() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+
+    // 72-hour response window plus the 7-day panic grace, far ahead of the 180-day timer
+    t.advance_days(9);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    t.advance_days(1);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Claimed);
+}
+
+#[test]
+fn a_frozen_vault_refuses_relayer_and_delegate_changes() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let delegate = t.user();
+    t.client.set_heartbeat_delegate(&owner, &delegate);
+    let circle = t.make_circle(&owner, 3);
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+
+    let relayer = t.user();
+    assert_eq!(t.client.try_set_claim_relayer(&owner, &PRIMARY_VAULT, &Some(relayer.clone()), &100), Err(Ok(Error::VaultFrozen)));
+    assert_eq!(t.client.try_revoke_heartbeat_delegate(&owner), Err(Ok(Error::VaultFrozen)));
+    assert_eq!(t.client.try_cancel_heir_change(&owner, &PRIMARY_VAULT), Err(Ok(Error::VaultFrozen)));
+
+    t.client.cancel_panic(&owner);
+    t.client.set_claim_relayer(&owner, &PRIMARY_VAULT, &Some(relayer), &100);
+    t.client.revoke_heartbeat_delegate(&owner);
+}
+
+#[test]
+fn a_frozen_vault_refuses_heir_witness_and_bond_changes() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+
+    let heirs = vec![&t.env, HeirShare { address: heir, bps: 10_000 }];
+    assert_eq!(t.client.try_set_heirs(&owner, &PRIMARY_VAULT, &heirs), Err(Ok(Error::VaultFrozen)));
+    assert_eq!(t.client.try_assign_witnesses(&owner, &vec![&t.env, (t.user(), 1)]), Err(Ok(Error::VaultFrozen)));
+    assert_eq!(t.client.try_unstake(&owner), Err(Ok(Error::VaultFrozen)));
+
+    t.client.cancel_panic(&owner);
+    t.client.set_heirs(&owner, &PRIMARY_VAULT, &heirs);
+}
+
+#[test]
+fn panic_votes_are_tracked_once_per_witness() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let outsider = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let (first, second) = (circle.get_unchecked(0), circle.get_unchecked(1));
+
+    t.client.panic_button(&first, &owner);
+    t.advance_seconds(60);
+    t.client.panic_button(&second, &owner);
+    assert_eq!(t.client.try_panic_button(&first, &owner), Err(Ok(Error::AlreadyVoted)));
+    assert_eq!(t.client.try_panic_button(&outsider, &owner), Err(Ok(Error::NotWitness)));
+
+    assert_eq!(t.client.get_panic_voters(&owner), vec![&t.env, first.clone(), second.clone()]);
+    let votes = t.client.get_panic_votes(&owner);
+    assert_eq!(votes.get_unchecked(second) - votes.get_unchecked(first), 60);
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+}
+
+#[test]
+fn the_owner_veto_restores_the_timer_and_needs_a_freeze() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    assert_eq!(t.client.try_cancel_panic(&owner), Err(Ok(Error::NotFrozen)));
+
+    let due = t.client.heartbeat_due(&owner, &PRIMARY_VAULT);
+    for witness in circle.iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+    assert!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT) < due);
+
+    t.client.cancel_panic(&owner);
+    let vault = t.client.get_vault(&owner, &PRIMARY_VAULT);
+    assert!(!vault.is_frozen && vault.frozen_at.is_none());
+    assert_eq!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT), due);
+    assert!(t.client.get_panic_voters(&owner).is_empty());
+    assert_eq!(t.client.try_cancel_panic(&owner), Err(Ok(Error::NotFrozen)));
+}
+
+#[test]
+fn panic_votes_older_than_the_window_no_longer_count() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    t.client.panic_button(&circle.get_unchecked(0), &owner);
+    t.client.panic_button(&circle.get_unchecked(1), &owner);
+    t.advance_seconds(3 * DAY_SECONDS);
+    t.client.panic_button(&circle.get_unchecked(2), &owner);
+
+    assert_eq!(t.client.get_panic_voters(&owner), vec![&t.env, circle.get_unchecked(2)]);
+    assert!(!t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+}
+
+#[test]
+fn a_stale_panic_vote_may_be_cast_again_but_a_live_one_may_not() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let first = circle.get_unchecked(0);
+
+    t.client.panic_button(&first, &owner);
+    t.advance_seconds(3 * DAY_SECONDS - 1);
+    assert_eq!(t.client.try_panic_button(&first, &owner), Err(Ok(Error::AlreadyVoted)));
+    t.advance_seconds(1);
+    t.client.panic_button(&first, &owner);
+    t.client.panic_button(&circle.get_unchecked(1), &owner);
+    t.client.panic_button(&circle.get_unchecked(2), &owner);
+    assert!(t.client.get_vault(&owner, &PRIMARY_VAULT).is_frozen);
+}
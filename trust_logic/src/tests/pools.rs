@@ -0,0 +1,45 @@
+// 🏛️ PROJECT BAZAAR | GIFT POOL TESTS
+// Collections paid to the beneficiary, or refunded when the minimum isn't met.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn open_pool(t: &TestBazaar, organizer: &Address, beneficiary: &Address, min_amount: i128) -> u64 {
+    let deadline = t.env.ledger().timestamp() + 7 * DAY_SECONDS;
+    t.client.create_pool(organizer, beneficiary, &t.token, &deadline, &min_amount)
+}
+
+#[test]
+fn the_organizer_closes_early_once_the_minimum_is_in() {
+    let t = TestBazaar::default();
+    let (organizer, family, neighbour) = (t.user(), t.user(), t.user());
+    let id = open_pool(&t, &organizer, &family, 300);
+    t.mint(&t.token, &neighbour, 400);
+    t.client.contribute_pool(&neighbour, &id, &100);
+    assert_eq!(t.client.try_finalize_pool(&organizer, &id), Err(Ok(Error::PoolOpen)));
+
+    assert_eq!(t.client.contribute_pool(&neighbour, &id, &250), 350);
+    assert_eq!(t.client.try_finalize_pool(&neighbour, &id), Err(Ok(Error::PoolOpen)));
+    assert_eq!(t.client.finalize_pool(&organizer, &id), PoolStatus::Paid);
+    assert_eq!(t.balance(&t.token, &family), 350);
+    assert_eq!(t.client.try_contribute_pool(&neighbour, &id, &50), Err(Ok(Error::PoolClosed)));
+}
+
+#[test]
+fn a_pool_short_of_its_minimum_refunds_each_contributor() {
+    let t = TestBazaar::default();
+    let (organizer, family, neighbour, cousin) = (t.user(), t.user(), t.user(), t.user());
+    let id = open_pool(&t, &organizer, &family, 1_000);
+    for (contributor, amount) in [(&neighbour, 100), (&cousin, 200)] {
+        t.mint(&t.token, contributor, amount);
+        t.client.contribute_pool(contributor, &id, &amount);
+    }
+    assert_eq!(t.client.try_refund_pool(&neighbour, &id), Err(Ok(Error::PoolNotFailed)));
+
+    t.advance_days(7);
+    assert_eq!(t.client.finalize_pool(&cousin, &id), PoolStatus::Failed);
+    assert_eq!(t.client.refund_pool(&cousin, &id), 200);
+    assert_eq!(t.client.try_refund_pool(&cousin, &id), Err(Ok(Error::NothingToRefund)));
+    assert_eq!(t.client.try_refund_pool(&organizer, &id), Err(Ok(Error::NothingToRefund)));
+    assert_eq!(t.balance(&t.token, &family), 0);
+}
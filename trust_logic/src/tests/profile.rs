@@ -0,0 +1,67 @@
+// 🏛️ PROJECT BAZAAR | PROFILE TESTS
+// Unique nickname handles and the storefront metadata behind them.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::{symbol_short, BytesN, String};
+
+#[test]
+fn changing_a_nickname_releases_the_old_handle() {
+    let t = TestBazaar::default();
+    let (user, other) = (t.user(), t.user());
+
+    t.client.register_nickname(&user, &symbol_short!("juan"));
+    assert_eq!(t.client.resolve_nickname(&symbol_short!("juan")), Some(user.clone()));
+    t.client.register_nickname(&user, &symbol_short!("juanito"));
+    assert_eq!(t.client.resolve_nickname(&symbol_short!("juan")), None);
+    assert_eq!(t.client.resolve_nickname(&symbol_short!("juanito")), Some(user));
+
+    t.client.register_nickname(&other, &symbol_short!("juan"));
+    assert_eq!(t.client.resolve_nickname(&symbol_short!("juan")), Some(other));
+}
+
+#[test]
+fn nicknames_refuse_the_default_and_taken_handles() {
+    let t = TestBazaar::default();
+    let (user, other) = (t.user(), t.user());
+    t.client.register_nickname(&user, &symbol_short!("juan"));
+
+    assert_eq!(t.client.try_register_nickname(&other, &symbol_short!("juan")), Err(Ok(Error::NicknameTaken)));
+    assert_eq!(t.client.try_register_nickname(&other, &symbol_short!("User")), Err(Ok(Error::InvalidNickname)));
+    assert_eq!(t.client.resolve_nickname(&symbol_short!("juan")), Some(user));
+}
+
+fn storefront(t: &TestBazaar, display_name: &str, tags: u32) -> MerchantProfile {
+    let all = vec![&t.env, symbol_short!("food"), symbol_short!("snacks"), symbol_short!("drinks"), symbol_short!("load"), symbol_short!("ice"), symbol_short!("gas")];
+    MerchantProfile {
+        display_name: String::from_str(&t.env, display_name),
+        bio_hash: BytesN::from_array(&t.env, &[7; 32]),
+        location: symbol_short!("PH_CEB"),
+        tags: all.slice(0..tags),
+    }
+}
+
+#[test]
+fn profiles_store_the_storefront_metadata() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    assert!(t.client.get_profile(&merchant).is_none());
+
+    t.client.set_profile(&merchant, &storefront(&t, "Aling Nena's Sari-Sari", 5));
+    let profile = t.client.get_profile(&merchant).unwrap();
+    assert_eq!(profile.display_name, String::from_str(&t.env, "Aling Nena's Sari-Sari"));
+    assert_eq!(profile.bio_hash, BytesN::from_array(&t.env, &[7; 32]));
+    assert_eq!(profile.location, symbol_short!("PH_CEB"));
+    assert_eq!(profile.tags.len(), 5);
+}
+
+#[test]
+fn profiles_refuse_blank_or_oversized_metadata() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+
+    assert_eq!(t.client.try_set_profile(&merchant, &storefront(&t, "", 1)), Err(Ok(Error::InvalidProfile)));
+    assert_eq!(t.client.try_set_profile(&merchant, &storefront(&t, &"x".repeat(51), 1)), Err(Ok(Error::InvalidProfile)));
+    assert_eq!(t.client.try_set_profile(&merchant, &storefront(&t, "Nena", 6)), Err(Ok(Error::InvalidProfile)));
+    assert!(t.client.get_profile(&merchant).is_none());
+}
@@ -0,0 +1,50 @@
+// 🏛️ PROJECT BAZAAR | RATE LIMIT TESTS
+// Cooldowns on vouches, emergency declarations and panic attempts.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn vouches_and_declarations_open_up_again_once_their_window_passes() {
+    let t = TestBazaar::default();
+    let (owner, merchant) = (t.user(), t.user());
+    let voucher = t.bonded_user(60);
+    t.mint(&t.bzr, &voucher, 10);
+    t.client.vouch(&voucher, &merchant);
+    t.client.unvouch(&voucher, &merchant);
+    t.advance_days(30);
+    t.client.vouch(&voucher, &merchant);
+
+    t.fund_vault(&owner, 1_000);
+    let declarer = t.make_circle(&owner, 3).get_unchecked(0);
+    t.client.declare_emergency(&declarer, &owner);
+    t.client.cancel_emergency(&owner);
+    t.advance_days(90);
+    t.client.declare_emergency(&declarer, &owner);
+}
+
+#[test]
+fn repeated_vouches_declarations_and_panic_attempts_are_refused() {
+    let t = TestBazaar::default();
+    let (owner, merchant) = (t.user(), t.user());
+    let voucher = t.bonded_user(60);
+    t.mint(&t.bzr, &voucher, 10);
+    t.client.vouch(&voucher, &merchant);
+    t.client.unvouch(&voucher, &merchant);
+    t.advance_days(29);
+    assert_eq!(t.client.try_vouch(&voucher, &merchant), Err(Ok(Error::VouchCooldown)));
+
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    t.client.cancel_emergency(&owner);
+    assert_eq!(t.client.try_declare_emergency(&circle.get_unchecked(0), &owner), Err(Ok(Error::RateLimited)));
+    t.client.declare_emergency(&circle.get_unchecked(1), &owner);
+
+    // Each lapsed panic vote used up one of the circle's three yearly attempts
+    for _ in 0..3 {
+        t.client.panic_button(&circle.get_unchecked(2), &owner);
+        t.advance_days(3);
+    }
+    assert_eq!(t.client.try_panic_button(&circle.get_unchecked(2), &owner), Err(Ok(Error::RateLimited)));
+}
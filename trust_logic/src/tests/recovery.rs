@@ -0,0 +1,154 @@
+// 🏛️ PROJECT BAZAAR | RECOVERY TESTS
+// Social recovery: the weighted quorum, expiry, the deny list and what moves with the account.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn deny_list_blocks_social_recovery_in_both_directions() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let fresh = t.user();
+
+    t.client.blacklist(&owner, &1);
+    assert_eq!(t.client.try_vote_recovery(&circle.get_unchecked(0), &owner, &fresh), Err(Ok(Error::Blacklisted)));
+    t.client.unblacklist(&owner);
+
+    for i in 0..4 {
+        t.client.vote_recovery(&circle.get_unchecked(i), &owner, &fresh);
+    }
+    // A listing that lands during the delay still stops the move
+    t.client.blacklist(&owner, &1);
+    t.advance_days(14);
+    assert_eq!(t.client.try_recover_account(&owner), Err(Ok(Error::Blacklisted)));
+    assert_eq!(t.client.list_vaults(&fresh).len(), 0);
+}
+
+#[test]
+fn recovery_survives_a_stray_vote_and_lapses_without_agreement() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let (fresh, stray) = (t.user(), t.user());
+
+    // A lone vote for the wrong address no longer pins the request
+    t.client.vote_recovery(&circle.get_unchecked(0), &owner, &stray);
+    t.advance_days(30);
+    assert!(t.client.get_recovery(&owner).is_none());
+
+    t.client.vote_recovery(&circle.get_unchecked(0), &owner, &stray);
+    for i in 1..4 {
+        t.client.vote_recovery(&circle.get_unchecked(i), &owner, &fresh);
+    }
+    assert!(t.client.get_recovery(&owner).unwrap().approved_at.is_none());
+    assert_eq!(t.client.vote_recovery(&circle.get_unchecked(0), &owner, &fresh), 4);
+
+    t.advance_days(14);
+    assert_eq!(t.client.recover_account(&owner), fresh);
+    assert_eq!(t.client.get_vault_balance(&fresh, &PRIMARY_VAULT, &t.token), 1_000);
+}
+
+#[test]
+fn the_recovery_quorum_counts_circle_weight_and_heads() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    let fresh = t.user();
+    // Weights 3 + 3 + 1: three quarters of 7 rounds up to 6
+    t.client.set_witness_weight(&owner, &circle.get_unchecked(0), &3);
+    t.client.set_witness_weight(&owner, &circle.get_unchecked(1), &3);
+
+    assert_eq!(t.client.vote_recovery(&circle.get_unchecked(0), &owner, &fresh), 3);
+    assert_eq!(t.client.vote_recovery(&circle.get_unchecked(1), &owner, &fresh), 6);
+    // Enough weight, but two witnesses aren't enough on their own
+    assert!(t.client.get_recovery(&owner).unwrap().approved_at.is_none());
+    assert_eq!(t.client.vote_recovery(&circle.get_unchecked(2), &owner, &fresh), 7);
+    assert!(t.client.get_recovery(&owner).unwrap().approved_at.is_some());
+}
+
+#[test]
+fn recovery_carries_goals_subscriptions_pools_and_paluwagan_seats() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let (fresh, landlord, friend) = (t.user(), t.user(), t.user());
+
+    t.mint(&t.token, &owner, 500);
+    let goal = t.client.create_goal(&owner, &t.token, &1_000, &(t.env.ledger().timestamp() + 60 * DAY_SECONDS));
+    t.client.deposit_goal(&owner, &goal, &200);
+    let rent = t.client.create_subscription(&owner, &landlord, &t.token, &100, &(30 * DAY_SECONDS));
+    let pool = t.client.create_pool(&friend, &owner, &t.token, &(t.env.ledger().timestamp() + 30 * DAY_SECONDS), &0);
+    let members = vec![&t.env, owner.clone(), friend.clone()];
+    for member in members.iter() {
+        t.mint(&t.bzr, &member, 100);
+        t.client.stake(&member);
+    }
+    let group = t.client.create_paluwagan(&friend, &members, &t.token, &50, &(7 * DAY_SECONDS));
+    t.client.join_paluwagan(&owner, &group);
+
+    for i in 0..4 {
+        t.client.vote_recovery(&circle.get_unchecked(i), &owner, &fresh);
+    }
+    t.advance_days(14);
+    t.client.recover_account(&owner);
+
+    assert_eq!(t.client.get_goal(&goal).owner, fresh);
+    assert_eq!(t.client.get_subscription(&rent).payer, fresh);
+    assert_eq!(t.client.get_pool(&pool).beneficiary, fresh);
+    let seats = t.client.get_paluwagan(&group);
+    assert_eq!((seats.members.get_unchecked(0), seats.joined.get_unchecked(0)), (fresh.clone(), fresh.clone()));
+    assert_eq!(t.client.cancel_subscription(&fresh, &rent), 0);
+    // The bond moved too, and stays locked behind the paluwagan seat
+    t.advance_days(30);
+    assert_eq!(t.client.try_unstake(&fresh), Err(Ok(Error::PaluwaganOutstanding)));
+}
+
+#[test]
+fn recovery_refuses_a_target_that_already_holds_records() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+    let fresh = t.user();
+    let landlord = t.user();
+    // Someone else's subscription paying `fresh` is enough to block the move
+    t.client.create_subscription(&landlord, &fresh, &t.token, &100, &(30 * DAY_SECONDS));
+
+    for i in 0..4 {
+        t.client.vote_recovery(&circle.get_unchecked(i), &owner, &fresh);
+    }
+    t.advance_days(14);
+    assert_eq!(t.client.try_recover_account(&owner), Err(Ok(Error::RecoveryTargetInUse)));
+    assert_eq!(t.client.list_vaults(&owner).len(), 1);
+    assert_eq!(t.client.list_vaults(&fresh).len(), 0);
+}
+
+#[test]
+fn a_recovered_minor_heir_keeps_their_guarded_inheritance() {
+    let t = TestBazaar::default();
+    let (owner, guardian, fresh) = (t.user(), t.user(), t.user());
+    let heir = t.fund_vault(&owner, 1_000);
+    let unlock_at = t.env.ledger().timestamp() + 400 * DAY_SECONDS;
+    t.client.set_minor_heir(&owner, &PRIMARY_VAULT, &heir, &MinorTerms { guardian, unlock_at, allowance_bps: 1_000 });
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+
+    let circle = t.make_circle(&heir, 5);
+    for i in 0..4 {
+        t.client.vote_recovery(&circle.get_unchecked(i), &heir, &fresh);
+    }
+    t.advance_days(14);
+    t.client.recover_account(&heir);
+    assert_eq!(t.client.try_get_guarded_funds(&owner, &heir).err(), Some(Ok(Error::NoGuardedFunds)));
+
+    t.advance_days(206);
+    assert_eq!(t.client.release_guarded_funds(&fresh, &owner).get_unchecked(t.token.clone()), 1_000);
+    assert_eq!(t.balance(&t.token, &fresh), 1_000);
+}
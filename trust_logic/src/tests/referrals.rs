@@ -0,0 +1,45 @@
+// 🏛️ PROJECT BAZAAR | REFERRAL TESTS
+// Newcomers attributed to a Silver-tier referrer and the rewards both sides accrue.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn both_sides_accrue_once_per_milestone_the_newcomer_reaches() {
+    let t = TestBazaar::default();
+    t.client.set_reward_rates(&RewardRates { per_order: 0, per_vouch: 0, per_contribution: 0, per_referral: 10 });
+    let (referrer, newcomer) = (t.user(), t.user());
+    t.set_trust(&referrer, 25);
+    t.client.register_with_referral(&newcomer, &referrer);
+    assert_eq!(t.client.get_referral(&newcomer).unwrap().referrer, referrer);
+
+    t.mint(&t.bzr, &newcomer, 100);
+    t.client.stake(&newcomer);
+    let order = t.funded_order(&t.user(), &newcomer, 100);
+    t.client.release_escrow(&newcomer, &order);
+    let second = t.funded_order(&t.user(), &newcomer, 100);
+    t.client.release_escrow(&newcomer, &second);
+
+    let milestones = t.client.get_referral(&newcomer).unwrap().milestones;
+    assert_eq!(milestones, vec![&t.env, ReferralMilestone::FirstStake, ReferralMilestone::FirstOrder]);
+    assert_eq!((t.client.get_pending_rewards(&newcomer), t.client.get_pending_rewards(&referrer)), (20, 20));
+}
+
+#[test]
+fn referrals_need_a_silver_referrer_a_fresh_user_and_a_monthly_slot() {
+    let t = TestBazaar::default();
+    let referrer = t.user();
+    t.set_trust(&referrer, 24);
+    assert_eq!(t.client.try_register_with_referral(&t.user(), &referrer), Err(Ok(Error::TierTooLow)));
+    t.set_trust(&referrer, 25);
+    assert_eq!(t.client.try_register_with_referral(&referrer, &referrer), Err(Ok(Error::InvalidReferral)));
+    let existing = t.bonded_user(0);
+    assert_eq!(t.client.try_register_with_referral(&existing, &referrer), Err(Ok(Error::AlreadyRegistered)));
+
+    for _ in 0..10 {
+        t.client.register_with_referral(&t.user(), &referrer);
+    }
+    assert_eq!(t.client.try_register_with_referral(&t.user(), &referrer), Err(Ok(Error::ReferralLimitReached)));
+    t.advance_days(30);
+    t.client.register_with_referral(&t.user(), &referrer);
+}
@@ -0,0 +1,46 @@
+// 🏛️ PROJECT BAZAAR | REMITTANCE TESTS
+// Direct sends with memo and fee, the shared history log and volume milestones.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::String;
+
+#[test]
+fn remittances_deliver_net_of_fee_and_log_both_sides() {
+    let t = TestBazaar::default();
+    t.client.set_fee_config(&FeeConfig { escrow_bps: 0, remit_bps: 100, claim_bps: 0 });
+    let (sender, recipient) = (t.user(), t.user());
+    t.mint(&t.token, &sender, 20_000);
+    let trust = t.client.get_trust(&sender);
+
+    let memo = String::from_str(&t.env, "tuition");
+    assert_eq!(t.client.remit(&sender, &recipient, &t.token, &1_000, &memo), 990);
+    assert_eq!(t.balance(&t.token, &recipient), 990);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 10);
+
+    let sent = t.client.get_remittances(&sender, &0, &10).get_unchecked(0);
+    assert_eq!((sent.amount, sent.fee, sent.memo), (990, 10, memo.clone()));
+    assert_eq!(t.client.get_remittances(&recipient, &0, &10).len(), 1);
+
+    // The tenth send is a volume milestone
+    for _ in 1..9 {
+        t.client.remit(&sender, &recipient, &t.token, &1_000, &memo);
+    }
+    assert_eq!(t.client.get_trust(&sender), trust);
+    t.client.remit(&sender, &recipient, &t.token, &1_000, &memo);
+    assert_eq!(t.client.get_trust(&sender), trust + 1);
+}
+
+#[test]
+fn remittances_refuse_self_sends_empty_amounts_and_long_memos() {
+    let t = TestBazaar::default();
+    let (sender, recipient) = (t.user(), t.user());
+    t.mint(&t.token, &sender, 1_000);
+    let memo = String::from_str(&t.env, "rent");
+
+    assert_eq!(t.client.try_remit(&sender, &sender, &t.token, &100, &memo), Err(Ok(Error::SelfRemit)));
+    assert_eq!(t.client.try_remit(&sender, &recipient, &t.token, &0, &memo), Err(Ok(Error::InvalidAmount)));
+    let long = String::from_str(&t.env, &"x".repeat(65));
+    assert_eq!(t.client.try_remit(&sender, &recipient, &t.token, &100, &long), Err(Ok(Error::InvalidMemo)));
+    assert_eq!(t.client.get_remittances(&sender, &0, &10).len(), 0);
+}
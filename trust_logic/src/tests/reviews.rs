@@ -0,0 +1,51 @@
+// 🏛️ PROJECT BAZAAR | REVIEW TESTS
+// One rating per released order, the running aggregate and its pull on trust.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+fn released_order(t: &TestBazaar, merchant: &Address, buyer: &Address) -> u64 {
+    let order = t.funded_order(merchant, buyer, 1_000);
+    t.client.mark_shipped(merchant, &order, &BytesN::from_array(&t.env, &[1; 32]));
+    t.client.confirm_delivery(buyer, &order);
+    order
+}
+
+#[test]
+fn ratings_aggregate_and_move_trust_with_the_average() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let comment = BytesN::from_array(&t.env, &[9; 32]);
+
+    let first = released_order(&t, &merchant, &buyer);
+    let trust = t.client.get_trust(&merchant);
+    t.client.rate_merchant(&buyer, &first, &5, &comment);
+    assert_eq!(t.client.get_review(&first).unwrap().stars, 5);
+    assert_eq!(t.client.get_trust(&merchant), trust + 4);
+
+    // A 1-star review pulls the average back to neutral and takes the bonus away
+    let second = released_order(&t, &merchant, &buyer);
+    let trust = t.client.get_trust(&merchant);
+    t.client.rate_merchant(&buyer, &second, &1, &comment);
+    let summary = t.client.get_rating(&merchant);
+    assert_eq!((summary.count, summary.sum, summary.trust_bonus), (2, 6, 0));
+    assert_eq!(t.client.get_trust(&merchant), trust - 4);
+}
+
+#[test]
+fn ratings_need_the_buyer_a_released_order_and_a_first_review() {
+    let t = TestBazaar::default();
+    let (merchant, buyer) = (t.user(), t.user());
+    let comment = BytesN::from_array(&t.env, &[9; 32]);
+
+    let pending = t.funded_order(&merchant, &buyer, 1_000);
+    assert_eq!(t.client.try_rate_merchant(&buyer, &pending, &4, &comment), Err(Ok(Error::InvalidOrderState)));
+
+    let order = released_order(&t, &merchant, &buyer);
+    assert_eq!(t.client.try_rate_merchant(&buyer, &order, &0, &comment), Err(Ok(Error::InvalidRating)));
+    assert_eq!(t.client.try_rate_merchant(&buyer, &order, &6, &comment), Err(Ok(Error::InvalidRating)));
+    assert_eq!(t.client.try_rate_merchant(&merchant, &order, &5, &comment), Err(Ok(Error::NotOrderParty)));
+    t.client.rate_merchant(&buyer, &order, &4, &comment);
+    assert_eq!(t.client.try_rate_merchant(&buyer, &order, &5, &comment), Err(Ok(Error::AlreadyRated)));
+}
@@ -0,0 +1,48 @@
+// 🏛️ PROJECT BAZAAR | REWARD TESTS
+// BZR accrued for released orders and vouches, and claims against the funded pool.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+fn set_rates(t: &TestBazaar) {
+    t.client.set_reward_rates(&RewardRates { per_order: 20, per_vouch: 10, per_contribution: 5, per_referral: 0 });
+}
+
+#[test]
+fn positive_behaviour_accrues_bzr_paid_from_the_pool() {
+    let t = TestBazaar::default();
+    set_rates(&t);
+    let (merchant, buyer) = (t.user(), t.user());
+    let order = t.funded_order(&merchant, &buyer, 1_000);
+    t.client.mark_shipped(&merchant, &order, &BytesN::from_array(&t.env, &[1; 32]));
+    t.client.confirm_delivery(&buyer, &order);
+    let voucher = t.bonded_user(60);
+    t.mint(&t.bzr, &voucher, 5);
+    t.client.vouch(&voucher, &merchant);
+    assert_eq!(t.client.get_pending_rewards(&merchant), 30);
+
+    // A short pool pays what it holds and leaves the rest accrued
+    let sponsor = t.user();
+    t.mint(&t.bzr, &sponsor, 25);
+    t.client.fund_rewards(&sponsor, &25);
+    assert_eq!(t.client.claim_rewards(&merchant), 25);
+    assert_eq!(t.balance(&t.bzr, &merchant), 25);
+    assert_eq!((t.client.get_pending_rewards(&merchant), t.client.get_reward_pool()), (5, 0));
+}
+
+#[test]
+fn rewards_refuse_bad_rates_empty_claims_and_an_empty_pool() {
+    let t = TestBazaar::default();
+    let negative = RewardRates { per_order: -1, per_vouch: 0, per_contribution: 0, per_referral: 0 };
+    assert_eq!(t.client.try_set_reward_rates(&negative), Err(Ok(Error::InvalidConfig)));
+    set_rates(&t);
+
+    let (merchant, target) = (t.user(), t.user());
+    assert_eq!(t.client.try_fund_rewards(&merchant, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_claim_rewards(&merchant), Err(Ok(Error::NothingToClaim)));
+    let voucher = t.bonded_user(60);
+    t.mint(&t.bzr, &voucher, 5);
+    t.client.vouch(&voucher, &target);
+    assert_eq!(t.client.try_claim_rewards(&target), Err(Ok(Error::RewardPoolEmpty)));
+}
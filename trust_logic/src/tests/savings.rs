@@ -0,0 +1,62 @@
+// 🏛️ PROJECT BAZAAR | SAVINGS GOAL TESTS
+// Goals stay locked until the target or the unlock date, except through the emergency break.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn open_goal(t: &TestBazaar, saver: &Address, target: i128) -> u64 {
+    let unlock = t.env.ledger().timestamp() + 30 * DAY_SECONDS;
+    t.client.create_goal(saver, &t.token, &target, &unlock)
+}
+
+#[test]
+fn a_goal_met_early_pays_out_and_earns_the_badge() {
+    let t = TestBazaar::default();
+    let saver = t.user();
+    t.mint(&t.token, &saver, 1_000);
+    let id = open_goal(&t, &saver, 500);
+
+    t.client.deposit_goal(&saver, &id, &300);
+    assert_eq!(t.client.try_withdraw_goal(&saver, &id), Err(Ok(Error::GoalLocked)));
+    assert!(!t.client.has_badge(&saver, &BADGE_GOAL_MET));
+
+    assert_eq!(t.client.deposit_goal(&saver, &id, &200), 500);
+    assert!(t.client.has_badge(&saver, &BADGE_GOAL_MET));
+    assert_eq!(t.client.withdraw_goal(&saver, &id), 500);
+    assert_eq!(t.balance(&t.token, &saver), 1_000);
+    assert!(t.client.get_goal(&id).is_closed);
+    assert_eq!(t.client.try_deposit_goal(&saver, &id, &10), Err(Ok(Error::GoalClosed)));
+}
+
+#[test]
+fn breaking_a_goal_costs_the_fee_and_strangers_are_refused() {
+    let t = TestBazaar::default();
+    let (saver, stranger) = (t.user(), t.user());
+    t.mint(&t.token, &saver, 1_000);
+    let past = t.env.ledger().timestamp();
+    assert_eq!(t.client.try_create_goal(&saver, &t.token, &500, &past), Err(Ok(Error::InvalidGoal)));
+    assert_eq!(t.client.try_create_goal(&saver, &t.token, &0, &(past + DAY_SECONDS)), Err(Ok(Error::InvalidAmount)));
+
+    let id = open_goal(&t, &saver, 5_000);
+    t.client.deposit_goal(&saver, &id, &1_000);
+    assert_eq!(t.client.try_withdraw_goal(&stranger, &id), Err(Ok(Error::NotGoalOwner)));
+    assert_eq!(t.client.try_break_goal(&stranger, &id), Err(Ok(Error::NotGoalOwner)));
+    assert_eq!(t.client.try_get_goal(&99).err(), Some(Ok(Error::GoalNotFound)));
+
+    assert_eq!(t.client.break_goal(&saver, &id), 950);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 50);
+    assert_eq!(t.client.try_break_goal(&saver, &id), Err(Ok(Error::GoalClosed)));
+}
+
+#[test]
+fn a_short_goal_unlocks_on_its_date() {
+    let t = TestBazaar::default();
+    let saver = t.user();
+    t.mint(&t.token, &saver, 100);
+    let id = open_goal(&t, &saver, 5_000);
+    t.client.deposit_goal(&saver, &id, &100);
+
+    t.advance_days(30);
+    assert_eq!(t.client.withdraw_goal(&saver, &id), 100);
+    assert!(!t.client.has_badge(&saver, &BADGE_GOAL_MET));
+}
@@ -0,0 +1,75 @@
+// 🏛️ PROJECT BAZAAR | SPENDING POLICY TESTS
+// Direct withdrawals against the daily limit, and the witness-approved path above it.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn repeated_small_withdrawals_add_up_against_the_daily_limit() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.make_circle(&owner, 3);
+    t.client.set_spending_limit(&owner, &t.token, &Some(300), &None);
+
+    for _ in 0..3 {
+        t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &100);
+    }
+    assert_eq!(t.client.can_withdraw(&owner, &PRIMARY_VAULT, &t.token, &1), WithdrawCheck::NeedsApproval);
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::ApprovalRequired)));
+
+    // The window rolls over a day after the first withdrawal in it
+    t.advance_days(1);
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &300);
+    assert_eq!(t.balance(&t.token, &owner), 600);
+}
+
+#[test]
+fn withdrawals_over_the_limit_wait_for_two_witness_approvals() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 3);
+    t.client.set_spending_limit(&owner, &t.token, &Some(100), &None);
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &500), Err(Ok(Error::ApprovalRequired)));
+
+    let id = t.client.request_withdrawal(&owner, &PRIMARY_VAULT, &t.token, &500);
+    t.client.approve_withdrawal(&circle.get_unchecked(0), &id);
+    assert_eq!(t.client.try_execute_withdrawal(&owner, &id), Err(Ok(Error::ApprovalRequired)));
+
+    t.client.approve_withdrawal(&circle.get_unchecked(1), &id);
+    t.client.execute_withdrawal(&owner, &id);
+    assert_eq!(t.balance(&t.token, &owner), 500);
+    assert_eq!(t.client.try_execute_withdrawal(&owner, &id), Err(Ok(Error::WithdrawalExecuted)));
+}
+
+#[test]
+fn an_approval_lapses_when_its_witness_leaves_the_circle() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 4);
+    t.client.set_spending_limit(&owner, &t.token, &Some(100), &None);
+
+    let id = t.client.request_withdrawal(&owner, &PRIMARY_VAULT, &t.token, &500);
+    t.client.approve_withdrawal(&circle.get_unchecked(0), &id);
+    assert_eq!(t.client.approve_withdrawal(&circle.get_unchecked(1), &id), 2);
+    t.client.remove_witness(&owner, &circle.get_unchecked(1));
+    assert_eq!(t.client.try_execute_withdrawal(&owner, &id), Err(Ok(Error::ApprovalRequired)));
+
+    assert_eq!(t.client.approve_withdrawal(&circle.get_unchecked(2), &id), 2);
+    t.client.execute_withdrawal(&owner, &id);
+    assert_eq!(t.balance(&t.token, &owner), 500);
+}
+
+#[test]
+fn raising_the_limit_needs_a_witness_cosign() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let circle = t.make_circle(&owner, 3);
+    t.client.set_spending_limit(&owner, &t.token, &Some(100), &None);
+
+    assert_eq!(t.client.try_set_spending_limit(&owner, &t.token, &None, &None), Err(Ok(Error::CosignRequired)));
+    t.client.set_spending_limit(&owner, &t.token, &Some(500), &Some(circle.get_unchecked(0)));
+    assert_eq!(t.client.get_spending_limit(&owner, &t.token), Some(500));
+}
@@ -0,0 +1,72 @@
+// 🏛️ PROJECT BAZAAR | STRATEGY TESTS
+// Parking idle vault balances in approved yield strategies and paying heirs principal + yield.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::{contract, contractimpl, token};
+
+/// Single-depositor strategy: one share per token deposited, and everything it holds,
+/// yield included, goes back on withdrawal.
+#[contract]
+struct MockStrategy;
+
+#[contractimpl]
+impl YieldStrategy for MockStrategy {
+    fn deposit(_env: Env, _from: Address, _token: Address, amount: i128) -> i128 {
+        amount
+    }
+
+    fn withdraw(env: Env, to: Address, token: Address, _shares: i128) -> i128 {
+        let client = token::Client::new(&env, &token);
+        let held = client.balance(&env.current_contract_address());
+        client.transfer(&env.current_contract_address(), &to, &held);
+        held
+    }
+
+    fn value_of(env: Env, token: Address, _shares: i128) -> i128 {
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+}
+
+#[test]
+fn heirs_inherit_the_parked_principal_and_its_yield() {
+    let t = TestBazaar::default();
+    let strategy = t.env.register(MockStrategy, ());
+    t.client.set_strategy(&strategy, &true);
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    assert_eq!(t.client.deposit_to_strategy(&owner, &PRIMARY_VAULT, &t.token, &strategy, &600), 600);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 400);
+    assert_eq!(t.client.get_strategy_position(&owner, &PRIMARY_VAULT, &t.token).unwrap().principal, 600);
+    t.mint(&t.token, &strategy, 60);
+    assert_eq!(t.client.get_strategy_value(&owner, &PRIMARY_VAULT, &t.token), 660);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_060);
+    assert!(t.client.get_strategy_position(&owner, &PRIMARY_VAULT, &t.token).is_none());
+}
+
+#[test]
+fn only_approved_strategies_take_what_the_vault_holds() {
+    let t = TestBazaar::default();
+    let (strategy, other) = (t.env.register(MockStrategy, ()), t.env.register(MockStrategy, ()));
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_deposit_to_strategy(&owner, &PRIMARY_VAULT, &t.token, &strategy, &100), Err(Ok(Error::StrategyNotApproved)));
+
+    t.client.set_strategy(&strategy, &true);
+    t.client.set_strategy(&other, &true);
+    assert_eq!(t.client.try_deposit_to_strategy(&owner, &PRIMARY_VAULT, &t.token, &strategy, &1_001), Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(t.client.try_withdraw_from_strategy(&owner, &PRIMARY_VAULT, &t.token), Err(Ok(Error::NoStrategyPosition)));
+    t.client.deposit_to_strategy(&owner, &PRIMARY_VAULT, &t.token, &strategy, &100);
+    assert_eq!(t.client.try_deposit_to_strategy(&owner, &PRIMARY_VAULT, &t.token, &other, &100), Err(Ok(Error::StrategyMismatch)));
+
+    // A strategy the admin drops can still be exited
+    t.client.set_strategy(&strategy, &false);
+    assert_eq!(t.client.withdraw_from_strategy(&owner, &PRIMARY_VAULT, &t.token), 100);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 1_000);
+}
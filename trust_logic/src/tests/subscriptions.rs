@@ -0,0 +1,38 @@
+// 🏛️ PROJECT BAZAAR | SUBSCRIPTION TESTS
+// Recurring payments collected once per interval out of a prepaid balance.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn installments_are_collected_once_per_interval_and_cancelling_refunds_the_rest() {
+    let t = TestBazaar::default();
+    let (tenant, landlord) = (t.user(), t.user());
+    t.mint(&t.token, &tenant, 500);
+    let id = t.client.create_subscription(&tenant, &landlord, &t.token, &100, &(30 * DAY_SECONDS));
+    assert_eq!(t.client.fund_subscription(&tenant, &id, &250), 250);
+
+    // The first installment is due straight away, the next one a month later
+    t.client.collect(&id);
+    assert_eq!(t.client.try_collect(&id), Err(Ok(Error::PaymentNotDue)));
+    t.advance_days(30);
+    t.client.collect(&id);
+    assert_eq!(t.balance(&t.token, &landlord), 200);
+
+    assert_eq!(t.client.cancel_subscription(&tenant, &id), 50);
+    assert_eq!(t.balance(&t.token, &tenant), 300);
+    assert_eq!(t.client.try_collect(&id), Err(Ok(Error::SubscriptionInactive)));
+}
+
+#[test]
+fn subscriptions_refuse_bad_schedules_strangers_and_short_balances() {
+    let t = TestBazaar::default();
+    let (tenant, landlord) = (t.user(), t.user());
+    assert_eq!(t.client.try_create_subscription(&tenant, &tenant, &t.token, &100, &(30 * DAY_SECONDS)), Err(Ok(Error::InvalidSubscription)));
+    assert_eq!(t.client.try_create_subscription(&tenant, &landlord, &t.token, &100, &(DAY_SECONDS - 1)), Err(Ok(Error::InvalidSubscription)));
+
+    let id = t.client.create_subscription(&tenant, &landlord, &t.token, &100, &(30 * DAY_SECONDS));
+    assert_eq!(t.client.try_collect(&id), Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(t.client.try_fund_subscription(&landlord, &id, &100), Err(Ok(Error::NotSubscriptionPayer)));
+    assert_eq!(t.client.try_cancel_subscription(&landlord, &id), Err(Ok(Error::NotSubscriptionPayer)));
+}
@@ -0,0 +1,36 @@
+// 🏛️ PROJECT BAZAAR | SUMMARY TESTS
+// The one-call dashboard view over a user's merchant record, vaults, emergency and circles.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn the_summary_gathers_the_whole_dashboard() {
+    let t = TestBazaar::default();
+    let owner = t.bonded_user(30);
+    let heir = t.fund_vault(&owner, 1_000);
+    t.client.create_vault(&owner, &heir);
+    let circle = t.make_circle(&owner, 3);
+    t.client.declare_emergency(&circle.get_unchecked(0), &owner);
+    let guarded = t.user();
+    t.client.assign_witnesses(&guarded, &vec![&t.env, (owner.clone(), 3)]);
+
+    let summary = t.client.get_account_summary(&owner);
+    assert_eq!((summary.merchant.trust_score, summary.tier), (30, TrustTier::Silver));
+    assert_eq!(summary.vault_ids, vec![&t.env, 0, 1]);
+    assert_eq!(summary.vault_states, vec![&t.env, VaultState::Active, VaultState::Active]);
+    assert_eq!(summary.balances.get_unchecked(t.token.clone()), 1_000);
+    assert_eq!(summary.emergency.len(), 1);
+    assert_eq!(summary.witnesses.len(), 3);
+    assert_eq!(summary.circles_i_protect, vec![&t.env, guarded]);
+    assert_eq!(t.client.get_account_summary(&heir).vaults_naming_me, vec![&t.env, owner]);
+}
+
+#[test]
+fn a_newcomer_gets_an_empty_summary_rather_than_an_error() {
+    let t = TestBazaar::default();
+    let summary = t.client.get_account_summary(&t.user());
+    assert_eq!((summary.merchant.trust_score, summary.tier), (0, TrustTier::Bronze));
+    assert!(summary.vaults.is_empty() && summary.vault_states.is_empty() && summary.balances.is_empty());
+    assert!(summary.emergency.is_empty() && summary.witnesses.is_empty() && summary.circles_i_protect.is_empty());
+}
@@ -0,0 +1,38 @@
+// 🏛️ PROJECT BAZAAR | TREASURY TESTS
+// Per-operation fees booked into the treasury and the admin's withdrawals from it.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn claim_fees_accrue_to_the_treasury_until_withdrawn() {
+    let t = TestBazaar::default();
+    t.client.set_fee_config(&FeeConfig { escrow_bps: 0, remit_bps: 0, claim_bps: 200 });
+    assert_eq!(t.client.get_fee_config().claim_bps, 200);
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 980);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 20);
+
+    let to = t.user();
+    t.client.withdraw_treasury(&t.token, &to, &15);
+    assert_eq!(t.balance(&t.token, &to), 15);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 5);
+}
+
+#[test]
+fn fees_are_capped_and_the_treasury_cannot_be_overdrawn() {
+    let t = TestBazaar::default();
+    let over_cap = FeeConfig { escrow_bps: 0, remit_bps: 501, claim_bps: 0 };
+    assert_eq!(t.client.try_set_fee_config(&over_cap), Err(Ok(Error::InvalidConfig)));
+    t.client.set_fee_config(&FeeConfig { escrow_bps: 500, remit_bps: 500, claim_bps: 500 });
+
+    let to = t.user();
+    assert_eq!(t.client.try_withdraw_treasury(&t.token, &to, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_withdraw_treasury(&t.token, &to, &1), Err(Ok(Error::InsufficientBalance)));
+}
@@ -0,0 +1,231 @@
+// 🏛️ PROJECT BAZAAR | TRUST TESTS
+// The BZR bond, vouches and the trust score they build, and how the score decays.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{IntoVal, TryFromVal, Val};
+
+/// A bonded user at `trust` holding the BZR each vouch stakes.
+fn voucher(t: &TestBazaar, trust: u32) -> Address {
+    let voucher = t.bonded_user(trust);
+    t.mint(&t.bzr, &voucher, 50);
+    voucher
+}
+
+#[test]
+fn the_bond_is_locked_for_thirty_days_and_then_returned() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    t.mint(&t.bzr, &merchant, 100);
+    t.client.stake(&merchant);
+    assert!(t.client.is_bonded(&merchant));
+    assert_eq!(t.client.get_trust(&merchant), 10);
+    assert_eq!(t.balance(&t.bzr, &merchant), 0);
+
+    t.advance_days(29);
+    assert_eq!(t.client.try_unstake(&merchant), Err(Ok(Error::BondLocked)));
+    t.advance_days(1);
+    assert_eq!(t.client.unstake(&merchant), 100);
+    assert_eq!(t.balance(&t.bzr, &merchant), 100);
+    assert!(!t.client.is_bonded(&merchant));
+    assert_eq!(t.client.get_trust(&merchant), 0);
+}
+
+#[test]
+fn bonds_are_staked_once_and_need_the_bzr() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    assert_eq!(t.client.try_unstake(&merchant), Err(Ok(Error::NotBonded)));
+    assert!(t.client.try_stake(&merchant).is_err());
+    assert!(!t.client.is_bonded(&merchant));
+
+    t.mint(&t.bzr, &merchant, 200);
+    t.client.stake(&merchant);
+    assert_eq!(t.client.try_stake(&merchant), Err(Ok(Error::AlreadyBonded)));
+    assert_eq!(t.balance(&t.bzr, &merchant), 100);
+}
+
+#[test]
+fn vouches_from_trusted_merchants_count_for_more() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    for (trust, points) in [(10, 1), (60, 3), (100, 5)] {
+        let voucher = voucher(&t, trust);
+        let before = t.client.get_trust(&merchant);
+        t.client.vouch(&voucher, &merchant);
+        assert_eq!(t.client.get_trust(&merchant) - before, points);
+        assert_eq!(t.client.get_vouchers(&merchant).get_unchecked(voucher), points);
+    }
+    assert_eq!(t.client.get_trust(&merchant), 9);
+}
+
+#[test]
+fn vouches_need_a_bonded_voucher_other_than_the_target() {
+    let t = TestBazaar::default();
+    let (merchant, unbonded) = (t.user(), t.user());
+    assert_eq!(t.client.try_vouch(&unbonded, &merchant), Err(Ok(Error::NotBonded)));
+    let voucher = voucher(&t, 60);
+    assert_eq!(t.client.try_vouch(&voucher, &voucher), Err(Ok(Error::SelfVouch)));
+    t.client.vouch(&voucher, &merchant);
+    assert_eq!(t.client.try_vouch(&voucher, &merchant), Err(Ok(Error::VouchCooldown)));
+    assert_eq!(t.client.get_trust(&merchant), 3);
+}
+
+#[test]
+fn idle_trust_decays_a_point_per_thirty_days() {
+    let t = TestBazaar::default();
+    let merchant = t.bonded_user(40);
+    t.advance_days(29);
+    assert_eq!(t.client.get_trust(&merchant), 40);
+    t.advance_days(1);
+    assert_eq!(t.client.get_trust(&merchant), 39);
+    t.advance_days(75);
+    assert_eq!(t.client.get_trust(&merchant), 37);
+
+    // Part-periods between reads still add up to full ones
+    t.advance_days(15);
+    assert_eq!(t.client.get_trust(&merchant), 36);
+}
+
+#[test]
+fn activity_holds_off_decay_and_never_active_users_keep_their_points() {
+    let t = TestBazaar::default();
+    let merchant = voucher(&t, 40);
+    let target = t.user();
+    t.advance_days(20);
+    t.client.vouch(&merchant, &target);
+    t.advance_days(20);
+    assert_eq!(t.client.get_trust(&merchant), 40);
+
+    // The target was never active itself, so its vouched points don't decay
+    t.advance_days(365);
+    assert_eq!(t.client.get_trust(&target), 2);
+}
+
+#[test]
+fn unvouching_or_a_collapsed_voucher_takes_the_points_back() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    let (revoker, quitter) = (voucher(&t, 60), voucher(&t, 40));
+    t.client.vouch(&revoker, &merchant);
+    t.client.vouch(&quitter, &merchant);
+    assert_eq!(t.client.get_vouchers(&merchant).len(), 2);
+    assert_eq!(t.client.get_vouches_given(&revoker), vec![&t.env, merchant.clone()]);
+    assert_eq!(t.client.get_trust(&merchant), 5);
+
+    t.client.unvouch(&revoker, &merchant);
+    assert!(t.client.get_vouches_given(&revoker).is_empty());
+    assert_eq!(t.client.get_trust(&merchant), 2);
+
+    // Dropping the bond collapses the quitter's standing and discounts their vouch
+    t.advance_days(30);
+    t.client.unstake(&quitter);
+    assert!(t.client.get_vouchers(&merchant).is_empty());
+    assert_eq!(t.client.get_trust(&merchant), 0);
+}
+
+#[test]
+fn vouchers_are_capped_at_ten_active_vouches() {
+    let t = TestBazaar::default();
+    let voucher = voucher(&t, 60);
+    t.mint(&t.bzr, &voucher, 5);
+    for _ in 0..10 {
+        t.client.vouch(&voucher, &t.user());
+    }
+    assert_eq!(t.client.try_vouch(&voucher, &t.user()), Err(Ok(Error::TooManyVouches)));
+    assert_eq!(t.client.try_unvouch(&voucher, &t.user()), Err(Ok(Error::NotVouched)));
+
+    let first = t.client.get_vouches_given(&voucher).get_unchecked(0);
+    t.client.unvouch(&voucher, &first);
+    t.client.vouch(&voucher, &t.user());
+}
+
+#[test]
+fn tiers_follow_the_score_thresholds_and_announce_changes() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    for (score, tier) in [(24, TrustTier::Bronze), (25, TrustTier::Silver), (50, TrustTier::Gold), (74, TrustTier::Gold), (75, TrustTier::Platinum)] {
+        t.set_trust(&merchant, score);
+        assert_eq!(t.client.get_tier(&merchant), tier);
+    }
+
+    t.set_trust(&merchant, 24);
+    t.client.vouch(&voucher(&t, 20), &merchant);
+    let topics: Vec<Val> = (events::TIER_CHANGED, merchant.clone()).into_val(&t.env);
+    let (_, _, data) = t.env.events().all().iter().find(|(_, event_topics, _)| *event_topics == topics).unwrap();
+    assert_eq!(TrustTier::try_from_val(&t.env, &data).unwrap(), TrustTier::Silver);
+}
+
+#[test]
+fn jurors_need_the_gold_tier() {
+    let t = TestBazaar::default();
+    let juror = t.user();
+    t.mint(&t.bzr, &juror, 100);
+    t.set_trust(&juror, 49);
+    assert_eq!(t.client.try_register_juror(&juror), Err(Ok(Error::JurorTrustTooLow)));
+    t.set_trust(&juror, 50);
+    t.client.register_juror(&juror);
+}
+
+#[test]
+fn one_call_vouches_for_several_targets() {
+    let t = TestBazaar::default();
+    let voucher = voucher(&t, 60);
+    let targets = vec![&t.env, t.user(), t.user(), t.user()];
+    t.client.vouch_many(&voucher, &targets);
+    for target in targets.iter() {
+        assert_eq!(t.client.get_trust(&target), 3);
+    }
+    assert_eq!(t.client.get_vouches_given(&voucher), targets);
+}
+
+#[test]
+fn a_failing_target_reverts_the_whole_vouch_batch() {
+    let t = TestBazaar::default();
+    let voucher = voucher(&t, 60);
+    let target = t.user();
+    assert_eq!(t.client.try_vouch_many(&voucher, &vec![&t.env, target.clone(), voucher.clone()]), Err(Ok(Error::SelfVouch)));
+    assert_eq!(t.client.get_trust(&target), 0);
+
+    let mut targets = Vec::new(&t.env);
+    for _ in 0..11 {
+        targets.push_back(t.user());
+    }
+    assert_eq!(t.client.try_vouch_many(&voucher, &targets), Err(Ok(Error::BatchTooLarge)));
+}
+
+#[test]
+fn every_score_change_is_logged_with_its_reason_and_actor() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    t.mint(&t.bzr, &merchant, 100);
+    t.client.stake(&merchant);
+    let voucher = voucher(&t, 60);
+    t.client.vouch(&voucher, &merchant);
+    t.client.unvouch(&voucher, &merchant);
+
+    let history = t.client.get_trust_history(&merchant, &0, &10);
+    assert_eq!(history.len(), 3);
+    for (change, (delta, reason)) in history.iter().zip([(10, TrustReason::Bond), (3, TrustReason::Vouch), (-3, TrustReason::Unvouch)]) {
+        assert_eq!((change.delta, change.reason), (delta, reason));
+    }
+    assert_eq!(history.get_unchecked(1).actor, voucher);
+}
+
+#[test]
+fn trust_history_keeps_the_latest_fifty_in_pages_of_twenty() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    for _ in 0..26 {
+        let voucher = voucher(&t, 60);
+        t.client.vouch(&voucher, &merchant);
+        t.client.unvouch(&voucher, &merchant);
+    }
+
+    assert_eq!(t.client.get_trust_history(&merchant, &0, &100).len(), 20);
+    assert_eq!(t.client.get_trust_history(&merchant, &40, &20).len(), 10);
+    assert!(t.client.get_trust_history(&merchant, &50, &20).is_empty());
+    // The two oldest entries were evicted, so the log now opens on a vouch
+    assert_eq!(t.client.get_trust_history(&merchant, &0, &1).get_unchecked(0).reason, TrustReason::Vouch);
+}
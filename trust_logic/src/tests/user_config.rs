@@ -0,0 +1,52 @@
+// 🏛️ PROJECT BAZAAR | USER CONFIG TESTS
+// Per-owner settings, their defaults and the witness co-sign on sensitive changes.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+#[test]
+fn owners_read_the_defaults_and_change_them_with_a_witness_cosign() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let circle = t.make_circle(&owner, 5);
+
+    let mut config = t.client.get_user_config(&owner);
+    assert_eq!(config.deadman_seconds, 180 * DAY_SECONDS);
+    assert_eq!((config.medical_release_bps, config.medical_quorum, config.panic_quorum, config.claim_cosigns), (1_500, 3, 3, 0));
+
+    // Raising the claim co-signs only tightens the vault
+    config.claim_cosigns = 2;
+    t.client.set_user_config(&owner, &config, &None);
+
+    config.panic_grace_seconds = 3 * DAY_SECONDS;
+    config.deadman_seconds = 90 * DAY_SECONDS;
+    config.panic_quorum = 4;
+    t.client.set_user_config(&owner, &config, &Some(circle.get_unchecked(0)));
+    let stored = t.client.get_user_config(&owner);
+    assert_eq!((stored.deadman_seconds, stored.panic_grace_seconds, stored.panic_quorum), (90 * DAY_SECONDS, 3 * DAY_SECONDS, 4));
+    assert_eq!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT), 90 * DAY_SECONDS);
+}
+
+#[test]
+fn sensitive_or_out_of_range_settings_are_refused() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    assert_eq!(t.client.try_get_user_config(&owner).err(), Some(Ok(Error::VaultNotFound)));
+    t.fund_vault(&owner, 1_000);
+    t.make_circle(&owner, 5);
+    let defaults = t.client.get_user_config(&owner);
+
+    let mut config = defaults.clone();
+    config.medical_release_bps = 3_000;
+    assert_eq!(t.client.try_set_user_config(&owner, &config, &None), Err(Ok(Error::CosignRequired)));
+    assert_eq!(t.client.try_set_user_config(&owner, &config, &Some(t.user())), Err(Ok(Error::NotWitness)));
+    config.medical_release_bps = 3_001;
+    assert_eq!(t.client.try_set_user_config(&owner, &config, &None), Err(Ok(Error::InvalidConfig)));
+
+    let mut config = defaults;
+    config.panic_grace_seconds = DAY_SECONDS;
+    assert_eq!(t.client.try_set_user_config(&owner, &config, &None), Err(Ok(Error::CosignRequired)));
+    config.panic_grace_seconds = 31 * DAY_SECONDS;
+    assert_eq!(t.client.try_set_user_config(&owner, &config, &None), Err(Ok(Error::InvalidTimeout)));
+}
@@ -0,0 +1,400 @@
+// 🏛️ PROJECT BAZAAR | VAULT TESTS
+// Legacy vault setup: timers, heirs, deposits and withdrawals, and the views over them.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{IntoVal, TryFromVal, Val};
+
+#[test]
+fn each_vault_keeps_its_own_deadman_timer() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let witness = t.make_circle(&owner, 3).get_unchecked(0);
+    t.client.set_vault_timeout(&owner, &PRIMARY_VAULT, &(90 * DAY_SECONDS), &Some(witness));
+    assert_eq!(t.client.get_vault(&owner, &PRIMARY_VAULT).timeout_seconds, 90 * DAY_SECONDS);
+
+    t.advance_days(89);
+    assert_eq!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT), DAY_SECONDS);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    t.advance_days(1);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn vault_timeouts_stay_between_90_and_365_days() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let witness = Some(t.make_circle(&owner, 3).get_unchecked(0));
+    for seconds in [89 * DAY_SECONDS, 366 * DAY_SECONDS] {
+        assert_eq!(t.client.try_set_vault_timeout(&owner, &PRIMARY_VAULT, &seconds, &witness), Err(Ok(Error::InvalidTimeout)));
+    }
+    assert_eq!(t.client.try_set_vault_timeout(&owner, &1, &(90 * DAY_SECONDS), &witness), Err(Ok(Error::VaultNotFound)));
+    assert_eq!(t.client.try_set_vault_timeout(&owner, &PRIMARY_VAULT, &(365 * DAY_SECONDS), &None), Err(Ok(Error::CosignRequired)));
+    t.client.set_vault_timeout(&owner, &PRIMARY_VAULT, &(365 * DAY_SECONDS), &witness);
+    assert_eq!(t.client.heartbeat_due(&owner, &PRIMARY_VAULT), 365 * DAY_SECONDS);
+}
+
+#[test]
+fn an_owner_opens_up_to_five_vaults_each_with_its_own_heir() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heirs = [t.user(), t.user(), t.user(), t.user(), t.user()];
+    for (expected, heir) in heirs.iter().enumerate() {
+        assert_eq!(t.client.create_vault(&owner, heir), expected as u32);
+    }
+    assert_eq!(t.client.list_vaults(&owner), vec![&t.env, 0, 1, 2, 3, 4]);
+    assert_eq!(t.client.get_vault(&owner, &4).heirs.get_unchecked(0).address, heirs[4]);
+    assert_eq!(t.client.try_create_vault(&owner, &t.user()), Err(Ok(Error::TooManyVaults)));
+}
+
+fn shares(t: &TestBazaar, split: &[(&Address, u32)]) -> Vec<HeirShare> {
+    let mut heirs = Vec::new(&t.env);
+    for (address, bps) in split {
+        heirs.push_back(HeirShare { address: (*address).clone(), bps: *bps });
+    }
+    heirs
+}
+
+#[test]
+fn a_claim_splits_the_vault_by_heir_share() {
+    let t = TestBazaar::default();
+    let (owner, daughter, son) = (t.user(), t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    t.client.set_heirs(&owner, &PRIMARY_VAULT, &shares(&t, &[(&daughter, 6_000), (&son, 4_000)]));
+    t.advance_days(30);
+    t.client.apply_heir_change(&owner, &PRIMARY_VAULT);
+    for heir in [&daughter, &son] {
+        t.client.accept_heirship(heir, &owner, &PRIMARY_VAULT);
+    }
+
+    t.advance_days(150);
+    t.client.initiate_claim(&son, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&son, &owner, &PRIMARY_VAULT);
+    assert_eq!((t.balance(&t.token, &daughter), t.balance(&t.token, &son)), (600, 400));
+}
+
+#[test]
+fn heir_shares_must_be_distinct_and_add_up_to_the_whole() {
+    let t = TestBazaar::default();
+    let (owner, daughter, son) = (t.user(), t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    for split in [
+        shares(&t, &[(&daughter, 6_000), (&son, 3_000)]),
+        shares(&t, &[(&daughter, 5_000), (&daughter, 5_000)]),
+        shares(&t, &[(&daughter, 10_000), (&son, 0)]),
+    ] {
+        assert_eq!(t.client.try_set_heirs(&owner, &PRIMARY_VAULT, &split), Err(Ok(Error::InvalidHeirShares)));
+    }
+    assert_eq!(t.client.try_set_heirs(&owner, &PRIMARY_VAULT, &Vec::new(&t.env)), Err(Ok(Error::NoHeir)));
+    assert!(t.client.get_pending_heirs(&owner, &PRIMARY_VAULT).is_none());
+}
+
+#[test]
+fn backup_heirs_take_over_one_grace_period_after_another() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let (first_backup, second_backup) = (t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    let backups = vec![&t.env, first_backup.clone(), second_backup.clone()];
+    t.client.set_backup_heirs(&owner, &PRIMARY_VAULT, &backups, &(7 * DAY_SECONDS));
+    let eligibility = t.client.get_heir_eligibility(&owner, &PRIMARY_VAULT);
+    assert_eq!(eligibility.get_unchecked(2).1 - eligibility.get_unchecked(0).1, 14 * DAY_SECONDS);
+
+    t.advance_days(187);
+    assert_eq!(t.client.try_initiate_claim(&second_backup, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    t.client.initiate_claim(&first_backup, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&first_backup, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &first_backup), 1_000);
+}
+
+#[test]
+fn backup_lists_keep_to_their_size_and_grace_bounds() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let stranger = t.user();
+    t.fund_vault(&owner, 1_000);
+    let mut backups = Vec::new(&t.env);
+    for _ in 0..6 {
+        backups.push_back(t.user());
+    }
+    assert_eq!(t.client.try_set_backup_heirs(&owner, &PRIMARY_VAULT, &backups, &(7 * DAY_SECONDS)), Err(Ok(Error::InvalidHeirShares)));
+    backups.pop_back();
+    for grace in [6 * DAY_SECONDS, 181 * DAY_SECONDS] {
+        assert_eq!(t.client.try_set_backup_heirs(&owner, &PRIMARY_VAULT, &backups, &grace), Err(Ok(Error::InvalidTimeout)));
+    }
+
+    t.client.set_backup_heirs(&owner, &PRIMARY_VAULT, &backups, &(7 * DAY_SECONDS));
+    t.advance_days(365);
+    assert_eq!(t.client.try_initiate_claim(&stranger, &owner, &PRIMARY_VAULT), Err(Ok(Error::NotHeir)));
+}
+
+#[test]
+fn deposits_and_withdrawals_move_real_tokens() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.mint(&t.token, &owner, 700);
+    t.client.deposit_vault(&owner, &PRIMARY_VAULT, &t.token, &500);
+    t.client.deposit(&owner, &PRIMARY_VAULT, &t.token, &200);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 1_700);
+    assert_eq!(t.balance(&t.token, &t.client.address), 1_700);
+
+    t.client.withdraw(&owner, &PRIMARY_VAULT, &t.token, &1_700);
+    assert_eq!(t.balance(&t.token, &owner), 1_700);
+    assert!(t.client.list_vault_assets(&owner, &PRIMARY_VAULT).is_empty());
+}
+
+#[test]
+fn withdrawals_stop_at_the_balance_and_the_owner() {
+    let t = TestBazaar::default();
+    let (owner, heir) = (t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_deposit_vault(&owner, &PRIMARY_VAULT, &t.token, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &-1), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(t.client.try_withdraw(&owner, &PRIMARY_VAULT, &t.token, &1_001), Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(t.client.try_withdraw(&heir, &PRIMARY_VAULT, &t.token, &1), Err(Ok(Error::VaultNotFound)));
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 1_000);
+}
+
+#[test]
+fn a_vault_holds_and_passes_on_several_assets() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    t.mint(&t.bzr, &owner, 300);
+    t.client.deposit_vault(&owner, &PRIMARY_VAULT, &t.bzr, &300);
+    assert_eq!(t.client.list_vault_assets(&owner, &PRIMARY_VAULT).len(), 2);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.bzr), 300);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    let claimed = t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!((claimed.get_unchecked(t.token.clone()), claimed.get_unchecked(t.bzr.clone())), (1_000, 300));
+    assert_eq!((t.balance(&t.token, &heir), t.balance(&t.bzr, &heir)), (1_000, 300));
+}
+
+#[test]
+fn a_vault_refuses_an_eleventh_asset() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let new_asset = || {
+        let asset = t.env.register_stellar_asset_contract_v2(t.admin.clone()).address();
+        t.mint(&asset, &owner, 10);
+        asset
+    };
+    for _ in 0..9 {
+        t.client.deposit_vault(&owner, &PRIMARY_VAULT, &new_asset(), &10);
+    }
+    assert_eq!(t.client.try_deposit_vault(&owner, &PRIMARY_VAULT, &new_asset(), &10), Err(Ok(Error::TooManyAssets)));
+
+    // Topping up an asset already held is still fine
+    t.mint(&t.token, &owner, 10);
+    t.client.deposit_vault(&owner, &PRIMARY_VAULT, &t.token, &10);
+    assert_eq!(t.client.list_vault_assets(&owner, &PRIMARY_VAULT).len(), 10);
+}
+
+#[test]
+fn the_vault_view_counts_down_to_the_claim() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let start = t.env.ledger().timestamp();
+
+    t.advance_days(100);
+    let view = t.client.get_vault_view(&owner, &PRIMARY_VAULT);
+    assert_eq!(view.claimable_at, start + 180 * DAY_SECONDS);
+    assert_eq!(view.seconds_until_claimable, 80 * DAY_SECONDS);
+    assert!(!view.is_claimable);
+    assert_eq!(view.vault.heirs.get_unchecked(0).address, heir);
+    assert_eq!(view.vault.balances.get_unchecked(t.token.clone()), 1_000);
+
+    t.advance_days(90);
+    let view = t.client.get_vault_view(&owner, &PRIMARY_VAULT);
+    assert!(view.is_claimable && view.seconds_until_claimable == 0);
+}
+
+#[test]
+fn vault_views_of_missing_vaults_are_refused() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    assert_eq!(t.client.try_get_vault_view(&owner, &PRIMARY_VAULT).err(), Some(Ok(Error::VaultNotFound)));
+    t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_get_vault_view(&owner, &1).err(), Some(Ok(Error::VaultNotFound)));
+    assert_eq!(t.client.get_vault_balance(&owner, &1, &t.token), 0);
+}
+
+#[test]
+fn a_sole_heir_cannot_decline() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_decline_heirship(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::NoHeir)));
+    assert_eq!(t.client.get_vault(&owner, &PRIMARY_VAULT).heirs.len(), 1);
+    assert_eq!(t.client.get_vaults_naming_me(&heir), vec![&t.env, owner]);
+}
+
+#[test]
+fn a_declined_share_is_spread_over_the_remaining_heirs() {
+    let t = TestBazaar::default();
+    let (owner, daughter, son, cousin) = (t.user(), t.user(), t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    t.client.set_heirs(&owner, &PRIMARY_VAULT, &shares(&t, &[(&daughter, 5_000), (&son, 2_500), (&cousin, 2_500)]));
+    t.advance_days(30);
+    t.client.apply_heir_change(&owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.get_vaults_naming_me(&cousin), vec![&t.env, owner.clone()]);
+
+    t.client.decline_heirship(&cousin, &owner, &PRIMARY_VAULT);
+    assert!(t.client.get_vaults_naming_me(&cousin).is_empty());
+    let heirs = t.client.get_vault(&owner, &PRIMARY_VAULT).heirs;
+    // The last heir absorbs the rounding
+    assert_eq!((heirs.get_unchecked(0).bps, heirs.get_unchecked(1).bps), (6_666, 3_334));
+    for heir in [&daughter, &son] {
+        t.client.accept_heirship(heir, &owner, &PRIMARY_VAULT);
+    }
+    t.advance_days(150);
+    t.client.initiate_claim(&daughter, &owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn only_accepted_heirs_may_claim() {
+    let t = TestBazaar::default();
+    let (owner, heir) = (t.user(), t.user());
+    t.client.create_vault(&owner, &heir);
+    assert_eq!(t.client.try_accept_heirship(&t.user(), &owner, &PRIMARY_VAULT), Err(Ok(Error::NotHeir)));
+
+    t.advance_days(180);
+    assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::HeirNotAccepted)));
+    t.client.accept_heirship(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_accept_heirship(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::HeirAlreadyAccepted)));
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn vault_states_move_through_warning_to_claimable_and_announce_each_step() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    let announced = |from: Option<VaultState>, to: VaultState| {
+        let topics: Vec<Val> = (events::VAULT_STATE, owner.clone(), PRIMARY_VAULT).into_val(&t.env);
+        let (_, event_topics, data) = t.env.events().all().last().unwrap();
+        event_topics == topics && <(Option<VaultState>, VaultState)>::try_from_val(&t.env, &data).unwrap() == (from, to)
+    };
+
+    assert_eq!(t.client.sync_vault_state(&owner, &PRIMARY_VAULT), VaultState::Active);
+    t.advance_days(149);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Active);
+    t.advance_days(1);
+    assert_eq!(t.client.sync_vault_state(&owner, &PRIMARY_VAULT), VaultState::Warning);
+    assert!(announced(Some(VaultState::Active), VaultState::Warning));
+    t.advance_days(30);
+    assert_eq!(t.client.sync_vault_state(&owner, &PRIMARY_VAULT), VaultState::Claimable);
+    assert!(announced(Some(VaultState::Warning), VaultState::Claimable));
+}
+
+#[test]
+fn vault_state_syncs_announce_only_changes_and_need_a_vault() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    t.advance_days(150);
+    t.client.sync_vault_state(&owner, &PRIMARY_VAULT);
+
+    t.client.sync_vault_state(&owner, &PRIMARY_VAULT);
+    assert!(t.env.events().all().is_empty());
+    assert_eq!(t.client.try_sync_vault_state(&t.user(), &PRIMARY_VAULT), Err(Ok(Error::VaultNotFound)));
+    assert_eq!(t.client.try_get_vault_state(&owner, &1), Err(Ok(Error::VaultNotFound)));
+}
+
+#[test]
+fn a_new_heir_takes_over_only_after_the_thirty_day_delay() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let old_heir = t.fund_vault(&owner, 1_000);
+    let new_heir = t.user();
+
+    let effective_at = t.client.update_heir(&owner, &PRIMARY_VAULT, &new_heir);
+    assert_eq!(effective_at, t.env.ledger().timestamp() + 30 * DAY_SECONDS);
+    assert_eq!(t.client.get_vault(&owner, &PRIMARY_VAULT).heirs.get_unchecked(0).address, old_heir);
+    t.advance_days(30);
+    t.client.apply_heir_change(&owner, &PRIMARY_VAULT);
+
+    let vault = t.client.get_vault(&owner, &PRIMARY_VAULT);
+    assert_eq!(vault.heirs.get_unchecked(0).address, new_heir);
+    assert!(vault.accepted_heirs.is_empty());
+    assert!(t.client.get_pending_heirs(&owner, &PRIMARY_VAULT).is_none());
+}
+
+#[test]
+fn heir_changes_wait_out_the_delay_and_a_freeze_drops_them() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_apply_heir_change(&owner, &PRIMARY_VAULT), Err(Ok(Error::NoHeirChange)));
+
+    t.client.update_heir(&owner, &PRIMARY_VAULT, &t.user());
+    t.advance_days(29);
+    assert_eq!(t.client.try_apply_heir_change(&owner, &PRIMARY_VAULT), Err(Ok(Error::HeirChangeLocked)));
+
+    for witness in t.make_circle(&owner, 3).iter() {
+        t.client.panic_button(&witness, &owner);
+    }
+    t.advance_days(1);
+    assert_eq!(t.client.try_apply_heir_change(&owner, &PRIMARY_VAULT), Err(Ok(Error::VaultFrozen)));
+    t.client.cancel_panic(&owner);
+    assert_eq!(t.client.try_apply_heir_change(&owner, &PRIMARY_VAULT), Err(Ok(Error::NoHeirChange)));
+}
+
+#[test]
+fn routed_assets_go_whole_to_their_heir_and_excluded_ones_stay() {
+    let t = TestBazaar::default();
+    let (owner, daughter, son) = (t.user(), t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    t.client.set_heirs(&owner, &PRIMARY_VAULT, &shares(&t, &[(&daughter, 5_000), (&son, 5_000)]));
+    t.advance_days(30);
+    t.client.apply_heir_change(&owner, &PRIMARY_VAULT);
+    for heir in [&daughter, &son] {
+        t.client.accept_heirship(heir, &owner, &PRIMARY_VAULT);
+    }
+    let business = t.env.register_stellar_asset_contract_v2(t.admin.clone()).address();
+    for (asset, amount) in [(&t.bzr, 300), (&business, 200)] {
+        t.mint(asset, &owner, amount);
+        t.client.deposit_vault(&owner, &PRIMARY_VAULT, asset, &amount);
+    }
+    t.client.set_token_routing(&owner, &PRIMARY_VAULT, &t.bzr, &Some(TokenRoute::Heir(son.clone())));
+    t.client.set_token_routing(&owner, &PRIMARY_VAULT, &business, &Some(TokenRoute::Exclude));
+    assert_eq!(t.client.get_token_routing(&owner, &PRIMARY_VAULT).len(), 2);
+
+    t.advance_days(180);
+    t.client.initiate_claim(&son, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&son, &owner, &PRIMARY_VAULT);
+    assert_eq!((t.balance(&t.token, &daughter), t.balance(&t.token, &son)), (500, 500));
+    assert_eq!((t.balance(&t.bzr, &daughter), t.balance(&t.bzr, &son)), (0, 300));
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &business), 200);
+}
+
+#[test]
+fn assets_route_only_to_listed_heirs_and_routes_can_be_cleared() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let stranger = t.user();
+    assert_eq!(t.client.try_set_token_routing(&owner, &PRIMARY_VAULT, &t.token, &Some(TokenRoute::Heir(stranger))), Err(Ok(Error::NotHeir)));
+    assert_eq!(t.client.try_set_token_routing(&owner, &1, &t.token, &Some(TokenRoute::Exclude)), Err(Ok(Error::VaultNotFound)));
+
+    t.client.set_token_routing(&owner, &PRIMARY_VAULT, &t.token, &Some(TokenRoute::Exclude));
+    t.client.set_token_routing(&owner, &PRIMARY_VAULT, &t.token, &None);
+    assert!(t.client.get_token_routing(&owner, &PRIMARY_VAULT).is_empty());
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+}
@@ -0,0 +1,36 @@
+// 🏛️ PROJECT BAZAAR | VERIFICATION TESTS
+// Admin-set KYC levels, the verified badge and the trust bonus that comes with them.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+#[test]
+fn verification_levels_carry_the_badge_and_a_trust_bonus() {
+    let t = TestBazaar::default();
+    let merchant = t.bonded_user(20);
+    assert!(!t.client.is_verified(&merchant));
+
+    t.client.verify_merchant(&merchant, &VerificationLevel::Basic);
+    assert_eq!(t.env.auths().first().unwrap().0, t.admin);
+    assert!(t.client.is_verified(&merchant));
+    assert!(t.client.has_badge(&merchant, &BADGE_VERIFIED));
+    assert_eq!(t.client.get_trust(&merchant), 25);
+
+    t.client.verify_merchant(&merchant, &VerificationLevel::Full);
+    assert_eq!(t.client.get_verification(&merchant), VerificationLevel::Full);
+    assert_eq!(t.client.get_trust(&merchant), 30);
+}
+
+#[test]
+fn withdrawn_verification_takes_back_the_badge_and_bonus() {
+    let t = TestBazaar::default();
+    let merchant = t.bonded_user(20);
+    t.client.verify_merchant(&merchant, &VerificationLevel::Full);
+    t.client.verify_merchant(&merchant, &VerificationLevel::None);
+    assert!(!t.client.is_verified(&merchant));
+    assert!(!t.client.has_badge(&merchant, &BADGE_VERIFIED));
+    assert_eq!(t.client.get_trust(&merchant), 20);
+
+    let fresh = TrustContractClient::new(&t.env, &t.env.register(TrustContract, (t.admin.clone(),)));
+    assert_eq!(fresh.try_verify_merchant(&merchant, &VerificationLevel::Basic), Err(Ok(Error::NotInitialized)));
+}
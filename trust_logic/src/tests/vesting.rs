@@ -0,0 +1,92 @@
+// 🏛️ PROJECT BAZAAR | VESTING TESTS
+// Inheritances paid out in steps, and what an owner who comes back can take back.
+
+use crate::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+
+fn monthly(periods: u32) -> Option<VestingSchedule> {
+    Some(VestingSchedule { period_seconds: 30 * DAY_SECONDS, periods })
+}
+
+fn claim(t: &TestBazaar, owner: &Address, heir: &Address) {
+    t.advance_days(180);
+    t.client.initiate_claim(heir, owner, &PRIMARY_VAULT);
+    t.advance_days(14);
+    t.client.finalize_claim(heir, owner, &PRIMARY_VAULT);
+}
+
+#[test]
+fn a_vested_inheritance_pays_out_month_by_month() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    t.client.set_vesting(&owner, &PRIMARY_VAULT, &monthly(4));
+    claim(&t, &owner, &heir);
+    assert_eq!(t.balance(&t.token, &heir), 0);
+    assert_eq!(t.client.try_claim_vested(&heir).err(), Some(Ok(Error::InsufficientBalance)));
+
+    t.advance_days(30);
+    assert_eq!(t.client.claim_vested(&heir).get_unchecked(t.token.clone()), 250);
+    t.advance_days(100);
+    assert_eq!(t.client.claim_vested(&heir).get_unchecked(t.token.clone()), 750);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+    assert!(t.client.get_vesting_grants(&heir).is_empty());
+}
+
+#[test]
+fn vesting_schedules_stay_within_their_bounds() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    let out_of_bounds = [
+        monthly(0),
+        monthly(121),
+        Some(VestingSchedule { period_seconds: DAY_SECONDS - 1, periods: 4 }),
+        Some(VestingSchedule { period_seconds: 366 * DAY_SECONDS, periods: 4 }),
+    ];
+    for schedule in out_of_bounds {
+        assert_eq!(t.client.try_set_vesting(&owner, &PRIMARY_VAULT, &schedule), Err(Ok(Error::InvalidVesting)));
+    }
+
+    // Clearing the schedule restores the lump sum
+    t.client.set_vesting(&owner, &PRIMARY_VAULT, &monthly(4));
+    t.client.set_vesting(&owner, &PRIMARY_VAULT, &None);
+    assert!(t.client.get_vesting(&owner, &PRIMARY_VAULT).is_none());
+    claim(&t, &owner, &heir);
+    assert_eq!(t.balance(&t.token, &heir), 1_000);
+}
+
+#[test]
+fn a_returning_owner_voids_the_claim_or_takes_back_the_unvested_rest() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    t.client.set_vesting(&owner, &PRIMARY_VAULT, &monthly(4));
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    t.client.ping_heartbeat(&owner);
+    assert!(t.client.get_pending_claim(&owner, &PRIMARY_VAULT).is_none());
+
+    claim(&t, &owner, &heir);
+    t.advance_days(30);
+    t.client.claim_vested(&heir);
+    assert_eq!(t.client.reclaim_vault(&owner, &PRIMARY_VAULT).get_unchecked(t.token.clone()), 750);
+    assert_eq!(t.balance(&t.token, &heir), 250);
+    assert_eq!(t.client.get_vault_balance(&owner, &PRIMARY_VAULT, &t.token), 750);
+    assert_eq!(t.client.get_vault_state(&owner, &PRIMARY_VAULT), VaultState::Active);
+    assert!(t.client.get_vesting_grants(&heir).is_empty());
+}
+
+#[test]
+fn only_a_claimed_vault_can_be_reclaimed() {
+    let t = TestBazaar::default();
+    let owner = t.user();
+    let heir = t.fund_vault(&owner, 1_000);
+    assert_eq!(t.client.try_reclaim_vault(&owner, &PRIMARY_VAULT).err(), Some(Ok(Error::VaultNotClaimed)));
+    assert_eq!(t.client.try_reclaim_vault(&heir, &PRIMARY_VAULT).err(), Some(Ok(Error::VaultNotFound)));
+
+    t.advance_days(180);
+    t.client.initiate_claim(&heir, &owner, &PRIMARY_VAULT);
+    assert_eq!(t.client.try_reclaim_vault(&owner, &PRIMARY_VAULT).err(), Some(Ok(Error::VaultNotClaimed)));
+}
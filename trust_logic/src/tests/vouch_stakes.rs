@@ -0,0 +1,63 @@
+// 🏛️ PROJECT BAZAAR | VOUCH STAKE TESTS
+// Vouch stakes under a fraud ruling and their release after the lock.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+use soroban_sdk::BytesN;
+
+#[test]
+fn a_fraud_ruling_burns_only_the_vouch_stakes_of_the_last_90_days() {
+    let t = TestBazaar::default();
+    let merchant = t.user();
+    let (early, late) = (t.user(), t.user());
+    for voucher in [&early, &late] {
+        t.mint(&t.bzr, voucher, 105);
+        t.client.stake(voucher);
+    }
+    t.client.vouch(&early, &merchant);
+    assert_eq!(t.client.try_release_vouch_stake(&early, &merchant), Err(Ok(Error::VouchStakeLocked)));
+    t.advance_days(91);
+    t.client.vouch(&late, &merchant);
+
+    let buyer = t.user();
+    let arbiter = t.user();
+    t.client.add_arbiter(&arbiter);
+    let title = String::from_str(&t.env, "Rice sack");
+    let listing = t.client.create_listing(&merchant, &title, &50, &t.token, &BytesN::from_array(&t.env, &[0; 32]));
+    let order = t.client.create_order(&buyer, &listing);
+    t.mint(&t.token, &buyer, 50);
+    t.client.fund_order(&buyer, &order);
+    let dispute = t.client.open_dispute(&buyer, &merchant, &order);
+    t.client.resolve_dispute(&arbiter, &dispute, &true);
+
+    // The late stake is gone; the early one outlived its lock and comes back
+    assert!(t.client.get_vouch_stake(&late, &merchant).is_none());
+    assert_eq!(t.client.try_release_vouch_stake(&late, &merchant), Err(Ok(Error::NoVouchStake)));
+    assert_eq!(t.client.release_vouch_stake(&early, &merchant), 5);
+    assert_eq!(t.balance(&t.bzr, &early), 5);
+    assert_eq!(t.balance(&t.bzr, &late), 0);
+    // Only the two bonds are left behind; the burned stake left the supply
+    assert_eq!(t.balance(&t.bzr, &t.client.address), 200);
+    assert_eq!(t.balance(&t.token, &buyer), 50);
+}
+
+#[test]
+fn an_unvouched_stake_stays_locked_for_90_days_then_comes_back() {
+    let t = TestBazaar::default();
+    let (voucher, merchant) = (t.user(), t.user());
+    t.mint(&t.bzr, &voucher, 105);
+    t.client.stake(&voucher);
+    t.client.vouch(&voucher, &merchant);
+    assert_eq!(t.balance(&t.bzr, &voucher), 0);
+
+    // Unvouching ahead of a ruling does not free the stake
+    t.client.unvouch(&voucher, &merchant);
+    assert_eq!(t.client.get_vouch_stake(&voucher, &merchant).unwrap().amount, 5);
+    t.advance_days(89);
+    assert_eq!(t.client.try_release_vouch_stake(&voucher, &merchant), Err(Ok(Error::VouchStakeLocked)));
+
+    t.advance_days(1);
+    assert_eq!(t.client.release_vouch_stake(&voucher, &merchant), 5);
+    assert_eq!(t.balance(&t.bzr, &voucher), 5);
+    assert_eq!(t.client.try_release_vouch_stake(&voucher, &merchant), Err(Ok(Error::NoVouchStake)));
+}
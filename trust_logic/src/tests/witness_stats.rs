@@ -0,0 +1,49 @@
+// 🏛️ PROJECT BAZAAR | WITNESS STATS TESTS
+// Response rates and latency per witness, and the Guardian badge for reliable ones.
+
+use crate::*;
+use crate::testutils::TestBazaar;
+
+/// Gives a fresh owner a three-witness circle that includes `witness`. Returns the owner
+/// and one of the other witnesses.
+fn guarded_owner(t: &TestBazaar, witness: &Address) -> (Address, Address) {
+    let (owner, other) = (t.user(), t.user());
+    t.fund_vault(&owner, 1_000);
+    t.client.assign_witnesses(&owner, &vec![&t.env, (witness.clone(), 1), (other.clone(), 1), (t.user(), 1)]);
+    t.client.accept_witness_role(witness, &owner);
+    t.client.accept_witness_role(&other, &owner);
+    (owner, other)
+}
+
+#[test]
+fn a_witness_who_answers_every_round_earns_the_guardian_badge() {
+    let t = TestBazaar::default();
+    let witness = t.user();
+    for _ in 0..3 {
+        let (owner, other) = guarded_owner(&t, &witness);
+        t.client.panic_button(&other, &owner);
+        t.advance_seconds(3_600);
+        t.client.panic_button(&witness, &owner);
+    }
+
+    let stats = t.client.get_witness_stats(&witness);
+    assert_eq!((stats.eligible, stats.votes_cast, stats.total_latency_seconds), (3, 3, 10_800));
+    assert!(t.client.has_badge(&witness, &BADGE_GUARDIAN));
+}
+
+#[test]
+fn a_witness_who_misses_rounds_gets_no_guardian_badge() {
+    let t = TestBazaar::default();
+    let witness = t.user();
+    let (ignored, other) = guarded_owner(&t, &witness);
+    t.client.panic_button(&other, &ignored);
+    for _ in 0..3 {
+        let (owner, other) = guarded_owner(&t, &witness);
+        t.client.panic_button(&other, &owner);
+        t.client.panic_button(&witness, &owner);
+    }
+
+    let stats = t.client.get_witness_stats(&witness);
+    assert_eq!((stats.eligible, stats.votes_cast), (4, 3));
+    assert!(!t.client.has_badge(&witness, &BADGE_GUARDIAN));
+}
@@ -0,0 +1,121 @@
+// 🏛️ PROJECT BAZAAR | TEST FIXTURE
+// Shared setup for contract tests: an initialized contract with every auth mocked, a BZR
+// token and one plain asset, plus shortcuts for the steps every flow starts with.
+// Enabled for this crate's own tests and, through the `testutils` feature, for
+// integrators testing against Bazaar.
+
+#![cfg(any(test, feature = "testutils"))]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::BytesN;
+use soroban_sdk::token::StellarAssetClient;
+
+pub const DAY_SECONDS: u64 = 86_400;
+/// Tests start at a realistic timestamp rather than the Unix epoch.
+const GENESIS_TIMESTAMP: u64 = 1_700_000_000;
+
+pub struct TestBazaar {
+    pub env: Env,
+    pub client: TrustContractClient<'static>,
+    pub admin: Address,
+    pub bzr: Address,
+    /// Plain asset for vault deposits, escrow and remittances.
+    pub token: Address,
+}
+
+impl Default for TestBazaar {
+    fn default() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = GENESIS_TIMESTAMP);
+
+        let admin = Address::generate(&env);
+        let client = TrustContractClient::new(&env, &env.register(TrustContract, (admin.clone(),)));
+        let bzr = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let config = ProtocolConfig { bond_amount: 100, slash_bps: 1_000, juror_stake: 100, juror_reward_bps: 500, listing_value_cap: 1_000_000 };
+        client.initialize(&admin, &bzr, &config);
+        TestBazaar { env, client, admin, bzr, token }
+    }
+}
+
+impl TestBazaar {
+    pub fn user(&self) -> Address {
+        Address::generate(&self.env)
+    }
+
+    /// Moves the ledger clock only, so persistent entries do not age out mid-test.
+    pub fn advance_days(&self, days: u64) {
+        self.advance_seconds(days * DAY_SECONDS);
+    }
+
+    pub fn advance_seconds(&self, seconds: u64) {
+        self.env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+    }
+
+    pub fn mint(&self, token: &Address, to: &Address, amount: i128) {
+        StellarAssetClient::new(&self.env, token).mint(to, &amount);
+    }
+
+    pub fn balance(&self, token: &Address, of: &Address) -> i128 {
+        token::Client::new(&self.env, token).balance(of)
+    }
+
+    /// Creates `user`'s next vault with a fresh, accepted heir and deposits `amount` of
+    /// the plain asset. Returns the heir; the first vault is `PRIMARY_VAULT`.
+    pub fn fund_vault(&self, user: &Address, amount: i128) -> Address {
+        let heir = self.user();
+        let vault_id = self.client.create_vault(user, &heir);
+        self.client.accept_heirship(&heir, user, &vault_id);
+        self.mint(&self.token, user, amount);
+        self.client.deposit_vault(user, &vault_id, &self.token, &amount);
+        heir
+    }
+
+    /// Assigns `size` fresh witnesses of weight 1 as `user`'s first circle and has each
+    /// accept, so all of them can vote straight away.
+    pub fn make_circle(&self, user: &Address, size: u32) -> Vec<Address> {
+        let mut witnesses = Vec::new(&self.env);
+        let mut weighted = Vec::new(&self.env);
+        for _ in 0..size {
+            let witness = self.user();
+            weighted.push_back((witness.clone(), 1u32));
+            witnesses.push_back(witness);
+        }
+        self.client.assign_witnesses(user, &weighted);
+        for witness in witnesses.iter() {
+            self.client.accept_witness_role(&witness, user);
+        }
+        witnesses
+    }
+
+    /// Lists an item from `merchant` and has `buyer` order and pay for it in the plain
+    /// asset. Returns the order id.
+    pub fn funded_order(&self, merchant: &Address, buyer: &Address, price: i128) -> u64 {
+        let title = String::from_str(&self.env, "Rice sack");
+        let listing = self.client.create_listing(merchant, &title, &price, &self.token, &BytesN::from_array(&self.env, &[0; 32]));
+        let order = self.client.create_order(buyer, &listing);
+        self.mint(&self.token, buyer, price);
+        self.client.fund_order(buyer, &order);
+        order
+    }
+
+    /// A fresh user with the configured BZR bond staked and trust set to `trust`.
+    pub fn bonded_user(&self, trust: u32) -> Address {
+        let user = self.user();
+        self.mint(&self.bzr, &user, 100);
+        self.client.stake(&user);
+        self.set_trust(&user, trust);
+        user
+    }
+
+    /// Sets `user`'s trust score directly, e.g. to the Gold tier jurors need.
+    pub fn set_trust(&self, user: &Address, score: u32) {
+        self.env.as_contract(&self.client.address, || {
+            let mut merchant = load_merchant_for_update(&self.env, user);
+            merchant.trust_score = score;
+            write_merchant(&self.env, user, &merchant);
+        });
+    }
+}
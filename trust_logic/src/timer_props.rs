@@ -0,0 +1,69 @@
+// 🏛️ PROJECT BAZAAR | TIMER PROPERTIES
+// Property tests for the deadline arithmetic: no input may wrap a deadline into the
+// past, and without a panic quorum no claim opens before the configured timeout.
+
+#![cfg(test)]
+
+use super::*;
+use crate::testutils::{TestBazaar, DAY_SECONDS};
+use proptest::prelude::*;
+use soroban_sdk::testutils::Address as _;
+
+fn vault_with(env: &Env, last_heartbeat: u64, timeout_seconds: u64, backup_grace_seconds: u64) -> LegacyVault {
+    // `new_vault` reads the governed defaults, which needs a contract context
+    let contract = env.register(TrustContract, (Address::generate(env),));
+    let mut vault = env.as_contract(&contract, || new_vault(env, &Address::generate(env)));
+    vault.last_heartbeat = last_heartbeat;
+    vault.timeout_seconds = timeout_seconds;
+    vault.backup_grace_seconds = backup_grace_seconds;
+    vault
+}
+
+proptest! {
+    #[test]
+    fn claimable_at_never_precedes_heartbeat_plus_timeout(heartbeat in any::<u64>(), timeout in any::<u64>(), grace in any::<u64>(), index in 0u32..5) {
+        let env = Env::default();
+        let vault = vault_with(&env, heartbeat, timeout, grace);
+        let fires_at = claimable_at(&vault);
+        prop_assert!(fires_at >= heartbeat && fires_at >= timeout);
+        prop_assert!(backup_claimable_at(&vault, index) >= fires_at);
+    }
+
+    #[test]
+    fn panic_rewind_leaves_the_response_window_and_grace(
+        now in any::<u64>(),
+        response in 0..=MAX_PANIC_RESPONSE_SECONDS,
+        timeout in MIN_DEADMAN_SECONDS..=MAX_DEADMAN_SECONDS,
+        grace in DAY_SECONDS..=30 * DAY_SECONDS,
+    ) {
+        let rewound = panic_rewind(now, response, timeout, grace);
+        let fires_at = rewound.saturating_add(timeout);
+        prop_assert!(rewound <= now.saturating_add(response));
+        prop_assert!(fires_at >= now.saturating_add(response).saturating_add(grace));
+    }
+
+    #[test]
+    fn decay_never_moves_the_checkpoint_past_now(score in any::<u32>(), last_activity in any::<u64>(), now in any::<u64>()) {
+        let (decayed_score, checkpoint) = decayed(score, last_activity, now);
+        prop_assert!(decayed_score <= score);
+        prop_assert!(checkpoint >= last_activity);
+        prop_assert!(checkpoint <= now.max(last_activity));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn no_claim_before_timeout_without_panic_quorum(elapsed in 0..(DEFAULT_DEADMAN_SECONDS - 1), heartbeats in 0u32..3) {
+        let t = TestBazaar::default();
+        let owner = t.user();
+        let heir = t.fund_vault(&owner, 1_000);
+        for _ in 0..heartbeats {
+            t.advance_days(30);
+            t.client.ping_heartbeat(&owner);
+        }
+        t.advance_seconds(elapsed);
+        prop_assert_eq!(t.client.try_initiate_claim(&heir, &owner, &PRIMARY_VAULT), Err(Ok(Error::TimerNotExpired)));
+    }
+}
@@ -0,0 +1,89 @@
+// 🏛️ PROJECT BAZAAR | PROTOCOL TREASURY
+// Protocol fees are tracked per token, separate from user balances held in vaults and
+// escrow. The admin sets a basis-point fee per operation and can withdraw what accrued;
+// governance-approved grants draw on it too.
+
+use super::*;
+
+/// No single operation may charge more than 5%, whatever the admin configures.
+const MAX_FEE_BPS: u32 = 500;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeConfig {
+    pub escrow_bps: u32,
+    pub remit_bps: u32,
+    pub claim_bps: u32,
+}
+
+pub(crate) fn fee_config(env: &Env) -> FeeConfig {
+    env.storage().instance().get(&DataKey::FeeConfig).unwrap_or(FeeConfig { escrow_bps: 0, remit_bps: 0, claim_bps: 0 })
+}
+
+pub(crate) fn fee_on(amount: i128, bps: u32) -> i128 {
+    amount * bps as i128 / FULL_SHARE_BPS as i128
+}
+
+/// Books a fee the contract already holds (escrow, vault balances) into the treasury.
+pub(crate) fn credit(env: &Env, token_id: &Address, payer: &Address, amount: i128) {
+    if amount <= 0 { return; }
+    let key = DataKey::Treasury(token_id.clone());
+    let balance: i128 = storage::read(env, &key).unwrap_or(0);
+    storage::write(env, &key, &(balance + amount));
+    env.events().publish((events::FEE_COLLECTED, payer.clone()), (token_id.clone(), amount));
+}
+
+/// Pulls `amount` of `token_id` from `payer` into the treasury.
+pub(crate) fn collect(env: &Env, token_id: &Address, payer: &Address, amount: i128) {
+    if amount <= 0 { return; }
+    token::Client::new(env, token_id).transfer(payer, &env.current_contract_address(), &amount);
+    credit(env, token_id, payer, amount);
+}
+
+/// Takes `amount` out of the treasury books; the tokens stay in the contract for the caller to move.
+pub(crate) fn debit(env: &Env, token_id: &Address, amount: i128) -> Result<(), Error> {
+    let key = DataKey::Treasury(token_id.clone());
+    let balance: i128 = storage::read(env, &key).unwrap_or(0);
+    if amount > balance { return Err(Error::InsufficientBalance); }
+    storage::write(env, &key, &(balance - amount));
+    Ok(())
+}
+
+pub(crate) fn validate_fee_config(config: &FeeConfig) -> Result<(), Error> {
+    if config.escrow_bps > MAX_FEE_BPS || config.remit_bps > MAX_FEE_BPS || config.claim_bps > MAX_FEE_BPS {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 15: TREASURY ---
+
+    pub fn set_fee_config(env: Env, config: FeeConfig) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        validate_fee_config(&config)?;
+        env.storage().instance().set(&DataKey::FeeConfig, &config);
+        env.events().publish((events::FEES_SET, admin), (config.escrow_bps, config.remit_bps, config.claim_bps));
+        Ok(())
+    }
+
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        fee_config(&env)
+    }
+
+    pub fn withdraw_treasury(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        if amount <= 0 { return Err(Error::InvalidAmount); }
+        debit(&env, &token, amount)?;
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+        env.events().publish((events::TREASURY_WITHDRAWN, admin), (token, to, amount));
+        Ok(())
+    }
+
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        storage::read(&env, &DataKey::Treasury(token)).unwrap_or(0)
+    }
+}
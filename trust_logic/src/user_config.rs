@@ -0,0 +1,134 @@
+// 🏛️ PROJECT BAZAAR | USER CONFIG
+// One place for the knobs that used to be scattered constants: the deadman timeout,
+// the panic grace period, the medical release size, the circle quorums and how many
+// witnesses must co-sign an heir's claim. The timeout and quorums still live on the
+// vault and circle config; this module reads and writes them together with the
+// settings that have no other home.
+
+use super::*;
+
+const MIN_PANIC_GRACE_SECONDS: u64 = 86_400;    // 1 Day
+const MAX_PANIC_GRACE_SECONDS: u64 = 2_592_000; // 30 Days
+const DEFAULT_MEDICAL_RELEASE_BPS: u32 = 1_500; // 15% at the first tier
+/// Keeps the top medical tier (50% at the default) at or below the whole vault.
+const MAX_MEDICAL_RELEASE_BPS: u32 = 3_000;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct UserConfig {
+    /// Timeout of the owner's first vault; further vaults set theirs with `set_vault_timeout`.
+    pub deadman_seconds: u64,
+    pub panic_grace_seconds: u64,
+    /// Share unlocked at the first medical tier; the higher tiers scale with it.
+    pub medical_release_bps: u32,
+    pub medical_quorum: u32,
+    pub panic_quorum: u32,
+    /// Witness approvals `finalize_claim` needs on top of the heir's signature; 0 turns it off.
+    pub claim_cosigns: u32,
+}
+
+/// The settings stored under `DataKey::UserConfig`; the rest is read from the vault and circle.
+#[contracttype]
+#[derive(Clone)]
+pub struct StoredUserConfig {
+    pub panic_grace_seconds: u64,
+    pub medical_release_bps: u32,
+    pub claim_cosigns: u32,
+}
+
+fn stored(env: &Env, user: &Address) -> StoredUserConfig {
+    storage::read(env, &DataKey::UserConfig(user.clone())).unwrap_or(StoredUserConfig {
+        panic_grace_seconds: PANIC_GRACE_SECONDS,
+        medical_release_bps: DEFAULT_MEDICAL_RELEASE_BPS,
+        claim_cosigns: 0,
+    })
+}
+
+pub(crate) fn panic_grace_seconds(env: &Env, user: &Address) -> u64 {
+    stored(env, user).panic_grace_seconds
+}
+
+pub(crate) fn medical_release_bps(env: &Env, user: &Address) -> u32 {
+    stored(env, user).medical_release_bps
+}
+
+pub(crate) fn claim_cosigns(env: &Env, user: &Address) -> u32 {
+    stored(env, user).claim_cosigns
+}
+
+/// Sensitive changes need an active witness of `user` to co-sign, so a stolen key alone
+/// can't loosen the vault's protections.
+pub(crate) fn require_cosign(env: &Env, user: &Address, cosigner: Option<Address>) -> Result<(), Error> {
+    let cosigner = cosigner.ok_or(Error::CosignRequired)?;
+    cosigner.require_auth();
+    require_witness(env, &cosigner, user)
+}
+
+pub(crate) fn user_config(env: &Env, user: &Address) -> Result<UserConfig, Error> {
+    let vault = load_vault(env, user, PRIMARY_VAULT)?;
+    let circle = circle_config(env, user);
+    let extra = stored(env, user);
+    Ok(UserConfig {
+        deadman_seconds: vault.timeout_seconds,
+        panic_grace_seconds: extra.panic_grace_seconds,
+        medical_release_bps: extra.medical_release_bps,
+        medical_quorum: circle.medical_quorum,
+        panic_quorum: circle.panic_quorum,
+        claim_cosigns: extra.claim_cosigns,
+    })
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 24: USER CONFIG ---
+
+    pub fn get_user_config(env: Env, user: Address) -> Result<UserConfig, Error> {
+        user_config(&env, &user)
+    }
+
+    /// Changing the timeout, the panic grace period, the medical release or either quorum,
+    /// or lowering the claim co-signs, needs an active witness to co-sign.
+    pub fn set_user_config(env: Env, user: Address, config: UserConfig, cosigner: Option<Address>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if config.deadman_seconds < MIN_DEADMAN_SECONDS || config.deadman_seconds > MAX_DEADMAN_SECONDS { return Err(Error::InvalidTimeout); }
+        if config.panic_grace_seconds < MIN_PANIC_GRACE_SECONDS || config.panic_grace_seconds > MAX_PANIC_GRACE_SECONDS { return Err(Error::InvalidTimeout); }
+        if config.medical_release_bps == 0 || config.medical_release_bps > MAX_MEDICAL_RELEASE_BPS { return Err(Error::InvalidConfig); }
+        if config.claim_cosigns > MAX_WITNESSES { return Err(Error::InvalidQuorum); }
+
+        let current = user_config(&env, &user)?;
+        let quorums_changed = config.medical_quorum != current.medical_quorum || config.panic_quorum != current.panic_quorum;
+        // Before the first circle there is nobody to co-sign a quorum change, and nothing it guards yet
+        let sensitive = config.deadman_seconds != current.deadman_seconds
+            || config.panic_grace_seconds != current.panic_grace_seconds
+            || config.medical_release_bps != current.medical_release_bps
+            || (quorums_changed && load_circle(&env, &user).is_ok())
+            || config.claim_cosigns < current.claim_cosigns;
+        if sensitive { require_cosign(&env, &user, cosigner)?; }
+        if config.claim_cosigns > current.claim_cosigns && config.claim_cosigns > active_witnesses(&env, &user).len() { return Err(Error::InvalidQuorum); }
+
+        if quorums_changed {
+            let mut circle = circle_config(&env, &user);
+            circle.medical_quorum = config.medical_quorum;
+            circle.panic_quorum = config.panic_quorum;
+            require_valid_quorums(&env, &user, &circle)?;
+            storage::write(&env, &DataKey::CircleConfig(user.clone()), &circle);
+        }
+        if config.deadman_seconds != current.deadman_seconds {
+            let mut vault = load_vault(&env, &user, PRIMARY_VAULT)?;
+            vault.timeout_seconds = config.deadman_seconds;
+            write_vault(&env, &user, PRIMARY_VAULT, &vault);
+        }
+        let extra = StoredUserConfig {
+            panic_grace_seconds: config.panic_grace_seconds,
+            medical_release_bps: config.medical_release_bps,
+            claim_cosigns: config.claim_cosigns,
+        };
+        storage::write(&env, &DataKey::UserConfig(user.clone()), &extra);
+        env.events().publish((events::USER_CONFIG_SET, user), (config.deadman_seconds, config.medical_release_bps));
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// 🏛️ PROJECT BAZAAR | MERCHANT VERIFICATION
+// Off-chain KYC recorded on-chain by the admin. A verified merchant carries the
+// "verified" badge and a trust bonus that grows with the level; regulated listings
+// can then be gated on `is_verified`.
+
+use super::*;
+
+const BASIC_TRUST_BONUS: u32 = 5;
+const FULL_TRUST_BONUS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum VerificationLevel {
+    None,
+    Basic,
+    Full,
+}
+
+fn trust_bonus(level: VerificationLevel) -> u32 {
+    match level {
+        VerificationLevel::None => 0,
+        VerificationLevel::Basic => BASIC_TRUST_BONUS,
+        VerificationLevel::Full => FULL_TRUST_BONUS,
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 25: VERIFICATION ---
+
+    /// Sets the level outright; downgrading takes back the difference in bonus, and
+    /// `None` also removes the badge.
+    pub fn verify_merchant(env: Env, user: Address, level: VerificationLevel) -> Result<(), Error> {
+        let admin = admin::require_admin(&env)?;
+        let mut merchant = load_merchant_for_update(&env, &user);
+        let previous = merchant.verification;
+        if previous == level { return Ok(()); }
+
+        let before = merchant.trust_score;
+        let score = merchant.trust_score.saturating_sub(trust_bonus(previous)) + trust_bonus(level);
+        merchant.trust_score = score.min(MAX_TRUST_SCORE);
+        merchant.verification = level;
+        match (level, merchant.badges.first_index_of(&BADGE_VERIFIED)) {
+            (VerificationLevel::None, Some(index)) => badges::retract(&env, &user, &mut merchant, index),
+            (VerificationLevel::None, None) => {}
+            (_, Some(_)) => {}
+            (_, None) => {
+                storage::remove(&env, &DataKey::BadgeRevoked(user.clone(), BADGE_VERIFIED));
+                badges::issue(&env, &user, &mut merchant, BADGE_VERIFIED);
+            }
+        }
+        badges::check_and_award_badges(&env, &user, &mut merchant);
+        write_merchant(&env, &user, &merchant);
+        history::record(&env, &user, before, merchant.trust_score, TrustReason::Verification, &admin);
+        discount_if_collapsed(&env, &user, &merchant);
+
+        env.events().publish((events::MERCHANT_VERIFIED, user.clone()), level);
+        env.events().publish((events::TRUST_CHANGED, user), merchant.trust_score);
+        Ok(())
+    }
+
+    pub fn is_verified(env: Env, user: Address) -> bool {
+        load_merchant(&env, &user).verification != VerificationLevel::None
+    }
+
+    pub fn get_verification(env: Env, user: Address) -> VerificationLevel {
+        load_merchant(&env, &user).verification
+    }
+}
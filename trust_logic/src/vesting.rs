@@ -0,0 +1,164 @@
+// 🏛️ PROJECT BAZAAR | INHERITANCE VESTING
+// Owners can have an inheritance pay out in equal monthly (or other) steps instead
+// of a lump sum. A finalized claim then opens one grant per heir and asset, and each
+// heir draws down whatever has vested with `claim_vested`.
+
+use super::*;
+
+const MIN_VESTING_PERIOD_SECONDS: u64 = 86_400;    // 1 Day
+const MAX_VESTING_PERIOD_SECONDS: u64 = 31_536_000; // 365 Days
+const MAX_VESTING_PERIODS: u32 = 120;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingSchedule {
+    pub period_seconds: u64,
+    pub periods: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingGrant {
+    pub owner: Address,
+    pub vault_id: u32,
+    pub token: Address,
+    pub total: i128,
+    pub released: i128,
+    pub start: u64,
+    pub schedule: VestingSchedule,
+    /// Set when the owner reclaimed the vault; `total` was cut to what had vested by then.
+    pub stopped_at: Option<u64>,
+}
+
+/// Amount of the grant unlocked so far; the final period releases any rounding dust.
+fn vested_amount(env: &Env, grant: &VestingGrant) -> i128 {
+    if grant.stopped_at.is_some() { return grant.total; }
+    let elapsed = env.ledger().timestamp().saturating_sub(grant.start) / grant.schedule.period_seconds;
+    let periods = elapsed.min(grant.schedule.periods as u64) as i128;
+    grant.total * periods / grant.schedule.periods as i128
+}
+
+/// Routes one heir's portion of a claim: straight transfer, or a new grant if the owner set a schedule.
+pub(crate) fn pay_or_vest(env: &Env, owner: &Address, vault_id: u32, heir: &Address, token_id: &Address, amount: i128) {
+    if amount <= 0 { return; }
+    let Some(schedule) = storage::read::<VestingSchedule>(env, &DataKey::VaultVesting(owner.clone(), vault_id)) else {
+        guardianship::deliver(env, owner, vault_id, heir, token_id, amount);
+        return;
+    };
+
+    let key = DataKey::Vesting(heir.clone());
+    let mut grants: Vec<VestingGrant> = storage::read(env, &key).unwrap_or(Vec::new(env));
+    grants.push_back(VestingGrant {
+        owner: owner.clone(),
+        vault_id,
+        token: token_id.clone(),
+        total: amount,
+        released: 0,
+        start: env.ledger().timestamp(),
+        schedule,
+        stopped_at: None,
+    });
+    storage::write(env, &key, &grants);
+    add_to_index(env, &DataKey::GrantHeirs(owner.clone()), heir);
+}
+
+/// Stops every running grant `owner` left behind from one vault. Heirs keep what had
+/// vested so far; the unvested rest is returned per token so it can go back into the vault.
+pub(crate) fn revoke_unvested(env: &Env, owner: &Address, vault_id: u32) -> Map<Address, i128> {
+    let now = env.ledger().timestamp();
+    let mut returned: Map<Address, i128> = Map::new(env);
+    let heirs_key = DataKey::GrantHeirs(owner.clone());
+    let heirs: Vec<Address> = storage::read(env, &heirs_key).unwrap_or(Vec::new(env));
+    let mut still_granted = Vec::new(env);
+    for heir in heirs.iter() {
+        let key = DataKey::Vesting(heir.clone());
+        let grants: Vec<VestingGrant> = storage::read(env, &key).unwrap_or(Vec::new(env));
+        let mut kept = Vec::new(env);
+        for mut grant in grants.iter() {
+            if grant.owner == *owner && grant.stopped_at.is_none() {
+                if grant.vault_id == vault_id {
+                    let vested = vested_amount(env, &grant);
+                    let unvested = grant.total - vested;
+                    returned.set(grant.token.clone(), returned.get(grant.token.clone()).unwrap_or(0) + unvested);
+                    grant.total = vested;
+                    grant.stopped_at = Some(now);
+                } else if !still_granted.contains(&heir) {
+                    still_granted.push_back(heir.clone());
+                }
+            }
+            if grant.released < grant.total { kept.push_back(grant); }
+        }
+        if kept.is_empty() { storage::remove(env, &key); } else { storage::write(env, &key, &kept); }
+    }
+    // Heirs still holding running grants from the owner's other vaults stay indexed
+    if still_granted.is_empty() { storage::remove(env, &heirs_key); } else { storage::write(env, &heirs_key, &still_granted); }
+    returned
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 11: VESTING ---
+
+    /// `None` restores lump-sum payouts. Only affects claims finalized afterwards.
+    pub fn set_vesting(env: Env, user: Address, vault_id: u32, schedule: Option<VestingSchedule>) -> Result<(), Error> {
+        require_not_paused(&env)?;
+        user.require_auth();
+        touch_heartbeat(&env, &user);
+        require_not_frozen(&env, &user)?;
+        if let Some(schedule) = &schedule {
+            if schedule.periods == 0 || schedule.periods > MAX_VESTING_PERIODS { return Err(Error::InvalidVesting); }
+            if schedule.period_seconds < MIN_VESTING_PERIOD_SECONDS || schedule.period_seconds > MAX_VESTING_PERIOD_SECONDS {
+                return Err(Error::InvalidVesting);
+            }
+        }
+
+        load_vault(&env, &user, vault_id)?;
+        let key = DataKey::VaultVesting(user.clone(), vault_id);
+        match &schedule {
+            Some(schedule) => storage::write(&env, &key, schedule),
+            None => storage::remove(&env, &key),
+        }
+        env.events().publish((events::VESTING_SET, user, vault_id), schedule);
+        Ok(())
+    }
+
+    /// Pays out everything vested across all of the heir's grants. Fully paid grants are dropped.
+    /// A minor heir's vested amounts go to their guarded funds instead.
+    pub fn claim_vested(env: Env, heir: Address) -> Result<Map<Address, i128>, Error> {
+        require_not_paused(&env)?;
+        heir.require_auth();
+        touch_heartbeat(&env, &heir);
+        let key = DataKey::Vesting(heir.clone());
+        let grants: Vec<VestingGrant> = storage::read(&env, &key).unwrap_or(Vec::new(&env));
+
+        let mut payouts: Map<Address, i128> = Map::new(&env);
+        let mut deliveries = Vec::new(&env);
+        let mut remaining = Vec::new(&env);
+        for mut grant in grants.iter() {
+            let due = vested_amount(&env, &grant) - grant.released;
+            if due > 0 {
+                grant.released += due;
+                payouts.set(grant.token.clone(), payouts.get(grant.token.clone()).unwrap_or(0) + due);
+                deliveries.push_back((grant.owner.clone(), grant.vault_id, grant.token.clone(), due));
+            }
+            if grant.released < grant.total { remaining.push_back(grant); }
+        }
+        if payouts.is_empty() { return Err(Error::InsufficientBalance); }
+
+        if remaining.is_empty() { storage::remove(&env, &key); } else { storage::write(&env, &key, &remaining); }
+        for (owner, vault_id, token_id, amount) in deliveries.iter() {
+            guardianship::deliver(&env, &owner, vault_id, &heir, &token_id, amount);
+            env.events().publish((events::VESTED_PAID, heir.clone()), (token_id, amount));
+        }
+        Ok(payouts)
+    }
+
+    pub fn get_vesting(env: Env, user: Address, vault_id: u32) -> Option<VestingSchedule> {
+        storage::read(&env, &DataKey::VaultVesting(user, vault_id))
+    }
+
+    pub fn get_vesting_grants(env: Env, heir: Address) -> Vec<VestingGrant> {
+        storage::read(&env, &DataKey::Vesting(heir)).unwrap_or(Vec::new(&env))
+    }
+}
@@ -0,0 +1,114 @@
+// 🏛️ PROJECT BAZAAR | VOUCH STAKES
+// Every vouch locks a small BZR stake, 5% of the merchant bond, behind the endorsement.
+// If the target loses a fraud dispute within 90 days the stake is burned; after that
+// the voucher can take it back with a small bonus from the rewards pool. Unvouching
+// does not release the stake early, so a voucher can't dodge a ruling they see coming.
+
+use super::*;
+
+const VOUCH_STAKE_BPS: u32 = 500;          // 5% of the bond
+const VOUCH_STAKE_REWARD_BPS: u32 = 200;   // 2% of the stake
+const VOUCH_STAKE_LOCK_SECONDS: u64 = 7_776_000; // 90 Days
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VouchStake {
+    pub amount: i128,
+    pub staked_at: u64,
+}
+
+/// Stakes behind `target`, keyed by voucher.
+fn stakes_on(env: &Env, target: &Address) -> Map<Address, VouchStake> {
+    storage::read(env, &DataKey::VouchStakes(target.clone())).unwrap_or(Map::new(env))
+}
+
+fn write_stakes(env: &Env, target: &Address, stakes: &Map<Address, VouchStake>) {
+    let key = DataKey::VouchStakes(target.clone());
+    if stakes.is_empty() { storage::remove(env, &key); } else { storage::write(env, &key, stakes); }
+}
+
+/// Pulls the stake for a new vouch. Vouching again while an earlier stake is still held
+/// re-locks that stake instead of taking a second one.
+pub(crate) fn lock(env: &Env, voucher: &Address, target: &Address) -> Result<(), Error> {
+    let mut stakes = stakes_on(env, target);
+    let now = env.ledger().timestamp();
+    if let Some(mut stake) = stakes.get(voucher.clone()) {
+        stake.staked_at = now;
+        stakes.set(voucher.clone(), stake);
+        write_stakes(env, target, &stakes);
+        return Ok(());
+    }
+
+    let amount = admin::protocol_config(env)?.bond_amount * VOUCH_STAKE_BPS as i128 / FULL_SHARE_BPS as i128;
+    if amount <= 0 { return Ok(()); }
+    token::Client::new(env, &admin::bzr_token(env)?).transfer(voucher, &env.current_contract_address(), &amount);
+    stakes.set(voucher.clone(), VouchStake { amount, staked_at: now });
+    write_stakes(env, target, &stakes);
+    env.events().publish((events::VOUCH_STAKED, target.clone()), (voucher.clone(), amount));
+    Ok(())
+}
+
+/// Burns every stake placed behind `target` in the last 90 days. Called when a dispute
+/// goes against them.
+pub(crate) fn burn_recent(env: &Env, target: &Address) -> Result<i128, Error> {
+    let stakes = stakes_on(env, target);
+    if stakes.is_empty() { return Ok(0); }
+    let now = env.ledger().timestamp();
+    let mut kept = Map::new(env);
+    let mut burned: i128 = 0;
+    for (voucher, stake) in stakes.iter() {
+        if now < stake.staked_at.saturating_add(VOUCH_STAKE_LOCK_SECONDS) {
+            burned += stake.amount;
+            env.events().publish((events::VOUCH_STAKE_BURNED, target.clone()), (voucher, stake.amount));
+        } else {
+            kept.set(voucher, stake);
+        }
+    }
+    write_stakes(env, target, &kept);
+    if burned > 0 { token::Client::new(env, &admin::bzr_token(env)?).burn(&env.current_contract_address(), &burned); }
+    Ok(burned)
+}
+
+/// Rewrites stakes `old` placed on the given targets to `new`.
+pub(crate) fn migrate_voucher(env: &Env, targets: &Vec<Address>, old: &Address, new: &Address) {
+    for target in targets.iter() {
+        let mut stakes = stakes_on(env, &target);
+        if let Some(stake) = stakes.get(old.clone()) {
+            stakes.remove(old.clone());
+            stakes.set(new.clone(), stake);
+            write_stakes(env, &target, &stakes);
+        }
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 39: VOUCH STAKES ---
+
+    /// Returns a stake that survived its 90-day lock, plus the bonus. A pool that can't
+    /// cover the bonus pays what it holds.
+    pub fn release_vouch_stake(env: Env, voucher: Address, target: Address) -> Result<i128, Error> {
+        require_not_paused(&env)?;
+        voucher.require_auth();
+        touch_heartbeat(&env, &voucher);
+        let mut stakes = stakes_on(&env, &target);
+        let stake = stakes.get(voucher.clone()).ok_or(Error::NoVouchStake)?;
+        if env.ledger().timestamp() < stake.staked_at.saturating_add(VOUCH_STAKE_LOCK_SECONDS) { return Err(Error::VouchStakeLocked); }
+
+        stakes.remove(voucher.clone());
+        write_stakes(&env, &target, &stakes);
+        let pool: i128 = env.storage().instance().get(&DataKey::RewardPool).unwrap_or(0);
+        let bonus = (stake.amount * VOUCH_STAKE_REWARD_BPS as i128 / FULL_SHARE_BPS as i128).min(pool.max(0));
+        if bonus > 0 { env.storage().instance().set(&DataKey::RewardPool, &(pool - bonus)); }
+
+        let payout = stake.amount + bonus;
+        token::Client::new(&env, &admin::bzr_token(&env)?).transfer(&env.current_contract_address(), &voucher, &payout);
+        env.events().publish((events::VOUCH_STAKE_RELEASED, target), (voucher, payout));
+        Ok(payout)
+    }
+
+    pub fn get_vouch_stake(env: Env, voucher: Address, target: Address) -> Option<VouchStake> {
+        stakes_on(&env, &target).get(voucher)
+    }
+}
@@ -0,0 +1,56 @@
+// 🏛️ PROJECT BAZAAR | WITNESS STATS
+// How reliably each witness shows up: emergencies and panic attempts they could have
+// voted on, the votes they actually cast and how long they took. Owners can check a
+// candidate before adding them, and dependable witnesses earn the Guardian badge.
+
+use super::*;
+
+const GUARDIAN_MIN_VOTES: u32 = 3;
+const GUARDIAN_MIN_RESPONSE_BPS: u32 = 8_000; // 80% of eligible rounds
+
+#[contracttype]
+#[derive(Clone)]
+pub struct WitnessStats {
+    pub eligible: u32,
+    pub votes_cast: u32,
+    /// Sum of seconds from an emergency or panic attempt opening to this witness's vote;
+    /// divide by `votes_cast` for the average.
+    pub total_latency_seconds: u64,
+}
+
+fn load_stats(env: &Env, witness: &Address) -> WitnessStats {
+    storage::read(env, &DataKey::WitnessStats(witness.clone())).unwrap_or(WitnessStats { eligible: 0, votes_cast: 0, total_latency_seconds: 0 })
+}
+
+/// A new emergency or panic attempt against `target_user`: every active witness could respond.
+pub(crate) fn mark_eligible(env: &Env, target_user: &Address) {
+    for witness in active_witnesses(env, target_user).iter() {
+        let mut stats = load_stats(env, &witness);
+        stats.eligible += 1;
+        storage::write(env, &DataKey::WitnessStats(witness.clone()), &stats);
+    }
+}
+
+pub(crate) fn record_vote(env: &Env, witness: &Address, opened_at: u64) {
+    let mut stats = load_stats(env, witness);
+    stats.votes_cast += 1;
+    stats.total_latency_seconds += env.ledger().timestamp().saturating_sub(opened_at);
+    storage::write(env, &DataKey::WitnessStats(witness.clone()), &stats);
+
+    let reliable = stats.votes_cast as u64 * FULL_SHARE_BPS as u64 >= stats.eligible as u64 * GUARDIAN_MIN_RESPONSE_BPS as u64;
+    if stats.votes_cast >= GUARDIAN_MIN_VOTES && reliable {
+        let mut merchant = load_merchant_for_update(env, witness);
+        badges::award(env, witness, &mut merchant, BADGE_GUARDIAN);
+        write_merchant(env, witness, &merchant);
+    }
+}
+
+#[contractimpl]
+impl TrustContract {
+
+    // --- FEATURE 32: WITNESS STATS ---
+
+    pub fn get_witness_stats(env: Env, witness: Address) -> WitnessStats {
+        load_stats(&env, &witness)
+    }
+}